@@ -9,35 +9,36 @@ extern crate serde_derive;
 #[path="src/parser.rs"]
 mod parser;
 
+use std::io::Write;
 use std::thread::{self, JoinHandle};
 use std::{path, env, fs};
-use grammar::Lang;
+use grammar::Locale;
 
-pub fn train_async(lang: Lang) -> JoinHandle<()> {
-    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", lang.to_string().to_lowercase());
+pub fn train_async(locale: Locale) -> JoinHandle<()> {
+    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", locale.lang.to_string().to_lowercase());
     thread::spawn(move || {
         let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-        let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
-        let rules = grammar::rules(lang).unwrap();
-        let examples =  grammar::examples(lang);
+        let mut file = fs::File::create(out_dir.join(format!("{}{}", locale.to_string().to_lowercase(), ".rmp"))).unwrap(); 
+        let rules = grammar::rules_for_locale(locale).unwrap();
+        let examples =  grammar::examples_for_locale(locale);
         let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
         ::rmp_serde::encode::write(&mut file, &model).unwrap();
     })
 }
 
-pub fn train_sync(lang: Lang) {
-    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", lang.to_string().to_lowercase());
+pub fn train_sync(locale: Locale) {
+    println!("cargo:rerun-if-changed=grammar/{}/src/rules.rs", locale.lang.to_string().to_lowercase());
     let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
-    let mut file = fs::File::create(out_dir.join(format!("{}{}", lang.to_string().to_lowercase(), ".rmp"))).unwrap(); 
-    let rules = grammar::rules(lang).unwrap();
-    let examples =  grammar::examples(lang);
+    let mut file = fs::File::create(out_dir.join(format!("{}{}", locale.to_string().to_lowercase(), ".rmp"))).unwrap(); 
+    let rules = grammar::rules_for_locale(locale).unwrap();
+    let examples =  grammar::examples_for_locale(locale);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor()).unwrap();
     ::rmp_serde::encode::write(&mut file, &model).unwrap();
 }
 
 pub fn train_all_async() {
-    let join_handlers: Vec<_> = Lang::all().into_iter().map(|lang| {
-        train_async(lang)
+    let join_handlers: Vec<_> = Locale::all().into_iter().map(|locale| {
+        train_async(locale)
     }).collect();
 
     for join in join_handlers {
@@ -46,11 +47,27 @@ pub fn train_all_async() {
 }
 
 pub fn train_all_sync() {
-    for lang in Lang::all() {
-        train_sync(lang);
+    for locale in Locale::all() {
+        train_sync(locale);
     }
 }
 
+/// Writes `models.rs`, embedding the model of every locale under the name of the locale
+pub fn write_models() {
+    let out_dir = path::PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut file = fs::File::create(out_dir.join("models.rs")).unwrap();
+    writeln!(file, "fn model_bytes(locale: &str) -> Option<&'static [u8]> {{").unwrap();
+    writeln!(file, "    match locale {{").unwrap();
+    for locale in Locale::all() {
+        let name = locale.to_string().to_lowercase();
+        writeln!(file, "        {:?} => Some(&include_bytes!({:?})[..]),", name, out_dir.join(format!("{}.rmp", name))).unwrap();
+    }
+    writeln!(file, "        _ => None,").unwrap();
+    writeln!(file, "    }}").unwrap();
+    writeln!(file, "}}").unwrap();
+}
+
 fn main() {
     train_all_sync();
+    write_models();
 }
@@ -7,31 +7,62 @@ mod rules;
 mod training;
 
 use rustling_ontology_values::DimensionKind::*;
+use rustling_ontology_values::locale::Region;
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    rule_set_for_region(None)
+}
+
+/// Rules of the language with the ones of the region, such as "Feber" in Austria
+pub fn rule_set_for_region(region: Option<Region>) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::composed_word_or_detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    rules::rules_number_interval(&mut b)?;
     rules::rules_time(&mut b)?;
+    rules::rules_timezone(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_quantity(&mut b)?;
+    rules::rules_phone_number(&mut b)?;
+    rustling_ontology_values::common_rules::rules_email(&mut b)?;
+    rustling_ontology_values::common_rules::rules_url(&mut b)?;
+    rustling_ontology_values::common_rules::rules_phone_number(&mut b)?;
+    match region.as_ref().map(|region| region.as_str()) {
+        Some("AT") => rules::rules_time_at(&mut b)?,
+        _ => {}
+    }
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage, Quantity, Email, Url, PhoneNumber, NumberInterval, DurationInterval, Recurrence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    examples_for_region(None)
+}
+
+pub fn examples_for_region(region: Option<Region>) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_number_interval(&mut v);
+    training::examples_duration_interval(&mut v);
+    training::examples_recurrence(&mut v);
     training::examples_time(&mut v);
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
+    training::examples_quantity(&mut v);
+    training::examples_contact(&mut v);
+    match region.as_ref().map(|region| region.as_str()) {
+        Some("AT") => training::examples_time_at(&mut v),
+        _ => {}
+    }
     v
 }
 
@@ -56,4 +87,12 @@ mod test {
         let examples = examples();
         assert_examples(&rules, examples);
     }
+
+    #[test]
+    fn test_regional_examples() {
+        for code in &["AT"] {
+            let region = Region::from_code(code);
+            assert_examples(&rule_set_for_region(region).unwrap(), examples_for_region(region));
+        }
+    }
 }
@@ -2,6 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
 use rustling_ontology_moment::{Grain, PeriodComp, Weekday, Period};
 
 fn german_article_regex() -> &'static str {
@@ -24,52 +25,12 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value())
     );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"cents?|penn(?:y|ies)|cts?|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
-    );
-    b.rule_1_terminal("₩",
-                      b.reg(r#"₩|krw|(?:s[üu]dkoreanische[rnms]? )?won"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
-    );
-    b.rule_1_terminal("$",
-                      b.reg(r#"\$|dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("EUR",
-                      b.reg(r#"€|euro?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-                      b.reg(r#"£|pfunds?|pfd."#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("GBP",
-                      b.reg(r#"gbp|pfunds? sterling"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("AUD",
-                      b.reg(r#"aud|australische[rnms]? dollar"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
-    );
-    b.rule_1_terminal("USD",
-                      b.reg(r#"us[d\$]|us[ -]dollar"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("PTS",
-                      b.reg(r#"pta?s?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("PTS") })
-    );
-    b.rule_1_terminal("INR",
-                      b.reg(r#"inr|₹|(?:indische[rn]? )?rupien?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
-    );
-    b.rule_1_terminal("CHF",
-                      b.reg(r#"chf|(?:schweizer )?frankens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("CHF") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::DE))?,
+                      |text_match| helpers::money_unit(names::DE, text_match.group(0))
     );
     b.rule_2("<unit> <amount>",
              money_unit!(),
@@ -108,6 +69,66 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_4("zwischen <amount-of-money> und <amount-of-money>",
+             b.reg(r#"zwischen"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"und"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("zwischen <number> und <amount-of-money>",
+             b.reg(r#"zwischen"#)?,
+             number_check!(),
+             b.reg(r#"und"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("von <amount-of-money> bis <amount-of-money>",
+             b.reg(r#"von"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"bis"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("von <number> bis <amount-of-money>",
+             b.reg(r#"von"#)?,
+             number_check!(),
+             b.reg(r#"bis"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|bis"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-|bis"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("unter <amount-of-money>",
+             b.reg(r#"unter|weniger als|h[öo]chstens|bis zu|maximal|nicht mehr als|billiger als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> oder weniger",
+             amount_of_money_check!(),
+             b.reg(r#"oder weniger|maximal"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("über <amount-of-money>",
+             b.reg(r#"[üu]ber|mehr als|mindestens|wenigstens|ab|minimal|teurer als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> oder mehr",
+             amount_of_money_check!(),
+             b.reg(r#"oder mehr|und mehr|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -181,6 +202,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_2("number.number hours",
              b.reg(r#"(\d+)\.(\d+)"#)?,
              unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Hour),
@@ -295,6 +321,66 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(|duration: &DurationValue| !duration.prefixed),
              |a, b| Ok(a.value() + b.value())
     );
+    b.rule_4("zwischen <duration> und <duration>",
+             b.reg(r#"zwischen"#)?,
+             duration_check!(),
+             b.reg(r#"und"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("zwischen <number> und <duration>",
+             b.reg(r#"zwischen"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"und"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("von <duration> bis <duration>",
+             b.reg(r#"von"#)?,
+             duration_check!(),
+             b.reg(r#"bis"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("von <number> bis <duration>",
+             b.reg(r#"von"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"bis"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|bis|oder"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|bis|oder"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("mindestens <duration>",
+             b.reg(r#"mindestens|wenigstens|mehr als|[üu]ber|l[äa]nger als"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> oder mehr",
+             duration_check!(),
+             b.reg(r#"oder (?:mehr|l[äa]nger)|und mehr"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("weniger als <duration>",
+             b.reg(r#"weniger als|unter|h[öo]chstens|bis zu|maximal|k[üu]rzer als"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> oder weniger",
+             duration_check!(),
+             b.reg(r#"oder (?:weniger|k[üu]rzer)|maximal"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
     Ok(())
 }
 
@@ -619,227 +705,227 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("christmas",
                       b.reg(r#"christtag|weih?nacht(?:en|s(?:feier)?tag)?"#)?,
-                      |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration("christmas")))
     );
     b.rule_1_terminal("christmas days (24/12-26/12)",
                       b.reg(r#"weihnachtsfest"#)?,
                       |_| {
                         Ok(helpers::month_day(12, 24)?
                           .span_to(&helpers::month_day(12, 26)?, true)?
-                          .form(Form::Celebration))
+                          .form(Form::Celebration("christmas_days")))
                       }
     );
     b.rule_1_terminal("christmas eve",
                       b.reg(r#"christnacht|(?:heilig(?:e[r|n])?|weihnachts) ?abend"#)?,
-                      |_| Ok(helpers::month_day(12, 24)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 24)?.form(Form::Celebration("christmas_eve")))
     );
     b.rule_1_terminal("new year's eve",
                       b.reg(r#"silvester|neujahrsabend"#)?,
-                      |_| Ok(helpers::month_day(12, 31)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 31)?.form(Form::Celebration("new_years_eve")))
     );
     b.rule_1_terminal("new year's day",
                       b.reg(r#"neujahr(?:s?tag)?"#)?,
-                      |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration("new_years_day")))
     );
     b.rule_1_terminal("Epiphanias",
                       b.reg(r#"heiligen? drei k[öo]nigen?"#)?,
-                      |_| Ok(helpers::month_day(1, 6)?.form(Form::Celebration).too_ambiguous())
+                      |_| Ok(helpers::month_day(1, 6)?.form(Form::Celebration("epiphany")).too_ambiguous())
     );
 
     b.rule_1_terminal("Candlemess",
         b.reg(r#"lichtmess"#)?,
-        |_| Ok(helpers::month_day(2, 2)?.form(Form::Celebration).too_ambiguous())
+        |_| Ok(helpers::month_day(2, 2)?.form(Form::Celebration("candlemas")).too_ambiguous())
     );
 
     b.rule_1_terminal("rosenmontag (Shrove Monday)",
         b.reg(r#"rosenmontag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -48, &helpers::easter()?)?.form(Form::Celebration)),
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, -48, &helpers::easter()?)?.form(Form::Celebration("shrove_monday"))),
     );
 
     b.rule_1_terminal("fastnachtsdienstag (Shrove Tuesday)",
         b.reg(r#"fastnachtsdienstag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?.form(Form::Celebration)),
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?.form(Form::Celebration("shrove_tuesday"))),
     );
 
     b.rule_1_terminal("aschermittwoch (Ash Wednesday)",
         b.reg(r#"aschermittwoch"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -46, &helpers::easter()?)?.form(Form::Celebration)),
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, -46, &helpers::easter()?)?.form(Form::Celebration("ash_wednesday"))),
     );
 
     b.rule_1_terminal("palmsonntag (Palm Sunday)",
         b.reg(r#"palmsonntag"#)?,
-        |_| Ok(helpers::cycle_nth_after(Grain::Day, -7, &helpers::easter()?)?.form(Form::Celebration)),
+        |_| Ok(helpers::cycle_nth_after(Grain::Day, -7, &helpers::easter()?)?.form(Form::Celebration("palm_sunday"))),
     );
     b.rule_1_terminal("Holy Thursday",
         b.reg(r#"gr[üu]ndonnerstag"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -3, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("holy_thursday")))
     );
     b.rule_1_terminal("Good Friday",
         b.reg(r#"karfreitag"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -2, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("good_friday")))
     );
     b.rule_1_terminal("Lent",
         b.reg(r#"(?:in|w[aä]hrend) der fastenzeit"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?
                           .span_to(&helpers::easter()?, false)?
-                          .form(Form::Celebration))
+                          .form(Form::Celebration("lent")))
     );
 
     b.rule_1_terminal("fasnet",
         b.reg(r#"fast?nacht|(?:in|w[aä]hrend) der fasnet"#)?,
         |_| Ok(helpers::month_day(11, 11)?
                           .span_to(&helpers::cycle_nth_after(Grain::Day, -47, &helpers::easter()?)?, false)?
-                          .form(Form::Celebration))
+                          .form(Form::Celebration("carnival")))
     );
     b.rule_1_terminal("Easter",
         b.reg(r#"oster(?:n|sonntag)"#)?,
         |_| Ok(helpers::easter()?
-                .form(Form::Celebration))
+                .form(Form::Celebration("easter")))
     );
     b.rule_1_terminal("Easter Monday",
         b.reg(r#"ostermontag"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("easter_monday")))
     );
     b.rule_1_terminal("ascension",
         b.reg(r#"himmelfahrt|auffahrt"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("ascension")))
 
     );
     b.rule_1_terminal("Pencost",
         b.reg(r#"pfingst(?:en|sonntag|feiertag(?:en)?)"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 49, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("pentecost")))
     );
 
     b.rule_1_terminal("Pencost Monday",
         b.reg(r#"pfingstmontag"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 50, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("whit_monday")))
     );
 
     b.rule_1_terminal("valentine's day",
                       b.reg(r#"valentin'?stag"#)?,
-                      |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration("valentines_day")))
     );
     b.rule_1_terminal("labor day",
                       b.reg(r#"tag der arbeit"#)?,
-                      |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration("labour_day")))
     );
     b.rule_1_terminal("Schweizer Bundesfeiertag",
                       b.reg(r#"schweiz(?:er)? (?:bundes)?feiertag|bundes feiertag"#)?,
-                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration("swiss_national_day")))
     );
     b.rule_1_terminal("Augsburg Celebration",
                       b.reg(r#"augsburger hohe[smn] friedensfest"#)?,
-                      |_| Ok(helpers::month_day(8, 8)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(8, 8)?.form(Form::Celebration("augsburg_peace_festival")))
     );
     b.rule_1_terminal("assumption day",
                       b.reg(r#"mari[äa] himmelfahrt(?:stag)?"#)?,
-                      |_| Ok(helpers::month_day(8, 15)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(8, 15)?.form(Form::Celebration("assumption")))
     );
     b.rule_1_terminal("reformation day",
                       b.reg(r#"reformations(?:tag|fest)?"#)?,
-                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration("reformation_day")))
     );
     b.rule_1_terminal("All saint's day",
                       b.reg(r#"allerheiligen(?:tag)?"#)?,
-                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration("all_saints_day")))
     );
     b.rule_1_terminal("Holy Joseph",
                       b.reg(r#"sankt josef"#)?,
-                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration("saint_joseph")))
     );
     b.rule_1_terminal("Holy Florian",
                       b.reg(r#"sankt florian"#)?,
-                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration("saint_florian")))
     );
     b.rule_1_terminal("Holy Rupert",
                       b.reg(r#"sankt rupert"#)?,
-                      |_| Ok(helpers::month_day(9, 24)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(9, 24)?.form(Form::Celebration("saint_rupert")))
     );
     b.rule_1_terminal("German national celebration",
                       b.reg(r#"tag (?:der )?deutsc?hen? einheit"#)?,
-                      |_| Ok(helpers::month_day(10, 3)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(10, 3)?.form(Form::Celebration("german_unity_day")))
     );
     b.rule_1_terminal("Day of popular vote",
                       b.reg(r#"tag der volksabtimmun"#)?,
-                      |_| Ok(helpers::month_day(10, 10)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(10, 10)?.form(Form::Celebration("plebiscite_day")))
     );
     b.rule_1_terminal("Austrian national celebration",
                       b.reg(r#"(?:[öo]sterreichischer? )?nationalfeiertag|national feiertag"#)?,
-                      |_| Ok(helpers::month_day(10, 26)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(10, 26)?.form(Form::Celebration("austrian_national_day")))
     );
     b.rule_1_terminal("Armistice Celebration",
                       b.reg(r#"waffenstillstandserkl[äa]rung"#)?,
-                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration("armistice_day")))
     );
     b.rule_1_terminal("Holy Martin",
                       b.reg(r#"sankt martin|martinstag"#)?,
-                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration("saint_martin")))
     );
     b.rule_1_terminal("Holy Leopold",
                       b.reg(r#"sankt leopold"#)?,
-                      |_| Ok(helpers::month_day(11, 15)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(11, 15)?.form(Form::Celebration("saint_leopold")))
     );
     b.rule_1_terminal("Holy Joseph",
                       b.reg(r#"josefstag"#)?,
-                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(3, 19)?.form(Form::Celebration("saint_joseph")))
     );
     b.rule_1_terminal("Switzerland national celebration",
                       b.reg(r#"an der bundesfeier"#)?,
-                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(8, 1)?.form(Form::Celebration("swiss_national_day")))
     );
     b.rule_1_terminal("Berchtoldstag",
         b.reg(r#"berchtoldstag"#)?,
-        |_| Ok(helpers::month_day(1, 2)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(1, 2)?.form(Form::Celebration("berchtolds_day")))
     );
     b.rule_1_terminal("Immaculate conception",
                       b.reg(r#"mari[äa] empf[äa]ngnis"#)?,
-                      |_| Ok(helpers::month_day(12, 8)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 8)?.form(Form::Celebration("immaculate_conception")))
     );
     b.rule_1_terminal("Stephanie's day",
                       b.reg(r#"stefanitag"#)?,
-                      |_| Ok(helpers::month_day(12, 26)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 26)?.form(Form::Celebration("saint_stephens_day")))
     );
     b.rule_1_terminal("Women's day",
                       b.reg(r#"(?:internationale[rnm] )?frauentag"#)?,
-                      |_| Ok(helpers::month_day(3, 8)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(3, 8)?.form(Form::Celebration("womens_day")))
     );
 
     b.rule_1("Father's Day",  // third Sunday of June
         b.reg(r#"vatt?er(?: ?tag)?|(?:herren|m[äa]nner)tag"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("fathers_day")))
     );
     b.rule_1_terminal("Mother's Day",
                       b.reg(r#"mutt?ertag|mutt?er (?:tag)?"#)?,
                       |_| Ok(helpers::day_of_week(Weekday::Sun)?
                           .intersect(&helpers::month(5)?)?
                           .intersect(&helpers::cycle_nth_after(Grain::Week, 1, &helpers::month_day(5, 1)?)?)?
-                          .form(Form::Celebration))
+                          .form(Form::Celebration("mothers_day")))
     );
     b.rule_1_terminal("halloween day",
                       b.reg(r#"hall?owe?en?"#)?,
-                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration("halloween")))
     );
     b.rule_1_terminal("Allerheiligen",
                       b.reg(r#"allerheiligen?|aller heiligen?"#)?,
-                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration("all_saints_day")))
     );
     b.rule_1_terminal("Sunday of the dead (German protestant)",
         b.reg(r#"totensonntag"#)?,
         |_| Ok(helpers::day_of_week(Weekday::Sun)?
                           .intersect(&helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(11, 1)?)?)?
-                          .form(Form::Celebration))
+                          .form(Form::Celebration("sunday_of_the_dead")))
     );
 
     b.rule_1_terminal("Nikolaus",
                       b.reg(r#"nikolaus(?: ?tag|abend)?|nikolo"#)?,
-                      |_| Ok(helpers::month_day(12, 6)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(12, 6)?.form(Form::Celebration("saint_nicholas_day")))
     );
 
     b.rule_2("<ordinal> advent sunday",
@@ -850,7 +936,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             let offset = - (4 - ordinal.value().value + 1);
             Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
                 .intersect(&helpers::day_of_week(Weekday::Sun)?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("advent_sunday")))
         }    
     );
     //Volkstrauertag
@@ -862,7 +948,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             let offset = -6;
             Ok(helpers::cycle_nth_after(Grain::Week, offset, &christmas)?
                 .intersect(&helpers::day_of_week(Weekday::Sun)?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("national_day_of_mourning")))
         }    
     );
 
@@ -1275,155 +1361,98 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("breakfast (latent)",
                       b.reg(r#"fr[üu]hst[üu]ck(?:szeit|spause|s)?"#)?,
-                      |_| Ok(helpers::hour(6, false)?
-                          .span_to(&helpers::hour(9, false)?, false)?
-                          .latent()
-                          .form(Form::Meal))
+                      |_| Ok(helpers::meal(MealForm::Breakfast, 6, 9)?.latent())
     );
     b.rule_1("lunch (latent)",
              b.reg(r#"mittag(?:szeit|pause|essen(?:szeit)?)"#)?,
-             |_| Ok(helpers::hour(12, false)?
-                 .span_to(&helpers::hour(14, false)?, false)?
-                 .latent()
-                 .form(Form::Meal))
+             |_| Ok(helpers::meal(MealForm::Lunch, 12, 14)?.latent())
     );
 
     b.rule_1_terminal("lunch",
                       b.reg(r#"mittags"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
-                          .form(Form::Meal))
+                      |_| helpers::meal(MealForm::Lunch, 12, 14)
     );
     b.rule_1_terminal("coffee break",
         b.reg(r#"kaffee"#)?,
-        |_| Ok(helpers::hour(15, false)?
-                .span_to(&helpers::hour(17, false)?, false)?
-                .too_ambiguous()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Snack, 15, 17)?
+                .too_ambiguous())
     );
     b.rule_1_terminal("dinner",
                       b.reg(r#"dinner|souper|abendessen(?:szeit)?|abendbrot(?:zeit)?|vesper(?:zeit)?|brotzeit"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(20, false)?, false)?
-                          .latent()
-                          .form(Form::Meal))
+                      |_| Ok(helpers::meal(MealForm::Dinner, 18, 20)?.latent())
     );
     b.rule_1_terminal("early morning",
                       b.reg(r#"fr[üu]hen vormittag|tagesanbruch|morgen(?:grauen|fr[üu]he)|fr[üu]h(?:en )?morgens?|am morgen fruh"#)?,
-                      |_| Ok(helpers::hour(4, false)?
-                          .span_to(&helpers::hour(9, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Morning)).latent())
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 5, 10, 4, 9)?.latent())
     );
     b.rule_1_terminal("morning (latent)",
         b.reg(r#"morgens|fr[üu]he?|am morgen"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+        |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 5, 10)?.latent())
     );
     b.rule_1_terminal("late morning",
                       b.reg(r#"(?:kurz|am sp[äa]ten) vor ?mittag|vormittag(?:s(?:zeit)?)?"#)?,
-                      |_| Ok(helpers::hour(10, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                      |_| helpers::part_of_day_portion(PartOfDayForm::Morning, 5, 10, 10, 12)
     );
     b.rule_1_terminal("early afternoon (latent)",
                       b.reg(r#"fr[üu]hen nachmittags?(?:stunden?)?"#)?,
-                      |_| Ok(helpers::hour(13, false)?
-                          .span_to(&helpers::hour(17, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Afternoon, 13, 19, 13, 17)?.latent())
     );
     b.rule_1_terminal("early afternoon",
                       b.reg(r#"nach dem mittagessen|kurz nach mittag"#)?,
-                      |_| Ok(helpers::hour(13, false)?
-                          .span_to(&helpers::hour(16, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      |_| helpers::part_of_day_portion(PartOfDayForm::Afternoon, 13, 19, 13, 16)
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"nach ?mittags?"#)?,
-                      |_| Ok(helpers::hour(13, false)?
-                          .span_to(&helpers::hour(19, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      |_| helpers::part_of_day(PartOfDayForm::Afternoon, 13, 19)
     );
     b.rule_1_terminal("late afternoon (latent)",
                       b.reg(r#"sp[äa]t(?:e[nr] )?nachmittags?(?:stunden?)?"#)?,
-                      |_| Ok(helpers::hour(17, false)?
-                          .span_to(&helpers::hour(19, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Afternoon, 13, 19, 17, 19)?.latent())
     );
     b.rule_1_terminal("early evening (latent)",
                       b.reg(r#"fr[üu]h am abend|abend fr[üu]h"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(21, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 18, 21)?.latent())
     );
     b.rule_1_terminal("early evening",
                       b.reg(r#"fr[üu]he[nr] abend(?:stunden?)?|abends fr[üu]h"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(21, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 18, 21)
     );
     b.rule_1_terminal("evening (latent)",
                       b.reg(r#"abend(?:zeit)?"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"abends"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| helpers::part_of_day(PartOfDayForm::Evening, 18, 0)
     );
     b.rule_1_terminal("late evening (latent)",
                       b.reg(r#"sp[äa]te[nr] abend(?:stunden?)?|abend sp[äa]t"#)?,
-                      |_| Ok(helpers::hour(21, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 21, 0)?.latent())
     );
     b.rule_1_terminal("late evening",
                       b.reg(r#"sp[äa]t(?: am )?abends?|abends sp[äa]t|sp[äa]t abends"#)?,
-                      |_| Ok(helpers::hour(21, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 21, 0)
     );
     b.rule_1_terminal("early night (latent)",
                       b.reg(r#"fr[üu]he[nr]? nacht(?:stunden?)?"#)?,
-                      |_| Ok(helpers::hour(21, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Night, 0, 4, 21, 0)?.latent())
     );
 
     b.rule_1_terminal("early night",
                       b.reg(r#"fr[üu]h in der nacht|fr[üu]h nachts"#)?,
-                      |_| Ok(helpers::hour(21, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| helpers::part_of_day_portion(PartOfDayForm::Night, 0, 4, 21, 0)
     );
     b.rule_1_terminal("night (latent)",
                       b.reg(r#"nacht"#)?,
-                      |_| Ok(helpers::hour(0, false)?
-                          .span_to(&helpers::hour(4, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Night, 0, 4)?.latent())
     );
     b.rule_1_terminal("night",
                       b.reg(r#"nachts"#)?,
-                      |_| Ok(helpers::hour(0, false)?
-                          .span_to(&helpers::hour(4, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| helpers::part_of_day(PartOfDayForm::Night, 0, 4)
     );
     b.rule_1_terminal("late night",
                       b.reg(r#"(?:sp[äa]t|tief)(?: in der)? ?nachts?"#)?,
-                      |_| Ok(helpers::hour(0, false)?
-                          .span_to(&helpers::hour(4, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| helpers::part_of_day(PartOfDayForm::Night, 0, 4)
     );
 
     b.rule_2("<time-of-day> morning",
@@ -1522,7 +1551,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("around <meal/celebration>",
              b.reg("um die")?,
-             time_check!(|time: &TimeValue| form!(Form::Celebration)(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::Celebration(_))(time) || form!(Form::Meal(_))(time)),
              |_, time| Ok(time.value().clone().precision(Precision::Approximate))
     );
 
@@ -1534,7 +1563,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("this <part-of-day>",
         b.reg(r#"diese[snm]?|heute"#)?,
-        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
         |_, time| Ok(helpers::cycle_nth(Grain::Day, 0)?
             .intersect(time.value())?
             .form(time.value().form.clone()))
@@ -1542,8 +1571,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("tonight",
         b.reg(r#"heute? (?:am)? abends?"#)?,
         |_| Ok(helpers::cycle_nth(Grain::Day, 0)?
-            .intersect(&helpers::hour(18, false)?
-                .span_to(&helpers::hour(0, false)?, false)?)?
+            .intersect(&helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?)?
             .form(Form::PartOfDay(PartOfDayForm::Night)))
     );
     b.rule_1_terminal("after work",
@@ -1555,13 +1583,13 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("<time> <part-of-day/meal>", // There are rules for <time-of-day> and <part-of-day>
         time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::TimeOfDay(_))(time) && excluding_form!(Form::Month(_))(time)),
-        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
         |time, pod| time.value().intersect(pod.value())
     );
 
     b.rule_3("<time> <meal> <time>", // There are rules for <time-of-day> and <part-of-day>
         time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
         |a, pod, b| a.value().intersect(b.value())?.intersect(pod.value())
     );
@@ -1595,24 +1623,18 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     // b.rule_2("<part-of-day/meal> <time>", // There are rules for <time-of-day> and <part-of-day>
     //     time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::TimeOfDay(_))(time) && excluding_form!(Form::Month(_))(time)),
-    //     time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+    //     time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
     //     |pod, time| time.value().intersect(pod.value())
     // );
     b.rule_3("<part-of-day/meal> of <time>", // There are rules for <time-of-day> and <part-of-day>
-        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+        time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
         b.reg(r#"de[sr]|vo[nm]|am"#)?,
         time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) &&  excluding_form!(Form::TimeOfDay(_))(time) && excluding_form!(Form::Month(_))(time)),
         |pod, _, time| time.value().intersect(pod.value())
     );
     b.rule_1_terminal("week-end",
         b.reg(r#"wochen ?enden?"#)?,
-        |_| {
-            let friday = helpers::day_of_week(Weekday::Fri)?
-                .intersect(&helpers::hour(18, false)?)?;
-            let monday = helpers::day_of_week(Weekday::Mon)?
-                .intersect(&helpers::hour(0, false)?)?;
-            friday.span_to(&monday, false)
-        }
+        |_| helpers::weekend(true)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"sommer(?:zeit|s)?"#)?,
@@ -1622,7 +1644,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("Summer solstice",
         b.reg(r#"sommersonn(?:en)?wende"#)?,
-        |_| Ok(helpers::month_day(6, 21)?.form(Form::Celebration).too_ambiguous())
+        |_| Ok(helpers::month_day(6, 21)?.form(Form::Celebration("summer_solstice")).too_ambiguous())
     );
     b.rule_1_terminal("season",
                       b.reg(r#"herbst(?:zeit|s|es)?|sp[äa]tjahr(?:es)?"#)?,
@@ -1638,7 +1660,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("Winter solstice",
         b.reg(r#"wintersonnwende"#)?,
-        |_| Ok(helpers::month_day(12, 21)?.form(Form::Celebration).too_ambiguous())
+        |_| Ok(helpers::month_day(12, 21)?.form(Form::Celebration("winter_solstice")).too_ambiguous())
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:fr[üu]hlings?|fr[üu]hjahr(?:es)?)(?:zeit)?"#)?,
@@ -1953,32 +1975,68 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt|mez|mesz)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("deutsche Zeit",
+                      b.reg(r#"(?:deutscher|berliner|mitteleurop[äa]ischer) zeit"#)?,
+                      |_| helpers::timezone("europe/berlin")
+    );
+    b.rule_1_terminal("Londoner Zeit",
+                      b.reg(r#"(?:londoner|britischer) zeit"#)?,
+                      |_| helpers::timezone("europe/london")
+    );
+    b.rule_1_terminal("New Yorker Zeit",
+                      b.reg(r#"(?:new yorker|ostk[üu]sten)[ -]?zeit"#)?,
+                      |_| helpers::timezone("america/new_york")
+    );
+    b.rule_1_terminal("Pazifikzeit",
+                      b.reg(r#"pazifik[ -]?zeit|westk[üu]sten[ -]?zeit"#)?,
+                      |_| helpers::timezone("america/los_angeles")
+    );
+    b.rule_1_terminal("japanische Zeit",
+                      b.reg(r#"(?:japanischer|tokioter) zeit"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<article> temp",
              b.reg(r#"bei|auf"#)?,
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              |_, temp| Ok(TemperatureValue {
                  value: temp.value().value,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temperature> plus",
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              b.reg(r#"plus"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temperature> minus",
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              b.reg(r#"minus"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: -1.0 * temp.value().value,
                  unit: temp.value().unit,
                  latent: temp.value().latent,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_1("number as temp",
@@ -1987,17 +2045,19 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value(),
                  unit: None,
                  latent: true,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("below <temp>",
              b.reg(r#"minus"#)?,
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              |_, temp| {
                  if temp.value().value >= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: temp.value().latent,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2005,7 +2065,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              }
     );
     b.rule_2("<temp> below",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"unter(?:m| de[mn])? (?:gefrierpunkt|null|0)"#)?,
              |temp, _| {
                  if temp.value().value >= 0.0 {
@@ -2013,6 +2073,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2021,13 +2082,14 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     );
     b.rule_2("above <temp>",
              b.reg(r#"plus"#)?,
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              |_, temp| {
                  if temp.value().value <= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2035,7 +2097,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              }
     );
     b.rule_2("<temp> above",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"[üu]ber(?:m| de[mn])? (?:gefrierpunkt|null| 0)"#)?,
              |temp, _| {
                  if temp.value().value <= 0.0 {
@@ -2043,6 +2105,7 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -2050,50 +2113,353 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              }
     );
     b.rule_2("<latent temp> degrees",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"grade?s?|°"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("degree"),
+                 unit: Some(TemperatureUnit::Degree),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> celsius",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"c(?:elsius)?\.?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("celsius"),
+                 unit: Some(TemperatureUnit::Celsius),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> kelvin",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"k(?:elvin)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("kelvin"),
+                 unit: Some(TemperatureUnit::Kelvin),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> fahrenheit",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f(?:ahrenheit)?"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> °F",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f"#)?,
              |temp, _| Ok(TemperatureValue {
                  value: temp.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
+    b.rule_1_terminal("unter dem gefrierpunkt",
+                      b.reg(r#"unter(?:m| de[mn])? (gefrierpunkt|null)"#)?,
+                      |text_match| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: if text_match.group(1) == "gefrierpunkt" { Some(TemperatureUnit::Celsius) } else { None },
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("zwischen <temp> und <temp>",
+             b.reg(r#"zwischen"#)?,
+             temperature_check!(),
+             b.reg(r#"und"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("von <temp> bis <temp>",
+             b.reg(r#"von"#)?,
+             temperature_check!(),
+             b.reg(r#"bis"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-|bis"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("unter <temp>",
+             b.reg(r#"unter|weniger als|h[öo]chstens|bis zu|maximal|nicht mehr als|k[äa]lter als"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> oder weniger",
+             temperature_check!(),
+             b.reg(r#"oder weniger|maximal"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("über <temp>",
+             b.reg(r#"[üu]ber|mehr als|mindestens|wenigstens|ab|minimal|w[äa]rmer als"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> oder mehr",
+             temperature_check!(),
+             b.reg(r#"oder mehr|und mehr"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> Kilometer",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"km|kilometer[ns]?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilometre))
+    );
+    b.rule_2("<number> Meter",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"m|meter[ns]?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Metre))
+    );
+    b.rule_2("<number> Meile",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"meilen?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Mile))
+    );
+    b.rule_2("<number> Fuß",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ft|fu(?:ß|ss)"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Foot))
+    );
+    b.rule_2("<number> Liter",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"l|liter[ns]?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Litre))
+    );
+    b.rule_2("<number> Milliliter",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ml|milliliter[ns]?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Millilitre))
+    );
+    b.rule_2("<number> Tasse",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"tassen?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Cup))
+    );
+    b.rule_2("<number> Gallone",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"gallonen?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gallon))
+    );
+    b.rule_2("<number> Kilogramm",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"kg|kilo(?:gramm)?s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilogram))
+    );
+    b.rule_2("<number> Gramm",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"g|gr|gramms?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gram))
+    );
+    b.rule_2("<number> Pfund (Masse)",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"lbs?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Pound))
+    );
+    b.rule_2("<number> Unze",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"oz|unzen?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Ounce))
+    );
+    b.rule_2("about <quantity>",
+             b.reg(r#"[cz]irka|nahezu|beinahe|ungef[äa]hr|fast|ca\.?"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"(?:haar|ganz |sehr )?genau|exakt|gerade|pr[äa]zise"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("phone number (spoken digits)",
+                      b.reg(r#"(?:plus\s+)?(?:(?:doppel(?:te?)?)\s+)?(?:null|eins|zwei|zwo|drei|vier|f[üu]nf|sechs|sieben|acht|neun)(?:[\s-]+(?:(?:doppel(?:te?)?)\s+)?(?:null|eins|zwei|zwo|drei|vier|f[üu]nf|sechs|sieben|acht|neun)){6,14}"#)?,
+                      |text_match| helpers::spoken_phone_number(
+                          text_match.group(0),
+                          |word| match word {
+                              "plus" => Some("+"),
+                              "null" => Some("0"),
+                              "eins" => Some("1"),
+                              "zwei" | "zwo" => Some("2"),
+                              "drei" => Some("3"),
+                              "vier" => Some("4"),
+                              "fünf" | "funf" => Some("5"),
+                              "sechs" => Some("6"),
+                              "sieben" => Some("7"),
+                              "acht" => Some("8"),
+                              "neun" => Some("9"),
+                              _ => None,
+                          },
+                          |word| match word {
+                              "doppel" | "doppelt" | "doppelte" => Some(2),
+                              _ => None,
+                          })
+    );
+    Ok(())
+}
+
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("zwischen <number> und <number>",
+             b.reg(r#"zwischen"#)?,
+             number_check!(),
+             b.reg(r#"und|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("von <number> bis <number>",
+             b.reg(r#"von"#)?,
+             number_check!(),
+             b.reg(r#"bis|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number> bis <number>",
+             number_check!(),
+             b.reg(r#"bis"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("mindestens <number>",
+             b.reg(r#"mindestens|wenigstens|minimum|nicht weniger als"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number> oder mehr",
+             number_check!(),
+             b.reg(r#"oder mehr|und mehr|\+"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("mehr als <number>",
+             b.reg(r#"mehr als|[üu]ber|gr[öo](?:ß|ss)er als"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("höchstens <number>",
+             b.reg(r#"h[öo]chstens|bis zu|maximal|nicht mehr als"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number> oder weniger",
+             number_check!(),
+             b.reg(r#"oder weniger"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("weniger als <number>",
+             b.reg(r#"weniger als|unter|kleiner als"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("stündlich",
+                      b.reg(r#"st[üu]ndlich"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("täglich",
+                      b.reg(r#"t[äa]glich"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("wöchentlich",
+                      b.reg(r#"w[öo]chentlich"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("monatlich",
+                      b.reg(r#"monatlich"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("vierteljährlich",
+                      b.reg(r#"viertelj[äa]hrlich|quartalsweise"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Quarter))
+    );
+    b.rule_1_terminal("jährlich",
+                      b.reg(r#"j[äa]hrlich"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_2("jede <cycle>",
+             b.reg(r#"jede[nrs]?"#)?,
+             cycle_check!(),
+             |_, a| Ok(RecurrenceValue::new(a.value().grain))
+    );
+    b.rule_3("alle <integer> <cycle>",
+             b.reg(r#"alle"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, n, a| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_3("jede zweite <cycle>",
+             b.reg(r#"jede[nrs]?"#)?,
+             b.reg(r#"zweite[nrs]?"#)?,
+             cycle_check!(),
+             |_, _, a| RecurrenceValue::new(a.value().grain).every(2)
+    );
+    b.rule_2("jeden <day-of-week>",
+             b.reg(r#"jede[nrs]?"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("jeden zweiten <day-of-week>",
+             b.reg(r#"jede[nrs]?"#)?,
+             b.reg(r#"zweite[nrs]?"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, _, a| RecurrenceValue::new(Grain::Week).every(2)?.at(a.value())
+    );
+    b.rule_3("<recurrence> und <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"und|,"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence> <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<recurrence> um <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             b.reg(r#"um|gegen"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_3("<recurrence> ab <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             b.reg(r#"ab(?: de[mn]| dem)?|beginnend(?: a[mn])?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().starting(b.value())
+    );
+    b.rule_3("<recurrence> bis <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             b.reg(r#"bis(?: zu[mr]| (?:e|i)nde)?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().until(b.value())
+    );
+    Ok(())
+}
+
+/// Month names of Austrian German, "Jänner" being already known to all regions
+pub fn rules_time_at(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("named-month (AT)",
+                      b.reg(r#"febers?"#)?,
+                      |_| helpers::month(2)
+    );
     Ok(())
 }
 
@@ -2271,6 +2637,30 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.suffixed()),
              |a, _, b| FloatValue::new(b.value().value() * 0.1 + a.value().value())
     );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(halbe?|drittel|viertel|f[üu]nftel|sechstel|siebtel|achtel|neuntel|zehntel)"#)?,
+             |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> einhalb",
+             integer_check_by_range!(0, 99),
+             b.reg(r#"ein ?halb"#)?,
+             |integer, _| helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, 2)?)
+    );
+    b.rule_1_terminal("anderthalb",
+                      b.reg(r#"anderthalb"#)?,
+                      |_| FloatValue::new_fraction(3, 2)
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+                      |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+                      b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+                      |text_match| helpers::compose_mixed_fraction(
+                          text_match.group(1).parse()?,
+                          &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
+    );
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
@@ -2292,6 +2682,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -2470,3 +2861,18 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word {
+        "halb" | "halbe" => Ok(2),
+        "drittel" => Ok(3),
+        "viertel" => Ok(4),
+        "fünftel" | "funftel" => Ok(5),
+        "sechstel" => Ok(6),
+        "siebtel" => Ok(7),
+        "achtel" => Ok(8),
+        "neuntel" => Ok(9),
+        "zehntel" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
@@ -4,29 +4,36 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(10.0, Some("degree")), "10 Grad");
+    example!(v, check_temperature(10.0, Some(TemperatureUnit::Degree)), "10 Grad");
     example!(v, check_temperature(-20.0, None), "minus 20");
-    example!(v, check_temperature(-20.0, Some("degree")), "minus 20 Grad");
-    example!(v, check_temperature(3.0, Some("degree")), "plus 3 Grad");
-    example!(v, check_temperature(71.0, Some("degree")), "71 Grad");
-    example!(v, check_temperature(-7.0, Some("degree")), "sieben Grad unter null");
+    example!(v, check_temperature(-20.0, Some(TemperatureUnit::Degree)), "minus 20 Grad");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "plus 3 Grad");
+    example!(v, check_temperature(71.0, Some(TemperatureUnit::Degree)), "71 Grad");
+    example!(v, check_temperature(-7.0, Some(TemperatureUnit::Degree)), "sieben Grad unter null");
     example!(v, check_temperature(-10.0, None), "10 unterm gefrierpunkt");
-    example!(v, check_temperature(5.0, Some("degree")), "5 grad über null");
-    example!(v, check_temperature(20.0, Some("degree")), "20 grad über null");
-    example!(v, check_temperature(15.0, Some("celsius")), "15 C", "15°C");
-    example!(v, check_temperature(23.0, Some("degree")), "plus 23 °", "23°");
-    example!(v, check_temperature(-24.0, Some("degree")), "minus 24 grad");
-    example!(v, check_temperature(-15.0, Some("degree")), "15 grad unter den gefrierpunkt");
-    example!(v, check_temperature(-34.0, Some("degree")), "34 grad unterm gefrierpunkt");
-    example!(v, check_temperature(13.0, Some("degree")), "plus 13 grad");
-    example!(v, check_temperature(130.0, Some("kelvin")), "130 kelvin");
-    example!(v, check_temperature(223.0, Some("kelvin")), "223 k");
-    example!(v, check_temperature(78.0, Some("fahrenheit")), "78 fahrenheit", "78 f");
-    example!(v, check_temperature(19.0, Some("degree")), "19 grad über null");
-    example!(v, check_temperature(-18.0, Some("degree")), "Bei -18 Grad");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Degree)), "5 grad über null");
+    example!(v, check_temperature(20.0, Some(TemperatureUnit::Degree)), "20 grad über null");
+    example!(v, check_temperature(15.0, Some(TemperatureUnit::Celsius)), "15 C", "15°C");
+    example!(v, check_temperature(23.0, Some(TemperatureUnit::Degree)), "plus 23 °", "23°");
+    example!(v, check_temperature(-24.0, Some(TemperatureUnit::Degree)), "minus 24 grad");
+    example!(v, check_temperature(-15.0, Some(TemperatureUnit::Degree)), "15 grad unter den gefrierpunkt");
+    example!(v, check_temperature(-34.0, Some(TemperatureUnit::Degree)), "34 grad unterm gefrierpunkt");
+    example!(v, check_temperature(13.0, Some(TemperatureUnit::Degree)), "plus 13 grad");
+    example!(v, check_temperature(130.0, Some(TemperatureUnit::Kelvin)), "130 kelvin");
+    example!(v, check_temperature(223.0, Some(TemperatureUnit::Kelvin)), "223 k");
+    example!(v, check_temperature(78.0, Some(TemperatureUnit::Fahrenheit)), "78 fahrenheit", "78 f");
+    example!(v, check_temperature(19.0, Some(TemperatureUnit::Degree)), "19 grad über null");
+    example!(v, check_temperature(-18.0, Some(TemperatureUnit::Degree)), "Bei -18 Grad");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "zwischen 18 und 22 grad", "von 18 bis 22 grad", "18-22 grad");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "über 30 grad", "mindestens 30 grad");
+    example!(v, check_temperature_interval(None, Some(0.0), Some(TemperatureUnit::Celsius)), "unter dem gefrierpunkt");
+    example!(v, check_temperature_interval(None, Some(5.0), Some(TemperatureUnit::Celsius)), "unter 5 °C", "5 celsius oder weniger");
 }
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "zwischen 10 und 20 euro", "10-20 euro", "von 10 euro bis 20 euro");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "mehr als 100 euro", "mindestens hundert euro");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "unter 50 dollar", "höchstens 50 dollar");
     example!(v, check_finance(30.0, Some("EUR"), Precision::Exact), "dreissig Euro");
     example!(v, check_finance(800.0, Some("$"), Precision::Exact), "800 dollar");
     example!(v, check_finance(800.0, Some("cent"), Precision::Exact), "achthundert cent");
@@ -241,6 +248,14 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 13, 44]), "ein uhr und vierundvierzig minuten");
     example!(v, check_moment_span!(c, [2013, 2, 11, 1, 9], [2013, 2, 11, 3, 26]), "gestern zwischen ein uhr und neun minuten und drei uhr und fünfundzwanzig minuten");
     example!(v, check_moment_span!(c, [2013, 2, 13, 00, 45], [2013, 2, 13, 2, 00]), "zwischen dreiviertel eins und ein uhr morgen");
+    example!(v, check_timezone("UTC"), "um 15 Uhr UTC", "15 uhr gmt");
+    example!(v, check_timezone("Europe/Berlin"), "um 9 Uhr deutscher Zeit");
+}
+
+pub fn examples_time_at(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_moment!(c, [2013, 2, 15]), "15. feber", "am 15. feber");
+    example!(v, check_moment!(c, [2014, 1, 3]), "3. jänner");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -257,6 +272,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(102), "102", "hundert zwei");
     example!(v, check_float(1.1), "1,1", "1 komma 1", "1,10", "01,10");
     example!(v, check_float(0.77), "0,77", ",77");
+    example!(v, check_fraction(2, 3), "zwei drittel", "zweidrittel", "2/3");
+    example!(v, check_fraction(5, 2), "zweieinhalb", "zwei einhalb", "2 1/2");
+    example!(v, check_fraction(3, 2), "anderthalb", "eineinhalb");
     example!(v, check_integer(100000), "100.000", "100000", "100K", "100k");
     example!(v, check_integer(3000000), "3M", "3000K", "3000000", "3.000.000");
     example!(v, check_integer(1200000), "1.200.000", "1200000", "1,2M", "1200K", ",0012G");
@@ -272,4 +290,50 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1000000.0), "1.000.000,00");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
-}
\ No newline at end of file
+}
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "zwischen 3 und 5", "zwischen 3-5", "von 3-5", "von drei bis fünf", "3 bis 5");
+    example!(v, check_number_interval(Some(3.0), None), "mindestens 3", "drei oder mehr");
+    example!(v, check_number_interval(None, Some(10.0)), "bis zu 10", "höchstens zehn");
+    example!(v, check_number_interval(Some(100.0), None), "mehr als 100", "über hundert");
+    example!(v, check_number_interval(None, Some(4.0)), "weniger als 4", "weniger als vier");
+}
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(5.0, QuantityUnit::Kilometre, Precision::Exact), "5 km", "fünf Kilometer");
+    example!(v, check_quantity(3.0, QuantityUnit::Mile, Precision::Exact), "3 Meilen", "drei Meilen");
+    example!(v, check_quantity(100.0, QuantityUnit::Metre, Precision::Exact), "100 m", "hundert Meter");
+    example!(v, check_quantity(6.0, QuantityUnit::Foot, Precision::Exact), "6 ft", "sechs Fuß");
+    example!(v, check_quantity(2.0, QuantityUnit::Litre, Precision::Exact), "2 l", "zwei Liter");
+    example!(v, check_quantity(250.0, QuantityUnit::Millilitre, Precision::Exact), "250 ml", "250 Milliliter");
+    example!(v, check_quantity(2.0, QuantityUnit::Cup, Precision::Exact), "2 Tassen", "zwei Tassen");
+    example!(v, check_quantity(10.0, QuantityUnit::Gallon, Precision::Exact), "10 Gallonen", "zehn Gallonen");
+    example!(v, check_quantity(3.0, QuantityUnit::Kilogram, Precision::Exact), "3 kg", "drei Kilo", "drei Kilogramm");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Precision::Exact), "500 g", "fünfhundert Gramm");
+    example!(v, check_quantity(3.0, QuantityUnit::Pound, Precision::Exact), "3 lbs");
+    example!(v, check_quantity(8.0, QuantityUnit::Ounce, Precision::Exact), "8 oz", "acht Unzen");
+    example!(v, check_quantity(10.0, QuantityUnit::Kilometre, Precision::Approximate), "ungefähr 10 km", "circa zehn Kilometer");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("john.doe@example.com"), "john.doe@example.com");
+    example!(v, check_url("https://www.example.com/path?q=1", "example.com"), "https://www.example.com/path?q=1");
+    example!(v, check_url("example.org", "example.org"), "example.org");
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78");
+    example!(v, check_phone_number("030123456"), "null drei null eins zwei drei vier fünf sechs", "030 123456");
+    example!(v, check_phone_number("01722233"), "null eins sieben zwo doppel zwei drei drei");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::hours(2).into()), Some(PeriodComp::hours(3).into())), "zwei bis drei stunden", "zwischen 2 und 3 stunden", "2-3 stunden");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "mindestens 20 minuten", "20 minuten oder mehr");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "weniger als eine woche", "höchstens 1 woche");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(7)), "jeden Tag um 7", "täglich um 7 Uhr");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "jeden Montag");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "jede zweite Woche", "alle zwei Wochen", "alle 2 Wochen");
+    example!(v, check_recurrence(Grain::Month, 1, vec![], None), "monatlich", "jeden Monat");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Tue, Weekday::Thu], None), "jeden Dienstag und Donnerstag");
+}
@@ -13,24 +13,40 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    rules::rules_number_interval(&mut b)?;
     rules::rules_time(&mut b)?;
+    rules::rules_timezone(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
+    rules::rules_quantity(&mut b)?;
+    rules::rules_phone_number(&mut b)?;
+    rustling_ontology_values::common_rules::rules_email(&mut b)?;
+    rustling_ontology_values::common_rules::rules_url(&mut b)?;
+    rustling_ontology_values::common_rules::rules_phone_number(&mut b)?;
     Ok(b.build())
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage, Quantity, Email, Url, PhoneNumber, NumberInterval, DurationInterval, Recurrence];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     let mut v = vec![];
     training::examples_numbers(&mut v);
+    training::examples_number_interval(&mut v);
+    training::examples_duration_interval(&mut v);
+    training::examples_recurrence(&mut v);
     training::examples_time(&mut v);
     training::examples_durations(&mut v);
+    training::examples_temperature(&mut v);
+    training::examples_finance(&mut v);
+    training::examples_percentage(&mut v);
+    training::examples_quantity(&mut v);
+    training::examples_contact(&mut v);
     v
 }
 
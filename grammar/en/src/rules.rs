@@ -4,7 +4,8 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_values::currency::names;
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, DateOrder};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -65,6 +66,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> more <unit-of-duration>",
              integer_check_by_range!(0),
              b.reg(r#"more|less"#)?,
@@ -207,6 +213,66 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |_, duration| Ok(duration.value().clone().precision(Precision::Exact))
     );
 
+    b.rule_4("between <duration> and <duration>",
+             b.reg(r#"between"#)?,
+             duration_check!(),
+             b.reg(r#"and"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("between <number> and <duration>",
+             b.reg(r#"between"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"and"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("from <duration> to <duration>",
+             b.reg(r#"from"#)?,
+             duration_check!(),
+             b.reg(r#"to|till|until"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("from <number> to <duration>",
+             b.reg(r#"from"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"to|till|until"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|to|or"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|to|or"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("at least <duration>",
+             b.reg(r#"at least|more than|over|no less than|minimum(?: of)?|min\.?"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> or more",
+             duration_check!(),
+             b.reg(r#"or (?:more|longer)|and (?:more|up)|\+"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("less than <duration>",
+             b.reg(r#"less than|under|at most|up to|no more than|maximum(?: of)?|max\.?"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> or less",
+             duration_check!(),
+             b.reg(r#"or (?:less|shorter)|max(?:imum)?"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
     Ok(())
 }
 
@@ -358,19 +424,48 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         "<ordinal> quarter",
         ordinal_check!(),
         cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
-        |ordinal, _| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+        |ordinal, _| helpers::fiscal_quarter(ordinal.value().value, &helpers::fiscal_year_nth(0)?)
     );
     b.rule_3("the <ordinal> quarter",
              b.reg(r#"the"#)?,
              ordinal_check!(),
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
-             |_, ordinal, _| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, &helpers::cycle_nth(Grain::Year, 0)?)
+             |_, ordinal, _| helpers::fiscal_quarter(ordinal.value().value, &helpers::fiscal_year_nth(0)?)
     );
     b.rule_3("<ordinal> quarter <year>",
              ordinal_check!(),
              cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
              time_check!(),
-             |ordinal, _, time| helpers::cycle_nth_after(Grain::Quarter, ordinal.value().value - 1, time.value())
+             |ordinal, _, time| helpers::fiscal_quarter(ordinal.value().value, time.value())
+    );
+    b.rule_1_terminal("Q<n>",
+                      b.reg(r#"q([1-4])"#)?,
+                      |text_match| helpers::fiscal_quarter(text_match.group(1).parse()?, &helpers::fiscal_year_nth(0)?)
+    );
+    b.rule_2("Q<n> <year>",
+             b.reg(r#"q([1-4])"#)?,
+             time_check!(form!(Form::Year(_))),
+             |text_match, time| helpers::fiscal_quarter(text_match.group(1).parse()?, time.value())
+    );
+    b.rule_1_terminal("Q<n> FY<year>",
+                      b.reg(r#"q([1-4]) (?:fy|fiscal year) ?'?(\d{2}|\d{4})"#)?,
+                      |text_match| helpers::fiscal_quarter(text_match.group(1).parse()?,
+                                                           &helpers::fiscal_year(text_match.group(2).parse()?)?)
+    );
+    b.rule_1_terminal("this|last|next fiscal year",
+                      b.reg(r#"(this|current|last|previous|next) (?:fiscal|financial) year"#)?,
+                      |text_match| {
+                          let n = match text_match.group(1).as_ref() {
+                              "last" | "previous" => -1,
+                              "next" => 1,
+                              _ => 0,
+                          };
+                          helpers::fiscal_year_nth(n)
+                      }
+    );
+    b.rule_1_terminal("FY<year>",
+                      b.reg(r#"(?:fy|(?:fiscal|financial) year) ?'?(\d{2}|\d{4})"#)?,
+                      |text_match| helpers::fiscal_year(text_match.group(1).parse()?)
     );
     Ok(())
 }
@@ -406,7 +501,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("for <date>",
              b.reg(r#"for"#)?,
-             time_check!(form!(Form::Meal)),
+             time_check!(form!(Form::Meal(_))),
              |_, a| Ok(a.value().clone().not_latent())
     );
     b.rule_2("on a <named-day>",
@@ -534,42 +629,42 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("Palm sunday",
         b.reg(r#"(?:palm|passion) sunday"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -7, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("palm_sunday")))
     );
     b.rule_1_terminal("Holy Thursday",
         b.reg(r#"(?:holy|maundy) thursday"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -3, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("holy_thursday")))
     );
     b.rule_1_terminal("Holy Friday",
         b.reg(r#"good friday"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -2, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("good_friday")))
     );
     b.rule_1_terminal("Holy Saturday",
         b.reg(r#"(?:holy|black) saturday|easter vigil"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -1, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("holy_saturday")))
     );
     b.rule_1_terminal("Easter",
         b.reg(r#"easter sunday"#)?,
-        |_| Ok(helpers::easter()?.form(Form::Celebration))
+        |_| Ok(helpers::easter()?.form(Form::Celebration("easter")))
     );
     b.rule_1_terminal("Easter Monday",
         b.reg(r#"easter monday"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("easter_monday")))
     );
     b.rule_1_terminal("Ascension",
         b.reg(r#"(?:(?:the )?feast of (?:the )?)?ascension(?: holiday|thursday|day)?"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("ascension")))
 
     );
     b.rule_1_terminal("Pentecost",
         b.reg(r#"(?:(?:the )?(?:feast|day) of )?pentecost"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 49, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("pentecost")))
     );
     b.rule_1_terminal("memorial day",
                       b.reg(r#"memorial day"#)?,
@@ -1114,11 +1209,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                     true)?.precision(a.value().precision))
     );
     b.rule_1_terminal("mm/.dd/.yyyy",
-                      b.reg(r#"(0?[1-9]|1[0-2])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
-                      |text_match| helpers::ymd(
-                          text_match.group(3).parse()?,
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
-                          text_match.group(2).parse()?)
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::MonthDay)
     );
     b.rule_1_terminal("yyyy-mm-dd",
                       b.reg(r#"(\d{2,4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
@@ -1129,34 +1225,27 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_1_terminal("mm/dd",
-                      b.reg(r#"(0?[1-9]|1[0-2])/(3[01]|[12]\d|0?[1-9])"#)?,
-                      |text_match| helpers::month_day(
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])/(3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
                           text_match.group(1).parse()?,
-                          text_match.group(2).parse()?)
+                          text_match.group(2).parse()?,
+                          None,
+                          DateOrder::MonthDay)
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"morning"#)?,
                       |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(12, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
                       }
     );
     b.rule_1_terminal("breakfast",
         b.reg(r#"breakfast"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Breakfast, 5, 10)?.latent())
     );
     b.rule_1_terminal("early morning",
                       b.reg(r#"early (?:(?:in|hours of) the )?morning"#)?,
                       |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(9, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                          Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 4, 12, 4, 9)?.latent())
                       }
     );
     b.rule_1_terminal("before work",
@@ -1178,85 +1267,65 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("afternoon",
                       b.reg(r#"after ?noo?n"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(19, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
                       }
     );
     b.rule_1_terminal("night",
                       b.reg(r#"evening|night"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
                       }
     );
 
     b.rule_1_terminal("night",
                       b.reg(r#"evening|night"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Night)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Night, 18, 0)?.latent())
                       }
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"brunch"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Brunch, 10, 15)?.latent())
     );
     b.rule_1_terminal("lunch",
                       b.reg(r#"lunch"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(14, false)?, false)?
-                              .latent()
-                              .form(Form::Meal))
+                          Ok(helpers::meal(MealForm::Lunch, 12, 14)?.latent())
                       }
     );
 
     b.rule_1_terminal("dinner",
         b.reg(r#"dinner|supper"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Dinner, 18, 23)?.latent())
     );
 
     b.rule_1_terminal("tea",
         b.reg(r#"(?:at )?tea time"#)?,
-        |_| Ok(helpers::hour(15, false)?
-                .span_to(&helpers::hour(17, false)?, false)?
-                .form(Form::Meal))
+        |_| helpers::meal(MealForm::Snack, 15, 17)
     );
     b.rule_2("at <meal>",
         b.reg("at|for|during")?,
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         |_, a| Ok(a.value().clone().not_latent())
     );
     b.rule_2("around <meal>",
         b.reg("(?:about|around|approximately)")?,
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         |_, a| Ok(a.value().clone().not_latent().precision(Approximate))
     );
     b.rule_2("<meal><time>",
-        time_check!(|time: &TimeValue| time.latent && form!(Form::Meal)(time)),
+        time_check!(|time: &TimeValue| time.latent && form!(Form::Meal(_))(time)),
         b.reg("time")?,
         |a, _| Ok(a.value().clone().not_latent())
     );
     b.rule_2("in|during the <part-of-day>",
              b.reg(r#"(?:in|during)(?: the)?"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, time| Ok(time.value().clone().not_latent())
     );
     b.rule_2("this <part-of-day>",
              b.reg(r#"this"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, time| Ok(helpers::cycle_nth(Grain::Day, 0)?
                  .intersect(time.value())?
                  .form(time.value().form.clone()))
@@ -1264,7 +1333,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("tonight",
                       b.reg(r#"toni(?:ght|gth|te)"#)?,
                       |_| {
-                          let period = helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?;
+                          let period = helpers::part_of_day(PartOfDayForm::Night, 18, 0)?;
                           Ok(helpers::cycle_nth(Grain::Day, 0)?
                               .intersect(&period)?
                               .form(Form::PartOfDay(PartOfDayForm::Night)))
@@ -1290,29 +1359,23 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("<time> <part-of-day>",
             time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
-            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
             |time, part_of_day| time.value().intersect(part_of_day.value())
     );
     b.rule_2("<part-of-day> <time>",
-            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
             time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
             |part_of_day, time| time.value().intersect(part_of_day.value())
     );
     b.rule_3("<part-of-day> of <time>",
-            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+            time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
             b.reg(r#"of"#)?,
             time_check!(|time: &TimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
             |part_of_day, _, time| time.value().intersect(part_of_day.value())
     );
     b.rule_1_terminal("week-end",
                       b.reg(r#"(?:the )?(?:week(?:\s|-)?end|wkend)"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend(true)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"(?:the )?summer"#)?,
@@ -1478,15 +1541,67 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // "cst", "bst" and "ist" are shared by several zones, see `helpers::timezone` for the ones they stand for
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt|est|edt|cst|cdt|mst|mdt|pst|pdt|bst|cet|cest|jst|kst|ist)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("eastern time",
+                      b.reg(r#"eastern(?: standard| daylight)? time|new york time"#)?,
+                      |_| helpers::timezone("america/new_york")
+    );
+    b.rule_1_terminal("central time",
+                      b.reg(r#"central(?: standard| daylight)? time|chicago time"#)?,
+                      |_| helpers::timezone("america/chicago")
+    );
+    b.rule_1_terminal("mountain time",
+                      b.reg(r#"mountain(?: standard| daylight)? time|denver time"#)?,
+                      |_| helpers::timezone("america/denver")
+    );
+    b.rule_1_terminal("pacific time",
+                      b.reg(r#"pacific(?: standard| daylight)?(?: time)?|(?:los angeles|san francisco) time"#)?,
+                      |_| helpers::timezone("america/los_angeles")
+    );
+    b.rule_1_terminal("london time",
+                      b.reg(r#"(?:london|uk|british) time"#)?,
+                      |_| helpers::timezone("europe/london")
+    );
+    b.rule_1_terminal("paris time",
+                      b.reg(r#"(?:paris|france|french|central european) time"#)?,
+                      |_| helpers::timezone("europe/paris")
+    );
+    b.rule_1_terminal("berlin time",
+                      b.reg(r#"(?:berlin|german) time"#)?,
+                      |_| helpers::timezone("europe/berlin")
+    );
+    b.rule_1_terminal("tokyo time",
+                      b.reg(r#"(?:tokyo|japan) time"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    b.rule_3("<time> in <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"in|at"#)?,
+             timezone_check!(),
+             |time, _, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
              amount_of_money_check!(),
              b.reg(r#"and"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
              amount_of_money_check!(),
@@ -1497,37 +1612,9 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"and"#)?,
              number_check!(),
              |a, _, b| helpers::compose_money_number(&a.value(), &b.value()));
-    b.rule_1_terminal("$",
-                      b.reg(r#"\$|dollars?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("EUR",
-                      b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-                      b.reg(r#"£|pounds?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("USD",
-                      b.reg(r#"us[d\$]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("GBP",
-                      b.reg(r#"gbp"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("PTS",
-                      b.reg(r#"pta?s?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("PTS") })
-    );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"cents?|penn(?:y|ies)|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
-    );
-    b.rule_1_terminal("INR",
-                      b.reg(r#"inr|rs(?:. )?|rupees?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::EN))?,
+                      |text_match| helpers::money_unit(names::EN, text_match.group(0))
     );
     b.rule_1_terminal("unnamed currency",
                       b.reg(r#"(?:buck|balle|pouloute)s?"#)?,
@@ -1571,6 +1658,66 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"between"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"and"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"between"#)?,
+             number_check!(),
+             b.reg(r#"and"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("from <amount-of-money> to <amount-of-money>",
+             b.reg(r#"from"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"to|till|until"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("from <number> to <amount-of-money>",
+             b.reg(r#"from"#)?,
+             number_check!(),
+             b.reg(r#"to|till|until"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|to"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-|to"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("under <amount-of-money>",
+             b.reg(r#"under|less than|below|at most|up to|no more than|max(?:imum)?|cheaper than"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"or (?:less|under|below)|max(?:imum)?"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("over <amount-of-money>",
+             b.reg(r#"over|more than|above|at least|from|min(?:imum)?|starting (?:at|from)"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"or (?:more|over|above)|and (?:up|above|over)|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -1582,38 +1729,351 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<latent temp> degrees",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:deg(?:ree?)?s?\.?)|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Celcius",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"c(?:el[cs]?(?:ius)?)?\.?"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Fahrenheit",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f(?:ah?rh?eh?n(?:h?eit)?)?\.?"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> below zero",
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0 && !temp.is_interval()),
+             b.reg(r#"below (?:zero|0)"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
                      latent: false,
+                     ..*a.value()
                  })
              });
+    b.rule_1_terminal("below freezing",
+                      b.reg(r#"below (freezing|zero)"#)?,
+                      |text_match| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: if text_match.group(1) == "freezing" { Some(TemperatureUnit::Celsius) } else { None },
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("between <temp> and <temp>",
+             b.reg(r#"between"#)?,
+             temperature_check!(),
+             b.reg(r#"and"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("from <temp> to <temp>",
+             b.reg(r#"from"#)?,
+             temperature_check!(),
+             b.reg(r#"to|till|until"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-|to"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("below <temp>",
+             b.reg(r#"below|under|less than|at most|up to|no more than|colder than|max(?:imum)?"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> or less",
+             temperature_check!(),
+             b.reg(r#"or (?:less|under|below|lower|colder)|max(?:imum)?"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("above <temp>",
+             b.reg(r#"above|over|more than|at least|warmer than|hotter than|min(?:imum)?"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> or more",
+             temperature_check!(),
+             b.reg(r#"or (?:more|over|above|higher|warmer)|and (?:up|above|over)"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilometre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"kms?|kilomet(?:er|re)s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilometre))
+    );
+    b.rule_2("<number> metre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"met(?:er|re)s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Metre))
+    );
+    b.rule_2("<number> mile",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"mi(?:les?)?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Mile))
+    );
+    b.rule_2("<number> foot",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ft|feet|foot"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Foot))
+    );
+    b.rule_2("<number> litre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"lit(?:er|re)s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Litre))
+    );
+    b.rule_2("<number> millilitre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ml|millilit(?:er|re)s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Millilitre))
+    );
+    b.rule_2("<number> cup",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"cups?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Cup))
+    );
+    b.rule_2("<number> gallon",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"gal(?:lon)?s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gallon))
+    );
+    b.rule_2("<number> kilogram",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"kgs?|kilo(?:gram(?:me)?)?s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilogram))
+    );
+    b.rule_2("<number> gram",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"gr|gram(?:me)?s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gram))
+    );
+    b.rule_2("<number> pound",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"lbs?|pounds?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Pound))
+    );
+    b.rule_2("<number> ounce",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"oz|ounces?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Ounce))
+    );
+    // single letter units only right after digits, so that stray letters are not read as units
+    b.rule_1_terminal("<digits> m|l|g",
+                      b.reg(r#"(\d+(?:\.\d+)?) ?([mlg])"#)?,
+                      |text_match| {
+                          let value: f32 = text_match.group(1).parse()?;
+                          let unit = match text_match.group(2).as_ref() {
+                              "m" => QuantityUnit::Metre,
+                              "l" => QuantityUnit::Litre,
+                              _ => QuantityUnit::Gram,
+                          };
+                          Ok(QuantityValue::new(value, unit))
+                      }
+    );
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:about|around|approximately|roughly|almost|nearly)"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"exactly|precisely"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("phone number (spoken digits)",
+                      b.reg(r#"(?:plus\s+)?(?:(?:double|triple)\s+)?(?:zero|oh|one|two|three|four|five|six|seven|eight|nine)(?:[\s-]+(?:(?:double|triple)\s+)?(?:zero|oh|one|two|three|four|five|six|seven|eight|nine)){6,14}"#)?,
+                      |text_match| helpers::spoken_phone_number(
+                          text_match.group(0),
+                          |word| match word {
+                              "plus" => Some("+"),
+                              "zero" | "oh" => Some("0"),
+                              "one" => Some("1"),
+                              "two" => Some("2"),
+                              "three" => Some("3"),
+                              "four" => Some("4"),
+                              "five" => Some("5"),
+                              "six" => Some("6"),
+                              "seven" => Some("7"),
+                              "eight" => Some("8"),
+                              "nine" => Some("9"),
+                              _ => None,
+                          },
+                          |word| match word {
+                              "double" => Some(2),
+                              "triple" => Some(3),
+                              _ => None,
+                          })
+    );
+    Ok(())
+}
+
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    // a bare dash between numbers is too often a phone number, a code or a range of years, it
+    // only reads as an interval after "between" or "from"
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"between"#)?,
+             number_check!(),
+             b.reg(r#"and|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("from <number> to <number>",
+             b.reg(r#"from"#)?,
+             number_check!(),
+             b.reg(r#"to|till|until|through|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number> to <number>",
+             number_check!(),
+             b.reg(r#"to|through"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("at least <number>",
+             b.reg(r#"at least|no (?:less|fewer) than|minimum(?: of)?|min\.?"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"or more|and (?:more|above|up)|\+"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("more than <number>",
+             b.reg(r#"more than|greater than|over|above"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("up to <number>",
+             b.reg(r#"up to|at most|no more than|maximum(?: of)?|max\.?"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"or (?:less|fewer)|and (?:less|below|under)"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("less than <number>",
+             b.reg(r#"less than|fewer than|under|below"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("hourly",
+                      b.reg(r#"hourly"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("daily",
+                      b.reg(r#"daily|everyday"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("weekly",
+                      b.reg(r#"weekly"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("monthly",
+                      b.reg(r#"monthly"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("quarterly",
+                      b.reg(r#"quarterly"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Quarter))
+    );
+    b.rule_1_terminal("yearly",
+                      b.reg(r#"yearly|annually"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_2("every <cycle>",
+             b.reg(r#"every|each"#)?,
+             cycle_check!(),
+             |_, a| Ok(RecurrenceValue::new(a.value().grain))
+    );
+    b.rule_3("every <integer> <cycle>",
+             b.reg(r#"every"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, n, a| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_3("every other <cycle>",
+             b.reg(r#"every"#)?,
+             b.reg(r#"other|second|alternate"#)?,
+             cycle_check!(),
+             |_, _, a| RecurrenceValue::new(a.value().grain).every(2)
+    );
+    b.rule_2("every <day-of-week>",
+             b.reg(r#"every|each|on every"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("every other <day-of-week>",
+             b.reg(r#"every"#)?,
+             b.reg(r#"other|second|alternate"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, _, a| RecurrenceValue::new(Grain::Week).every(2)?.at(a.value())
+    );
+    b.rule_3("<recurrence> and <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"and|,"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence> <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<recurrence> from <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             b.reg(r#"from|starting(?: on| from)?|beginning(?: on)?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().starting(b.value())
+    );
+    b.rule_3("<recurrence> until <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             b.reg(r#"until|till|through|up to"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().until(b.value())
+    );
     Ok(())
 }
 
@@ -1786,10 +2246,42 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                 ..FloatValue::default()
             })
     });
-    b.rule_2("<integer> and a half",
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"(hal(?:f|ves)|thirds?|quarters?|fourths?|fifths?|sixths?|sevenths?|eighths?|ninths?|tenths?)"#)?,
+        |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_3("<integer>-<fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"-"#)?,
+        b.reg(r#"(hal(?:f|ves)|thirds?|quarters?|fourths?|fifths?|sixths?|sevenths?|eighths?|ninths?|tenths?)"#)?,
+        |integer, _, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> and a <fraction denominator>",
         integer_check!(),
-        b.reg(r#"and a half"#)?,
-        |integer, _| FloatValue::new(integer.value().value as f32 + 0.5)
+        b.reg(r#"and an? (half|third|quarter)"#)?,
+        |integer, text_match| helpers::compose_mixed_fraction(
+            integer.value().value,
+            &FloatValue::new_fraction(1, fraction_denominator(text_match.group(1))?)?)
+    );
+    b.rule_3("<integer> and <fraction>",
+        integer_check!(),
+        b.reg(r#"and"#)?,
+        number_check!(|number: &NumberValue| number.fraction().map_or(false, |f| 0 < f.numerator && f.numerator < f.denominator)),
+        |integer, _, fraction| match fraction.value() {
+            &NumberValue::Float(ref float) => helpers::compose_mixed_fraction(integer.value().value, float),
+            _ => Err(RuleErrorKind::Invalid.into()),
+        }
+    );
+    b.rule_1_terminal("fraction (numeric)",
+        b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+        |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+        b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+        |text_match| helpers::compose_mixed_fraction(
+            text_match.group(1).parse()?,
+            &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
     );
     b.rule_3("number dot number",
              number_check!(|number: &NumberValue| !number.prefixed()),
@@ -1841,6 +2333,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -2015,4 +2508,19 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              ordinal_check!(),
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
     Ok(())
-}
\ No newline at end of file
+}
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word {
+        "half" | "halves" => Ok(2),
+        "third" | "thirds" => Ok(3),
+        "quarter" | "quarters" | "fourth" | "fourths" => Ok(4),
+        "fifth" | "fifths" => Ok(5),
+        "sixth" | "sixths" => Ok(6),
+        "seventh" | "sevenths" => Ok(7),
+        "eighth" | "eighths" => Ok(8),
+        "ninth" | "ninths" => Ok(9),
+        "tenth" | "tenths" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
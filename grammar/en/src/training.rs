@@ -42,6 +42,10 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "next quarter", "next qtr");
     example!(v, check_moment!(c, [2013, 7, 1], Grain::Quarter), "third quarter", "3rd quarter", "third qtr", "3rd qtr", "the 3rd qtr");
     example!(v, check_moment!(c, [2018, 10, 1], Grain::Quarter), "4th quarter 2018", "4th qtr 2018", "the 4th qtr of 2018");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "Q2", "q2");
+    example!(v, check_moment!(c, [2014, 7, 1], Grain::Quarter), "Q3 2014", "Q3 FY2014", "q3 fy 14");
+    example!(v, check_moment!(c, [2013]), "this fiscal year", "current financial year");
+    example!(v, check_moment!(c, [2014]), "next fiscal year", "FY2014", "FY 14", "fiscal year 2014");
     example!(v, check_moment!(c, [2012]), "last year", "last yr");
     example!(v, check_moment!(c, [2013]), "this year", "current year", "this yr");
     example!(v, check_moment!(c, [2014]), "next year", "next yr");
@@ -179,6 +183,10 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2016, 12, 15]), "12.15.2016", "12.15.16");
     example!(v, check_moment!(c, [2017, 05, 10]), "wednesday the 10th of may");
     example!(v, check_moment_span!(c, [2017, 05, 12, 10, 32], [2017, 06, 7, 18, 23]), "friday the 12th of may, 10:32 am to wednesday the 7th of june, 6:22 pm");
+    example!(v, check_timezone("EST"), "3pm EST", "3 pm est");
+    example!(v, check_timezone("UTC"), "noon UTC", "noon gmt");
+    example!(v, check_timezone("America/Los_Angeles"), "5 o'clock Pacific time", "5pm pacific");
+    example!(v, check_timezone("Europe/Paris"), "9am Paris time", "tomorrow at 9 in paris time");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -186,6 +194,8 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 1], Precision::Approximate), "about one day", "approximately 1 day");
     example!(v, check_duration!([0, 2, 0]), "during two months", "for 2 months");
     example!(v, check_duration!([1]), "during a year");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30]), "one and a half hours", "for 1 1/2 hours");
+    example!(v, check_duration!([0, 0, 0, 2, 16]), "two and two thirds days");
     example!(v, check_duration!([0, 0, 0, 0, 0, 1, 3]), "during one minute and three seconds", "for 1 minute and 3 seconds", "for 1min3sec");
     example!(v, check_duration!([0, 0, 0, 0, 3, 30], Precision::Approximate), "about three and a half hours", "around 3 hours and a half");
     example!(v, check_duration!([0, 0, 0, 0, 1, 30], Precision::Approximate), "about one hour and a half");
@@ -197,6 +207,21 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 2], Precision::Approximate), "around two days");
 }
 
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10 dollars", "ten dollars");
+    example!(v, check_finance(3000.0, Some("JPY"), Precision::Exact), "3000 yen", "¥3000");
+    example!(v, check_finance(2.5, Some("$"), Precision::Exact), "2 1/2 dollars", "two and a half dollars");
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "between 10 and 20 euros", "10-20 euros", "from 10 euros to 20 euros");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "under $50", "less than 50 dollars", "$50 or less");
+    example!(v, check_finance_interval(None, Some(3000.0), Some("JPY")), "at most 3000 yen", "up to ¥3000");
+    example!(v, check_finance_interval(Some(100.0), None, Some("$")), "more than $100", "over 100 dollars", "$100 and up");
+}
+
+pub fn examples_percentage(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_percentage(20.0), "20%", "20 percent", "twenty per cent");
+    example!(v, check_percentage(3.5), "3 1/2 percent", "three and a half percent", "3.5%");
+}
+
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(0), "0", "naught", "nought", "zero", "nil");
     example!(v, check_integer(1), "1", "one", "single");
@@ -208,6 +233,10 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(18), "18", "eighteen");
     example!(v, check_float(1.1), "1.1", "1.10", "01.10");
     example!(v, check_float(0.77), "0.77", ".77");
+    example!(v, check_fraction(2, 3), "2/3", "two thirds", "two-thirds");
+    example!(v, check_fraction(3, 5), "three fifths", "3/5");
+    example!(v, check_fraction(3, 2), "one and a half", "1 1/2");
+    example!(v, check_fraction(5, 2), "2 1/2", "two and a half");
     example!(v,
              check_integer(100000),
              "100,000",
@@ -251,4 +280,65 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(3), "the 3rd", "3rd", "third");
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
-}
\ No newline at end of file
+}
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "between 3 and 5", "between 3-5", "from 3-5", "from three to five", "3 to 5");
+    example!(v, check_number_interval(Some(10.0), Some(20.0)), "from ten to twenty", "between ten and twenty");
+    example!(v, check_number_interval(Some(3.0), None), "at least 3", "three or more", "at least three");
+    example!(v, check_number_interval(None, Some(10.0)), "up to 10", "at most ten", "no more than 10");
+    example!(v, check_number_interval(Some(100.0), None), "more than 100", "over one hundred");
+    example!(v, check_number_interval(None, Some(4.0)), "less than 4", "fewer than four");
+}
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(5.0, QuantityUnit::Kilometre, Precision::Exact), "5 km", "five kilometers", "5 kilometres");
+    example!(v, check_quantity(3.0, QuantityUnit::Mile, Precision::Exact), "3 miles", "three miles");
+    example!(v, check_quantity(100.0, QuantityUnit::Metre, Precision::Exact), "100 m", "one hundred meters", "100 metres");
+    example!(v, check_quantity(6.0, QuantityUnit::Foot, Precision::Exact), "6 ft", "six feet");
+    example!(v, check_quantity(2.0, QuantityUnit::Litre, Precision::Exact), "2 l", "two liters", "2 litres");
+    example!(v, check_quantity(250.0, QuantityUnit::Millilitre, Precision::Exact), "250 ml", "250 milliliters");
+    example!(v, check_quantity(2.0, QuantityUnit::Cup, Precision::Exact), "2 cups", "two cups");
+    example!(v, check_quantity(10.0, QuantityUnit::Gallon, Precision::Exact), "10 gallons", "ten gal");
+    example!(v, check_quantity(1.5, QuantityUnit::Kilogram, Precision::Exact), "1.5 kg", "1.5 kilograms", "one and a half kilos");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Precision::Exact), "500 g", "500 grams", "five hundred grams");
+    example!(v, check_quantity(3.0, QuantityUnit::Pound, Precision::Exact), "3 lbs", "three pounds");
+    example!(v, check_quantity(8.0, QuantityUnit::Ounce, Precision::Exact), "8 oz", "eight ounces");
+    example!(v, check_quantity(10.0, QuantityUnit::Kilometre, Precision::Approximate), "about 10 km", "around ten kilometers");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("john.doe@example.com"), "john.doe@example.com");
+    example!(v, check_url("https://www.example.com/path?q=1", "example.com"), "https://www.example.com/path?q=1");
+    example!(v, check_url("example.org", "example.org"), "example.org");
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78");
+    example!(v, check_phone_number("0612345678"), "oh six one two three four five six seven eight", "06 12 34 56 78");
+    example!(v, check_phone_number("4155550123"), "(415) 555-0123", "four one five five five five oh one two three", "four one five triple five oh one two three");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 degrees", "thirty degrees", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5°C", "minus 5 celsius", "5 degrees celsius below zero");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "minus five degrees", "5 degrees below zero");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70 fahrenheit", "70°F");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "between 18 and 22 degrees", "from 18 to 22 degrees", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "above 30 degrees", "30 degrees or more");
+    example!(v, check_temperature_interval(Some(30.0), None, None), "above 30");
+    example!(v, check_temperature_interval(None, Some(0.0), Some(TemperatureUnit::Celsius)), "below freezing");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Celsius)), "under 10°C", "less than 10 celsius");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "every monday", "each monday", "on every monday");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "each day at 8am", "every day at 8", "daily at 8 am");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "every other week", "every second week", "every 2 weeks");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], None), "daily", "every day");
+    example!(v, check_recurrence(Grain::Month, 1, vec![], None), "monthly", "every month");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Tue, Weekday::Thu], Some(18)), "every tuesday and thursday at 6pm");
+    example!(v, check_recurrence(Grain::Week, 2, vec![Weekday::Fri], None), "every other friday", "every other friday until march");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::hours(2).into()), Some(PeriodComp::hours(3).into())), "2 to 3 hours", "between two and three hours", "from 2 hours to 3 hours", "2-3 hours");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "at least 20 minutes", "20 minutes or more");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "less than a week", "up to one week");
+}
@@ -1,6 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -14,45 +15,21 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
              b.reg(r#"y"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
-    b.rule_1_terminal("$",
-        b.reg(r#"\$|d(?:ó|o)lar(?:es)?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("EUR",
-        b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-        b.reg(r#"(?:pound|libra)s?|£"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("USD",
-        b.reg(r#"us[d\$]|d[óo]lar(?:es)? (?:estadounidense|americano)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("Bitcoin",
-        b.reg(r#"bitcóin(?:es)?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
-    );
-    b.rule_1_terminal("GBP",
-        b.reg(r#"gbp|libras? esterlina"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"centavos?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::ES))?,
+                      |text_match| helpers::money_unit(names::ES, text_match.group(0))
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -75,6 +52,66 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..AmountOfMoneyValue::default()
                  })
              });
+    b.rule_4("entre <amount-of-money> y <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"y"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> y <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"y"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <amount-of-money> a <amount-of-money>",
+             b.reg(r#"de(?:sde)?"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"a|hasta"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> a <amount-of-money>",
+             b.reg(r#"de(?:sde)?"#)?,
+             number_check!(),
+             b.reg(r#"a|hasta"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|a"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-|a"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("menos de <amount-of-money>",
+             b.reg(r#"menos de|como m[áa]ximo|m[áa]ximo|hasta|no m[áa]s de|por debajo de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> o menos",
+             amount_of_money_check!(),
+             b.reg(r#"o menos|como m[áa]ximo"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("más de <amount-of-money>",
+             b.reg(r#"m[áa]s de|al menos|por lo menos|como m[íi]nimo|m[íi]nimo|a partir de|desde|por encima de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> o más",
+             amount_of_money_check!(),
+             b.reg(r#"o m[áa]s|como m[íi]nimo|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -112,6 +149,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_2("en <duration>",
              b.reg(r#"en"#)?,
              duration_check!(),
@@ -122,6 +164,66 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(),
              |_, duration| duration.value().ago()
     );
+    b.rule_4("entre <duration> y <duration>",
+             b.reg(r#"entre"#)?,
+             duration_check!(),
+             b.reg(r#"y"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> y <duration>",
+             b.reg(r#"entre"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"y"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <duration> a <duration>",
+             b.reg(r#"de(?:sde)?"#)?,
+             duration_check!(),
+             b.reg(r#"a|hasta"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> a <duration>",
+             b.reg(r#"de(?:sde)?"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"a|hasta"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|a|o"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|a|o"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("al menos <duration>",
+             b.reg(r#"al menos|por lo menos|como m[íi]nimo|m[áa]s de"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> o más",
+             duration_check!(),
+             b.reg(r#"o m[áa]s|como m[íi]nimo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("menos de <duration>",
+             b.reg(r#"menos de|como m[áa]ximo|no m[áa]s de"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> o menos",
+             duration_check!(),
+             b.reg(r#"o menos|como m[áa]ximo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
     Ok(())
 }
 
@@ -293,11 +395,11 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| helpers::day_of_week(Weekday::Fri)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"s[áa]bado|s(?:á|a)b\.?"#)?,
+                      b.reg(r#"s[áa]bados?|s(?:á|a)b\.?"#)?,
                       |_| helpers::day_of_week(Weekday::Sat)
     );
     b.rule_1_terminal("named-day",
-                      b.reg(r#"domingo|dom\.?"#)?,
+                      b.reg(r#"domingos?|dom\.?"#)?,
                       |_| helpers::day_of_week(Weekday::Sun)
     );
     b.rule_1_terminal("named-month",
@@ -610,15 +712,11 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"ma(?:ñ|n)ana"#)?,
-                      |_| Ok(helpers::hour(4, false)?.span_to(&helpers::hour(12, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Morning))
-                          .latent())
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"tarde"#)?,
-                      |_| Ok(helpers::hour(12, false)?.span_to(&helpers::hour(19, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon))
-                          .latent())
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 21)?.latent())
     );
     b.rule_1_terminal("del mediodía",
                       b.reg(r#"del mediod[ií]a"#)?,
@@ -628,25 +726,23 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"noche"#)?,
-                      |_| Ok(helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Evening))
-                          .latent())
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
     );
     b.rule_2("in the <part-of-day>",
              b.reg(r#"(?:a|en|de|por) la"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, pod| Ok(pod.value().clone().not_latent())
     );
     b.rule_2("this <part-of-day>",
              b.reg(r#"est(?:e|a)"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, pod| Ok(helpers::cycle_nth(Grain::Day, 0)?
                  .intersect(pod.value())?
                  .form(pod.value().form.clone()))
     );
     b.rule_2("<time-of-day> <part-of-day>",
              time_check!(),
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |time, pod| time.value().intersect(pod.value())
     );
     b.rule_2("<dim time> de la tarde",
@@ -668,20 +764,14 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
     b.rule_3("<integer> in the <part-of-day>",
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              b.reg(r#"(?:a|en|de|por) la"#)?,
              time_check!(),
              |pod, _, time| time.value().intersect(pod.value())
     );
     b.rule_1_terminal("week-end",
                       b.reg(r#"week[ -]?end|fin de semana"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend(true)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"verano"#)?,
@@ -762,46 +852,83 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt|cet|cest)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("hora de Madrid",
+                      b.reg(r#"hora (?:de madrid|peninsular|de espa[ñn]a|espa[ñn]ola)"#)?,
+                      |_| helpers::timezone("europe/madrid")
+    );
+    b.rule_1_terminal("hora de México",
+                      b.reg(r#"hora (?:de m[ée]xico|del centro de m[ée]xico)"#)?,
+                      |_| helpers::timezone("america/mexico_city")
+    );
+    b.rule_1_terminal("hora de Nueva York",
+                      b.reg(r#"hora (?:de nueva york|del este)"#)?,
+                      |_| helpers::timezone("america/new_york")
+    );
+    b.rule_1_terminal("hora del Pacífico",
+                      b.reg(r#"hora del pac[íi]fico"#)?,
+                      |_| helpers::timezone("america/los_angeles")
+    );
+    b.rule_1_terminal("hora de Londres",
+                      b.reg(r#"hora (?:de londres|brit[áa]nica)"#)?,
+                      |_| helpers::timezone("europe/london")
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp", number_check!(), |a| {
         Ok(TemperatureValue {
             value: a.value().value(),
             unit: None,
             latent: true,
+            ..TemperatureValue::default()
         })
     });
     b.rule_2("<latent temp> temp",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:grados?)|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Celcius",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:cent(?:i|í)grados?|c(?:el[cs]?(?:ius)?)?\.?)"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Fahrenheit",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f(?:ah?reh?n(?:h?eit)?)?\.?"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<latent temp> temp bajo cero",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:(?:grados?)|°)?(?: bajo cero)"#)?,
              |a, _| {
                  Ok(TemperatureValue {
@@ -810,6 +937,205 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_1_terminal("bajo cero",
+                      b.reg(r#"(?:por debajo de|bajo) (cero|el punto de congelaci[óo]n)"#)?,
+                      |text_match| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: if text_match.group(1) == "cero" { None } else { Some(TemperatureUnit::Celsius) },
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("entre <temp> y <temp>",
+             b.reg(r#"entre"#)?,
+             temperature_check!(),
+             b.reg(r#"y"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("de <temp> a <temp>",
+             b.reg(r#"de(?:sde)?"#)?,
+             temperature_check!(),
+             b.reg(r#"a|hasta"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-|a"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("menos de <temp>",
+             b.reg(r#"menos de|como m[áa]ximo|m[áa]ximo|hasta|no m[áa]s de|por debajo de"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> o menos",
+             temperature_check!(),
+             b.reg(r#"o menos|como m[áa]ximo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("más de <temp>",
+             b.reg(r#"m[áa]s de|al menos|por lo menos|como m[íi]nimo|m[íi]nimo|a partir de|por encima de"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> o más",
+             temperature_check!(),
+             b.reg(r#"o m[áa]s|como m[íi]nimo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("phone number (spoken digits)",
+                      b.reg(r#"(?:m[áa]s\s+)?(?:cero|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve)(?:[\s-]+(?:cero|uno|dos|tres|cuatro|cinco|seis|siete|ocho|nueve)){6,14}"#)?,
+                      |text_match| helpers::spoken_phone_number(
+                          text_match.group(0),
+                          |word| match word {
+                              "más" | "mas" => Some("+"),
+                              "cero" => Some("0"),
+                              "uno" => Some("1"),
+                              "dos" => Some("2"),
+                              "tres" => Some("3"),
+                              "cuatro" => Some("4"),
+                              "cinco" => Some("5"),
+                              "seis" => Some("6"),
+                              "siete" => Some("7"),
+                              "ocho" => Some("8"),
+                              "nueve" => Some("9"),
+                              _ => None,
+                          },
+                          |_| None)
+    );
+    Ok(())
+}
+
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("entre <number> y <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"y|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("de <number> a <number>",
+             b.reg(r#"de(?:sde)?"#)?,
+             number_check!(),
+             b.reg(r#"a|hasta|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number> a <number>",
+             number_check!(),
+             b.reg(r#"a"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("al menos <number>",
+             b.reg(r#"al menos|por lo menos|como m[íi]nimo|m[íi]nimo"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number> o más",
+             number_check!(),
+             b.reg(r#"o m[áa]s|\+"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("más de <number>",
+             b.reg(r#"m[áa]s de|mayor (?:de|que)|por encima de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("hasta <number>",
+             b.reg(r#"hasta|como m[áa]ximo|a lo sumo|m[áa]ximo|no m[áa]s de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number> o menos",
+             number_check!(),
+             b.reg(r#"o menos"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("menos de <number>",
+             b.reg(r#"menos de|menor (?:de|que)|por debajo de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("diario",
+                      b.reg(r#"diari(?:o|a|amente)"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("semanal",
+                      b.reg(r#"semanal(?:mente)?"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("mensual",
+                      b.reg(r#"mensual(?:mente)?"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("trimestral",
+                      b.reg(r#"trimestral(?:mente)?"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Quarter))
+    );
+    b.rule_1_terminal("anual",
+                      b.reg(r#"anual(?:mente)?"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_2("cada <cycle>",
+             b.reg(r#"cada|tod[oa]s l[oa]s"#)?,
+             cycle_check!(),
+             |_, a| Ok(RecurrenceValue::new(a.value().grain))
+    );
+    b.rule_3("cada <integer> <cycle>",
+             b.reg(r#"cada"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, n, a| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_2("cada <day-of-week>",
+             b.reg(r#"cada|todos los"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("<recurrence> y <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"y|,"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence> <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<recurrence> desde <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             b.reg(r#"desde(?: el)?|a partir del?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().starting(b.value())
+    );
+    b.rule_3("<recurrence> hasta <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             b.reg(r#"hasta(?: el)?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().until(b.value())
+    );
+    Ok(())
+}
+
+/// Currency of Mexican Spanish, where the "peso" is the mexican one
+pub fn rules_finance_mx(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("currency (mexico)",
+                      b.reg(&helpers::money_unit_regex(names::ES_MX))?,
+                      |text_match| helpers::money_unit(names::ES_MX, text_match.group(0))
+    );
     Ok(())
 }
 
@@ -950,6 +1276,33 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..FloatValue::default()
                  })
              });
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(medios?|tercios?|cuartos?|quintos?|sextos?|s[ée]ptimos?|octavos?|novenos?|d[ée]cimos?)"#)?,
+             |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> y medio",
+             integer_check_by_range!(0, 99),
+             b.reg(r#"y (medio|media|un tercio|un cuarto)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "medio" | "media" => 2,
+                     "un tercio" => 3,
+                     "un cuarto" => 4,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, denominator)?)
+             });
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+                      |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+                      b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+                      |text_match| helpers::compose_mixed_fraction(
+                          text_match.group(1).parse()?,
+                          &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
+    );
     b.rule_1_terminal("decimal with thousands separator",
                       b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
                       |text_match| {
@@ -975,6 +1328,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -1081,3 +1435,18 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       });
     Ok(())
 }
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word.trim_right_matches('s') {
+        "medio" => Ok(2),
+        "tercio" => Ok(3),
+        "cuarto" => Ok(4),
+        "quinto" => Ok(5),
+        "sexto" => Ok(6),
+        "séptimo" | "septimo" => Ok(7),
+        "octavo" => Ok(8),
+        "noveno" => Ok(9),
+        "décimo" | "decimo" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
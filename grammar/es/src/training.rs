@@ -3,7 +3,14 @@ use rustling_ontology_moment::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
+pub fn examples_finance_mx(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance(200.0, Some("MXN"), Precision::Exact), "200 pesos", "doscientos pesos mexicanos");
+}
+
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "entre 10 y 20 euros", "10-20 euros", "de 10€ a 20€");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "más de 100 euros", "al menos cien euros");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "menos de 50 dólares", "como máximo $50");
     example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10$", "diez dólares");
     example!(v, check_finance(10000.0, Some("GBP"), Precision::Exact), "10.000 libras esterlina");
     example!(v, check_finance(20.0, Some("EUR"), Precision::Exact), "20€", "20 euros");
@@ -129,6 +136,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 12, 21], [2014, 1, 7]), "21 de Dic. a 6 de Ene");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 0], [2013, 2, 12, 7, 30]), "dentro de tres horas");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16");
+    example!(v, check_timezone("Europe/Madrid"), "a las 9 hora de Madrid", "9 de la mañana hora peninsular");
+    example!(v, check_timezone("UTC"), "a las 15 UTC");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -148,6 +157,8 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(33), "33", "treinta y tres", "treinta y 3");
     example!(v, check_float(1.1), "1,1", "1,10", "01,10");
     example!(v, check_float(0.77), "0,77", ",77");
+    example!(v, check_fraction(2, 3), "dos tercios", "2/3");
+    example!(v, check_fraction(5, 2), "dos y medio", "2 1/2");
     example!(v,
              check_integer(100000),
              "100.000",
@@ -183,3 +194,43 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "una punto cinco",
              "1,5");
 }
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "entre 3 y 5", "entre 3-5", "de 3-5", "de tres a cinco", "3 a 5");
+    example!(v, check_number_interval(Some(3.0), None), "al menos 3", "tres o más");
+    example!(v, check_number_interval(None, Some(10.0)), "hasta 10", "como máximo diez");
+    example!(v, check_number_interval(Some(100.0), None), "más de 100", "más de cien");
+    example!(v, check_number_interval(None, Some(4.0)), "menos de 4", "menos de cuatro");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("john.doe@example.com"), "john.doe@example.com");
+    example!(v, check_url("https://www.example.com/path?q=1", "example.com"), "https://www.example.com/path?q=1");
+    example!(v, check_url("example.org", "example.org"), "example.org");
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78");
+    example!(v, check_phone_number("612345678"), "seis uno dos tres cuatro cinco seis siete ocho", "612 345 678");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 grados", "treinta grados", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "menos cinco grados", "-5°", "5 grados bajo cero");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5°C");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "entre 18 y 22 grados", "de 18 a 22 grados", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "más de 30 grados", "30 grados o más");
+    example!(v, check_temperature_interval(None, Some(0.0), None), "bajo cero");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Degree)), "menos de 10 grados", "10 grados o menos");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::days(2).into()), Some(PeriodComp::days(3).into())), "entre dos y tres días", "de 2 a 3 días", "2-3 días");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "al menos 20 minutos", "20 minutos o más");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "menos de una semana", "1 semana o menos");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "todos los lunes", "cada lunes");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "cada día a las 8", "todos los días a las ocho");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "cada dos semanas", "cada 2 semanas");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], None), "diariamente", "todos los días");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Sat, Weekday::Sun], None), "todos los sábados y domingos");
+}
@@ -2,6 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -16,56 +17,20 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_3("intersect (and X cents)",
              amount_of_money_check!(),
              b.reg(r#"et"#)?,
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, _, b| helpers::compose_money(&a.value(), &b.value()));
     b.rule_2("intersect",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
-    b.rule_1_terminal("$",
-        b.reg(r#"\$|dollars?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("EUR",
-        b.reg(r#"€|(?:[e€]uro?s?)"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-        b.reg(r#"£|livres?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("USD",
-        b.reg(r#"us[d\$]|dollars? am[eé]ricains?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("CHF",
-        b.reg(r#"chf|francs? suisses?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CHF") })
-    );
-    b.rule_1_terminal("JPY",
-        b.reg(r#"jpy|yens?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("JPY") })
-    );
-    b.rule_1_terminal("RMB|CNH|CNY",
-        b.reg(r#"cny|cnh|rmb|yuans?|renmimbis?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("¥") })
-    );
-    b.rule_1_terminal("Bitcoin",
-        b.reg(r#"bitcoins?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
-    );
-    b.rule_1_terminal("GBP",
-        b.reg(r#"gbp|livres? sterlings?"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"centimes?|cents?|penn(?:y|ies)|fens?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::FR))?,
+                      |text_match| helpers::money_unit(names::FR, text_match.group(0))
     );
     b.rule_1_terminal("unnamed currency",
                       b.reg(r#"(?:balle)s?"#)?,
@@ -121,6 +86,66 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             })
         }
     );
+    b.rule_4("entre <amount-of-money> et <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"et"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> et <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"et"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <amount-of-money> à <amount-of-money>",
+             b.reg(r#"de"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"[àa]"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> à <amount-of-money>",
+             b.reg(r#"de"#)?,
+             number_check!(),
+             b.reg(r#"[àa]"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|[àa]"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-|[àa]"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("moins de <amount-of-money>",
+             b.reg(r#"moins de|au plus|au maximum|maximum|jusqu'[àa]|pas plus de|en dessous de|inf[ée]rieur [àa]"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> ou moins",
+             amount_of_money_check!(),
+             b.reg(r#"ou moins|maximum|max"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("plus de <amount-of-money>",
+             b.reg(r#"plus de|au moins|au minimum|minimum|[àa] partir de|au-dessus de|sup[ée]rieur [àa]"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> ou plus",
+             amount_of_money_check!(),
+             b.reg(r#"ou plus|et plus|minimum|min|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -170,6 +195,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, unit| Ok(DurationValue::new(PeriodComp::new(unit.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> de <unit-of-duration>",
         integer_check!(|integer: &IntegerValue| integer.value >= 0 && integer.group),
         b.reg(r#"d[e']"#)?,
@@ -261,6 +291,66 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              time_check!(),
              |duration, _, time| duration.value().before(time.value())
     );
+    b.rule_4("entre <duration> et <duration>",
+             b.reg(r#"entre"#)?,
+             duration_check!(),
+             b.reg(r#"et"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> et <duration>",
+             b.reg(r#"entre"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"et"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <duration> à <duration>",
+             b.reg(r#"de|depuis"#)?,
+             duration_check!(),
+             b.reg(r#"[àa]|jusqu'[àa]"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> à <duration>",
+             b.reg(r#"de|depuis"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"[àa]|jusqu'[àa]"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|[àa]|ou"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|[àa]|ou"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("au moins <duration>",
+             b.reg(r#"au moins|plus d[e']|au minimum|minimum|pas moins d[e']"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> ou plus",
+             duration_check!(),
+             b.reg(r#"ou plus|et plus|minimum"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("moins de <duration>",
+             b.reg(r#"moins d[e']|au plus|au maximum|maximum|pas plus d[e']|jusqu'[àa]"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> ou moins",
+             duration_check!(),
+             b.reg(r#"ou moins|maximum|max"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
     Ok(())
 }
 
@@ -293,6 +383,10 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"an(?:n[ée]e?)?s?"#)?,
              |_| CycleValue::new(Grain::Year)
     );
+    b.rule_1_terminal("trimestre (cycle)",
+        b.reg(r#"trimestres?"#)?,
+        |_| CycleValue::new(Grain::Quarter)
+    );
     b.rule_2("ce|dans le <cycle>",
              b.reg(r#"(?:cet?t?e?s?)|(?:dans l[ae']? ?)"#)?,
              cycle_check!(),
@@ -438,6 +532,40 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              time_check!(),
              |_, time| helpers::cycle_nth_after_not_immediate(Grain::Day, -1, time.value())
     );
+    b.rule_2("<ordinal> trimestre",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             |ordinal, _| helpers::fiscal_quarter(ordinal.value().value, &helpers::fiscal_year_nth(0)?)
+    );
+    b.rule_3("<ordinal> trimestre <year>",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             time_check!(form!(Form::Year(_))),
+             |ordinal, _, time| helpers::fiscal_quarter(ordinal.value().value, time.value())
+    );
+    b.rule_2("T<n> <year>",
+             b.reg(r#"[tq]([1-4])"#)?,
+             time_check!(form!(Form::Year(_))),
+             |text_match, time| helpers::fiscal_quarter(text_match.group(1).parse()?, time.value())
+    );
+    b.rule_1_terminal("exercice <year>",
+        b.reg(r#"(?:l'|de l')?exercice(?: fiscal| comptable)? (\d{4})"#)?,
+        |text_match| helpers::fiscal_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("cet exercice",
+        b.reg(r#"(?:cet |l')exercice(?: fiscal| comptable)?(?: en cours)?"#)?,
+        |_| helpers::fiscal_year_nth(0)
+    );
+    b.rule_1_terminal("l'exercice prochain|dernier",
+        b.reg(r#"l'exercice(?: fiscal| comptable)? (prochain|suivant|dernier|pr[ée]c[ée]dent)"#)?,
+        |text_match| {
+            let n = match text_match.group(1).as_ref() {
+                "prochain" | "suivant" => 1,
+                _ => -1,
+            };
+            helpers::fiscal_year_nth(n)
+        }
+    );
     Ok(())
 }
 
@@ -470,31 +598,31 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_, a| Ok(a.value().clone())
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"lun\.?(?:di)?"#)?,
+        b.reg(r#"lun\.?(?:dis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Mon)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"mar\.?(?:di)?"#)?,
+        b.reg(r#"mar\.?(?:dis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Tue)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"mer\.?(?:credi)?"#)?,
+        b.reg(r#"mer\.?(?:credis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Wed)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"jeu\.?(?:di)?"#)?,
+        b.reg(r#"jeu\.?(?:dis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Thu)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"ven\.?(?:dredi)?"#)?,
+        b.reg(r#"ven\.?(?:dredis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Fri)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"sam\.?(?:edi)?"#)?,
+        b.reg(r#"sam\.?(?:edis?)?"#)?,
         |_| helpers::day_of_week(Weekday::Sat)
     );
     b.rule_1_terminal("named-day",
-        b.reg(r#"dim\.?(?:anche)?"#)?,
+        b.reg(r#"dim\.?(?:anches?)?"#)?,
         |_| helpers::day_of_week(Weekday::Sun)
     );
     b.rule_1_terminal("named-month",
@@ -547,7 +675,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("noel",
         b.reg(r#"(?:jour de )?no[eë]l"#)?,
-        |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration("christmas")))
     );
     b.rule_1_terminal("soir de noël",
         b.reg(r#"(soir(?:ée)?|veille) de no[eë]l"#)?,
@@ -555,63 +683,63 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             let start = helpers::month_day(12, 24)?.intersect(&helpers::hour(18, false)?)?;
             let end = helpers::month_day(12, 25)?.intersect(&helpers::hour(0, false)?)?;
             Ok(start.span_to(&end, false)?
-                 .form(Form::Celebration))
+                 .form(Form::Celebration("christmas_eve")))
         }
     );
     b.rule_1_terminal("jour de l'an",
         b.reg(r#"(?:le )?(?:jour de l'|nouvel )an"#)?,
-        |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration("new_years_day")))
     );
     b.rule_1_terminal("toussaint",
         b.reg(r#"(?:(?:la |la journée de la |jour de la )?toussaint|jour des morts)"#)?,
-        |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(11, 1)?.form(Form::Celebration("all_saints_day")))
     );
     b.rule_1_terminal("Armistice",
         b.reg(r#"(?:pour )?l'armistice"#)?,
-        |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(11, 11)?.form(Form::Celebration("armistice_day")))
     );
     b.rule_1_terminal("Saint Etienne (Alsace)",
         b.reg(r#"(?:(?:le jour|la f[eê]te) de )?la (?:saint|st) [eé]tienne"#)?,
-        |_| Ok(helpers::month_day(12, 26)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(12, 26)?.form(Form::Celebration("saint_stephens_day")))
     );
     b.rule_1_terminal("jeudi saint",
         b.reg(r#"(?:le )?jeudi saint"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -3, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("holy_thursday")))
     );
     b.rule_1_terminal("vendredi saint",
         b.reg(r#"(?:le )?vendredi saint"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -2, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("good_friday")))
     );
     b.rule_1_terminal("samedi saint",
         b.reg(r#"(?:le )?samedi saint"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, -1, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("holy_saturday")))
     );
     b.rule_1_terminal("pâques",
         b.reg(r#"(?:la f[eê]te de |le jour de |le dimanche de )?p[âa]ques"#)?,
-        |_| Ok(helpers::easter()?.form(Form::Celebration))
+        |_| Ok(helpers::easter()?.form(Form::Celebration("easter")))
     );
     b.rule_1_terminal("le lundi de pâques",
         b.reg(r#"le lundi de p[âa]ques"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 1, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("easter_monday")))
     );
     b.rule_1_terminal("ascension",
         b.reg(r#"(?:la f[eê]te de l'|le jeudi de l'|l'|le jour de l')ascension"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 39, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("ascension")))
 
     );
     b.rule_1_terminal("pencôte",
         b.reg(r#"(?:la f[eê]te de la |la |le lundi de la )?penc[oô]te"#)?,
         |_| Ok(helpers::cycle_nth_after(Grain::Day, 49, &helpers::easter()?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("pentecost")))
     );
     b.rule_1_terminal("1er mai",
         b.reg(r#"(?:la )?f(e|ê)te du travail"#)?,
-        |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration))
+        |_| Ok(helpers::month_day(5, 1)?.form(Form::Celebration("labour_day")))
     );
     b.rule_1_terminal("fêtes des pères",
         b.reg(r#"(?:la )?f[eê]te des p[eè]res"#)?,
@@ -619,7 +747,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             let sundays_of_june = helpers::month(6)?.intersect(&helpers::day_of_week(Weekday::Sun)?)?;
             let second_week_of_june = helpers::cycle_nth_after(Grain::Week, 2, &helpers::month_day(6, 1)?)?;
             Ok(sundays_of_june.intersect(&second_week_of_june)? // third sunday of June
-                   .form(Form::Celebration))
+                   .form(Form::Celebration("fathers_day")))
         }
     );
     b.rule_1_terminal("fêtes des mères",
@@ -629,18 +757,18 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             // If it is the same day as the Pentecost, it is the first sunday of june
             // This case is not supported for now
             Ok(helpers::day_of_week(Weekday::Sun)?.last_of(&helpers::month(5)?)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("mothers_day")))
         }
     );
     b.rule_1_terminal("fête nationale",
         b.reg(r#"(?:la )?f[eê]te (?:nationale|du (?:14|quatorze) juillet)"#)?,
         |_| Ok(helpers::month_day(7, 14)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("bastille_day")))
     );
     b.rule_1_terminal("assomption",
         b.reg(r#"(?:la f[eê]te de |le jour de )?l'assomption"#)?,
         |_| Ok(helpers::month_day(8, 15)?
-                .form(Form::Celebration))
+                .form(Form::Celebration("assumption")))
     );
     b.rule_1_terminal("maintenant",
         b.reg(r#"maintenant|(?:tout de suite)"#)?,
@@ -744,9 +872,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"week(?:\s|-)?end (?:d['eu]|en|du mois de)"#)?,
              time_check!(form!(Form::Month(_))),
              |ordinal, _, time| {
-                 let week_day_start = helpers::day_of_week(Weekday::Fri)?.intersect(&helpers::hour(18, false)?)?;
-                 let week_day_end = helpers::day_of_week(Weekday::Mon)?.intersect(&helpers::hour(0, false)?)?;
-                 let week_day = week_day_start.span_to(&week_day_end, false)?;
+                 let week_day = helpers::weekend(true)?;
                  let week_ends_of_time = time.value().intersect(&week_day)?;
                  week_ends_of_time.the_nth(ordinal.value().value - 1)
              }
@@ -755,9 +881,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"(?:le )?dernier week(?:\s|-)?end (?:du mois d[e']|d['eu]|en)"#)?,
              time_check!(form!(Form::Month(_))),
              |_, time| {
-                 let week_day_start = helpers::day_of_week(Weekday::Fri)?.intersect(&helpers::hour(18, false)?)?;
-                 let week_day_end = helpers::day_of_week(Weekday::Mon)?.intersect(&helpers::hour(0, false)?)?;
-                 let week_day = week_day_start.span_to(&week_day_end, false)?;
+                 let week_day = helpers::weekend(true)?;
                  week_day.last_of(time.value())
              }
     );
@@ -962,52 +1086,31 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("matin",
         b.reg(r#"mat(?:in[ée]?e?)?"#)?,
-        |_| Ok(helpers::hour(4, false)?
-                .span_to(&helpers::hour(12, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+        |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
     );
     b.rule_1_terminal("début de matinée",
         b.reg(r#"(?:le matin (?:tr[eè]s )?t[ôo]t|(?:tr[eè]s )?t[ôo]t le matin|d[ée]but de matin[ée]e)"#)?,
-        |_| Ok(helpers::hour(4, false)?
-                .span_to(&helpers::hour(9, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+        |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 4, 12, 4, 9)?.latent())
     );
     b.rule_1_terminal("petit dejeuner",
         b.reg(r#"petit[- ]d[ée]jeuner"#)?,
-        |_| Ok(helpers::hour(5, false)?
-                .span_to(&helpers::hour(10, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Breakfast, 5, 10)?.latent())
     );
     b.rule_1_terminal("milieu de matinée",
         b.reg(r#"milieu de matin[ée]e"#)?,
-        |_| Ok(helpers::hour(9, false)?
-                .span_to(&helpers::hour(11, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+        |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 4, 12, 9, 11)?.latent())
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"brunch"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Brunch, 10, 15)?.latent())
     );
     b.rule_1_terminal("fin de matinée",
         b.reg(r#"fin de matin[ée]e"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(12, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+        |_| Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 4, 12, 10, 12)?.latent())
     );
     b.rule_1_terminal("déjeuner",
         b.reg(r#"d[eéè]jeuner"#)?,
-        |_| Ok(helpers::hour(12, false)?
-                .span_to(&helpers::hour(14, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Lunch, 12, 14)?.latent())
     );
     b.rule_1_terminal("après le déjeuner",
         b.reg(r#"apr[eè]s (?:le )?d[eéè]jeuner"#)?,
@@ -1052,64 +1155,47 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("après-midi",
         b.reg(r#"apr[eéè]s?[ \-]?midi|aprem"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                    .span_to(&helpers::hour(19, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+            Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
         }
     );
     b.rule_1_terminal("début d'après-midi",
         b.reg(r#"d[ée]but (?:d'|de l')(?:apr[eéè]s?[ \-]?midi|aprem)"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                    .span_to(&helpers::hour(15, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Afternoon, 12, 19, 12, 15)?.latent())
         }
     );
     b.rule_1_terminal("milieu d'après-midi",
         b.reg(r#"milieu (?:d'|de l')(?:apr[eéè]s?[ \-]?midi|aprem)"#)?,
         |_| {
-            Ok(helpers::hour(15, false)?
-                    .span_to(&helpers::hour(17, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Afternoon, 12, 19, 15, 17)?.latent())
         }
     );
     b.rule_1_terminal("gouter",
         b.reg(r#"(?:(?:[àa] )?l[' ]heure du|au moment du|pendant le|pour le) go[uû]ter"#)?,
-        |_| Ok(helpers::hour(16, false)?
-                .span_to(&helpers::hour(18, false)?, false)?
-                .form(Form::Meal))
+        |_| helpers::meal(MealForm::Snack, 16, 18)
     );
     b.rule_1_terminal("thé",
         b.reg(r#"(?:(?:[àa] )?l[' ]heure du|au moment du|pendant le|pour le) th[eé]"#)?,
         |_| Ok(helpers::hour(15, false)?
                 .span_to(&helpers::hour(17, false)?, false)?
-                .form(Form::Meal))
+                .form(Form::Meal(MealForm::Snack)))
     );
     b.rule_1_terminal("cafe",
         b.reg(r#"(?:(?:[àa] )?l[' ]heure du|au moment du|pendant le|pour le) caf[eé]"#)?,
         |_| Ok(helpers::hour(14, false)?
                 .span_to(&helpers::hour(16, false)?, false)?
-                .form(Form::Meal))
+                .form(Form::Meal(MealForm::Snack)))
     );
     b.rule_1_terminal("fin d'après-midi",
         b.reg(r#"fin (?:d'|de l')(?:apr[eéè]s?[ \-]?midi|aprem)"#)?,
         |_| {
-            Ok(helpers::hour(17, false)?
-                    .span_to(&helpers::hour(19, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Afternoon, 12, 19, 17, 19)?.latent())
         }
     );
     b.rule_1_terminal("début de journée",
         b.reg(r#"d[ée]but de (?:la )?journ[ée]e"#)?,
         |_| {
-            Ok(helpers::hour(6, false)?
-                    .span_to(&helpers::hour(10, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Morning)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Morning, 4, 12, 6, 10)?.latent())
         }
     );
     b.rule_1_terminal("milieu de journée",
@@ -1124,77 +1210,60 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("fin de journée",
         b.reg(r#"fin de (?:la )?journ[ée]e"#)?,
         |_| {
-            Ok(helpers::hour(17, false)?
-                    .span_to(&helpers::hour(21, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Evening)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 17, 21)?.latent())
         }
     );
     b.rule_1_terminal("soir",
         b.reg(r#"soir[ée]?e?"#)?,
         |_| {
-            Ok(helpers::hour(18, false)?
-                    .span_to(&helpers::hour(0, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Evening)))
+            Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
         }
     );
     b.rule_1_terminal("début de soirée",
         b.reg(r#"d[ée]but de (?:la )?soir[ée]e?"#)?,
         |_| {
-            Ok(helpers::hour(18, false)?
-                    .span_to(&helpers::hour(21, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Evening)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 18, 21)?.latent())
         }
     );
     b.rule_1_terminal("fin de soirée",
         b.reg(r#"fin de (?:la )?soir[ée]e?"#)?,
         |_| {
-            Ok(helpers::hour(21, false)?
-                    .span_to(&helpers::hour(0, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Evening)))
+            Ok(helpers::part_of_day_portion(PartOfDayForm::Evening, 18, 0, 21, 0)?.latent())
         }
     );
     b.rule_1_terminal("diner",
         b.reg(r#"d[iî]ner|souper"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
-                .form(Form::Meal))
+        |_| helpers::meal(MealForm::Dinner, 18, 23)
     );
     b.rule_1_terminal("nuit", 
         b.reg(r#"nuit"#)?,
         |_| {
-            Ok(helpers::hour(22, false)?
-                    .span_to(&helpers::hour(6, false)?, false)?
-                    .latent()
-                    .form(Form::PartOfDay(PartOfDayForm::Night)))
+            Ok(helpers::part_of_day(PartOfDayForm::Night, 22, 6)?.latent())
         }
     );
     b.rule_2("a l'heure <meal>",
         b.reg(r#"(?:[àa] )?l[' ]heure du|au moment du|pendant l[ea']|au|pour l[ea']|l[ea']"#)?,
-         time_check!(|time: &TimeValue| time.latent && form!(Form::Meal)(time)),
+         time_check!(|time: &TimeValue| time.latent && form!(Form::Meal(_))(time)),
         |_, a| Ok(a.value().clone().not_latent())
     );
     b.rule_2("<dim time> <meal>",
              time_check!(),
-             time_check!(form!(Form::Meal)),
+             time_check!(form!(Form::Meal(_))),
              |a, b| a.value().intersect(b.value())
     );
     b.rule_2("du|dans le <part-of-day>",
              b.reg(r#"pendant(?: l[ae']?)?|durant(?: l[ae']?)?|du|(?:[aà]|dans) l[ae']?|au|en|l[ae']|d[èe]s(?: l[ae']?)?"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, a| Ok(a.value().clone().not_latent())
     );
     b.rule_2("ce <part-of-day>",
              b.reg(r#"cet?t?e?"#)?,
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |_, a| Ok(helpers::cycle_nth(Grain::Day, 0)?.intersect(a.value())?.form(a.value().form.clone()))
     );
     b.rule_2("<dim time> <part-of-day>",
              time_check!(excluding_form!(Form::TimeOfDay(_))),
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |a, b| a.value().intersect(b.value())
     );
     b.rule_2("<dim time> du matin",
@@ -1216,20 +1285,14 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              }
     );
     b.rule_3("<part-of-day> du <dim time>",
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              b.reg(r#"du"#)?,
              time_check!(),
              |a, _, b| b.value().intersect(a.value())
     );
     b.rule_1_terminal("week-end",
         b.reg(r#"week(?:\s|-)?end"#)?,
-        |_| {
-            let friday = helpers::day_of_week(Weekday::Fri)?
-                                .intersect(&helpers::hour(18, false)?)?;
-            let monday = helpers::day_of_week(Weekday::Mon)?
-                                .intersect(&helpers::hour(0, false)?)?;
-            friday.span_to(&monday, false)
-        }
+        |_| helpers::weekend(true)
     );
     b.rule_1_terminal("début de semaine",
         b.reg(r#"(?:en |au )?d[ée]but de (?:cette |la )?semaine"#)?,
@@ -1529,47 +1592,88 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt|cet|cest)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("heure de Paris",
+                      b.reg(r#"heure (?:de paris|fran[çc]aise|de france)"#)?,
+                      |_| helpers::timezone("europe/paris")
+    );
+    b.rule_1_terminal("heure de Londres",
+                      b.reg(r#"heure (?:de londres|anglaise)"#)?,
+                      |_| helpers::timezone("europe/london")
+    );
+    b.rule_1_terminal("heure de Berlin",
+                      b.reg(r#"heure (?:de berlin|allemande)"#)?,
+                      |_| helpers::timezone("europe/berlin")
+    );
+    b.rule_1_terminal("heure de New York",
+                      b.reg(r#"heure (?:de new york|de la c[ôo]te est)"#)?,
+                      |_| helpers::timezone("america/new_york")
+    );
+    b.rule_1_terminal("heure du Pacifique",
+                      b.reg(r#"heure (?:du pacifique|de la c[ôo]te ouest|de los angeles)"#)?,
+                      |_| helpers::timezone("america/los_angeles")
+    );
+    b.rule_1_terminal("heure de Tokyo",
+                      b.reg(r#"heure (?:de tokyo|japonaise)"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp", number_check!(), |a| {
         Ok(TemperatureValue {
             value: a.value().value(),
             unit: None,
             latent: true,
+            ..TemperatureValue::default()
         })
     });
     b.rule_2("<latent temp> degrees",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:deg(?:r[éeè])?s?\.?)|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Celcius",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"c(?:el[cs]?(?:ius)?)?\.?"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Fahrenheit",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f(?:ah?reh?n(?:h?eit)?)?\.?"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<latent temp> en dessous de zero",
-             temperature_check!(),
-             b.reg(r#"en dessous de (?:0|z[ée]ro)"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"(?:en dessous de|au-dessous de|sous) (?:0|z[ée]ro)"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
@@ -1577,6 +1681,316 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_1_terminal("en dessous du point de congélation",
+                      b.reg(r#"(?:en dessous|au-dessous) du (?:point de )?(?:gel|cong[ée]lation)"#)?,
+                      |_| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: Some(TemperatureUnit::Celsius),
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("entre <temp> et <temp>",
+             b.reg(r#"entre"#)?,
+             temperature_check!(),
+             b.reg(r#"et"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("de <temp> à <temp>",
+             b.reg(r#"de|depuis"#)?,
+             temperature_check!(),
+             b.reg(r#"[àa]|jusqu'[àa]"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-|[àa]"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("moins de <temp>",
+             b.reg(r#"moins de|en dessous de|au-dessous de|inf[ée]rieure? [àa]|au plus|au maximum|pas plus de|jusqu'[àa]"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> ou moins",
+             temperature_check!(),
+             b.reg(r#"ou moins|maximum|max"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("plus de <temp>",
+             b.reg(r#"plus de|au-dessus de|sup[ée]rieure? [àa]|au moins|au minimum|[àa] partir de"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> ou plus",
+             temperature_check!(),
+             b.reg(r#"ou plus|minimum|min"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_quantity(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> kilomètre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"km|kilom[eè]tres?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilometre))
+    );
+    b.rule_2("<number> mètre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"m|m[eè]tres?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Metre))
+    );
+    b.rule_2("<number> mile",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"miles?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Mile))
+    );
+    b.rule_2("<number> pied",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ft|pieds?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Foot))
+    );
+    b.rule_2("<number> litre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"l|litres?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Litre))
+    );
+    b.rule_2("<number> millilitre",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"ml|millilitres?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Millilitre))
+    );
+    b.rule_2("<number> tasse",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"tasses?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Cup))
+    );
+    b.rule_2("<number> gallon",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"gallons?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gallon))
+    );
+    b.rule_2("<number> kilogramme",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"kgs?|kilo(?:gramme)?s?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Kilogram))
+    );
+    b.rule_2("<number> gramme",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"g|gr|grammes?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Gram))
+    );
+    b.rule_2("<number> livre (masse)",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"lbs?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Pound))
+    );
+    b.rule_2("<number> once",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"oz|onces?"#)?,
+             |a, _| Ok(QuantityValue::new(a.value().value(), QuantityUnit::Ounce))
+    );
+    b.rule_2("about <quantity>",
+             b.reg(r#"(?:autour|pas loin|pr[eè]s|aux alentours) d[e']|environ|(?:approximative|quasi)ment"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Approximate))
+    );
+    b.rule_2("exactly <quantity>",
+             b.reg(r#"(?:tr[eè]s )?exactement|pr[eé]cis[eé]ment"#)?,
+             quantity_check!(),
+             |_, quantity| Ok(quantity.value().clone().precision(Exact))
+    );
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("phone number (spoken digits)",
+                      b.reg(r#"(?:plus\s+)?(?:z[ée]ro|un|deux|trois|quatre|cinq|six|sept|huit|neuf)(?:[\s-]+(?:z[ée]ro|un|deux|trois|quatre|cinq|six|sept|huit|neuf)){6,14}"#)?,
+                      |text_match| helpers::spoken_phone_number(
+                          text_match.group(0),
+                          |word| match word {
+                              "plus" => Some("+"),
+                              "zéro" | "zero" => Some("0"),
+                              "un" => Some("1"),
+                              "deux" => Some("2"),
+                              "trois" => Some("3"),
+                              "quatre" => Some("4"),
+                              "cinq" => Some("5"),
+                              "six" => Some("6"),
+                              "sept" => Some("7"),
+                              "huit" => Some("8"),
+                              "neuf" => Some("9"),
+                              _ => None,
+                          },
+                          |_| None)
+    );
+    Ok(())
+}
+
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("entre <number> et <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"et|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("de <number> à <number>",
+             b.reg(r#"de"#)?,
+             number_check!(),
+             b.reg(r#"[àa]|-"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number> à <number>",
+             number_check!(),
+             b.reg(r#"[àa]"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("au moins <number>",
+             b.reg(r#"au moins|minimum|pas moins de|au minimum"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number> ou plus",
+             number_check!(),
+             b.reg(r#"ou plus|et plus|\+"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("plus de <number>",
+             b.reg(r#"plus de|sup[ée]rieur [àa]|au-dessus de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("jusqu'à <number>",
+             b.reg(r#"jusqu'[àa]|au plus|au maximum|maximum|pas plus de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number> ou moins",
+             number_check!(),
+             b.reg(r#"ou moins"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("moins de <number>",
+             b.reg(r#"moins de|inf[ée]rieur [àa]|en dessous de"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("quotidien",
+        b.reg(r#"quotidien(?:ne)?(?:ment)?"#)?,
+        |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("hebdomadaire",
+        b.reg(r#"hebdomadaire(?:ment)?"#)?,
+        |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("mensuel",
+        b.reg(r#"mensuel(?:le)?(?:lement)?"#)?,
+        |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("trimestriel",
+        b.reg(r#"trimestriel(?:le)?(?:lement)?"#)?,
+        |_| Ok(RecurrenceValue::new(Grain::Quarter))
+    );
+    b.rule_1_terminal("annuel",
+        b.reg(r#"annuel(?:le)?(?:lement)?"#)?,
+        |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_2("tous les <cycle>",
+             b.reg(r#"tou(?:te)?s les|chaque"#)?,
+             cycle_check!(),
+             |_, a| Ok(RecurrenceValue::new(a.value().grain))
+    );
+    b.rule_3("tous les <integer> <cycle>",
+             b.reg(r#"tou(?:te)?s les"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, n, a| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_2("tous les <day-of-week>",
+             b.reg(r#"tous les|chaque"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("un <day-of-week> sur deux",
+             b.reg(r#"une?"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"sur deux"#)?,
+             |_, a, _| RecurrenceValue::new(Grain::Week).every(2)?.at(a.value())
+    );
+    b.rule_3("<recurrence> et <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"et|,"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence> <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<recurrence> à partir de <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             b.reg(r#"[àa] partir d[ue']|d[èe]s(?: l[ea'])?"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().starting(b.value())
+    );
+    b.rule_3("<recurrence> jusqu'à <time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             b.reg(r#"jusqu ?'? ?(?:à|au|en)"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().until(b.value())
+    );
+    Ok(())
+}
+
+/// Tens of Belgian French, "septante" and "nonante"
+pub fn rules_numbers_be(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rules_regional_tens(b, r#"(septante|nonante)"#)
+}
+
+/// Tens of Swiss French, "septante", "huitante" or "octante", and "nonante"
+pub fn rules_numbers_ch(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rules_regional_tens(b, r#"(septante|huitante|octante|nonante)"#)
+}
+
+fn rules_regional_tens(b: &mut RuleSetBuilder<Dimension>, tens: &str) -> RustlingResult<()> {
+    b.rule_1_terminal("number (70, 80, 90) (regional)",
+             b.reg(tens)?,
+             |text_match| {
+                 let value = match text_match.group(1).as_ref() {
+                     "septante" => 70,
+                     "huitante" => 80,
+                     "octante" => 80,
+                     "nonante" => 90,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 IntegerValue::new(value)
+             });
+    b.rule_3("numbers 71 81 91 (regional)",
+             integer_check_by_range!(70, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             b.reg(r#"-?et-?"#)?,
+             integer_check_by_range!(1, 1),
+             |a, _, b| IntegerValue::new(a.value().value + b.value().value));
+    b.rule_2("numbers 72..79 82..89 92..99 (regional)",
+             integer_check_by_range!(70, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             integer_check_by_range!(2, 9),
+             |a, b| IntegerValue::new(a.value().value + b.value().value));
+    b.rule_3("numbers 72..79 82..89 92..99 (regional)",
+             integer_check_by_range!(70, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             b.reg(r"-")?,
+             integer_check_by_range!(2, 9),
+             |a, _, b| IntegerValue::new(a.value().value + b.value().value));
     Ok(())
 }
 
@@ -1814,6 +2228,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -2004,9 +2419,29 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> et demi",
         integer_check_by_range!(0, 99),
         b.reg(r#"et demie?"#)?,
-        |integer, _| {
-            FloatValue::new(integer.value().value as f32 + 0.5)
-        }
+        |integer, _| helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, 2)?)
+    );
+    b.rule_2("<number> et un <fraction denominator>",
+        integer_check_by_range!(0, 99),
+        b.reg(r#"et un (tiers|quart)"#)?,
+        |integer, text_match| helpers::compose_mixed_fraction(
+            integer.value().value,
+            &FloatValue::new_fraction(1, fraction_denominator(text_match.group(1))?)?)
+    );
+    b.rule_2("<integer> <fraction denominator>",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"(demie?s?|tiers|quarts?|cinqui[eè]mes?|sixi[eè]mes?|septi[eè]mes?|huiti[eè]mes?|neuvi[eè]mes?|dixi[eè]mes?)"#)?,
+        |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_1_terminal("fraction (numeric)",
+        b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+        |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+        b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+        |text_match| helpers::compose_mixed_fraction(
+            text_match.group(1).parse()?,
+            &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
     );
     b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
         b.reg(r#"(sept|huit|non)ante( et un)"#)?,
@@ -2116,3 +2551,18 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word.trim_right_matches('s') {
+        "demi" | "demie" => Ok(2),
+        "tier" => Ok(3),
+        "quart" => Ok(4),
+        "cinquième" | "cinquieme" => Ok(5),
+        "sixième" | "sixieme" => Ok(6),
+        "septième" | "septieme" => Ok(7),
+        "huitième" | "huitieme" => Ok(8),
+        "neuvième" | "neuvieme" => Ok(9),
+        "dixième" | "dixieme" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
@@ -4,6 +4,9 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "entre 10 et 20 euros", "entre 10€ et 20€", "10-20 €");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "plus de 100 €", "au moins cent euros");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "moins de 50 dollars", "50$ maximum");
     example!(v, check_finance(2000.0, Some("$"), Precision::Exact), "deux milles dollars");
     example!(v, check_finance(10.0, Some("GBP"), Precision::Exact), "dix livres sterlings");
     example!(v, check_finance(2.05, Some("EUR"), Precision::Exact), "deux euros et cinq centimes");
@@ -72,6 +75,10 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2012]), "l'année dernière");
     example!(v, check_moment!(c, [2013]), "cette année");
     example!(v, check_moment!(c, [2014]), "l'année prochaine");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "le trimestre prochain", "2ème trimestre");
+    example!(v, check_moment!(c, [2014, 7, 1], Grain::Quarter), "3ème trimestre 2014", "T3 2014");
+    example!(v, check_moment!(c, [2013]), "cet exercice", "l'exercice fiscal en cours");
+    example!(v, check_moment!(c, [2014]), "l'exercice prochain", "l'exercice fiscal 2014", "exercice comptable 2014");
     example!(v, check_moment!(c, [2013, 2, 10]), "dimanche dernier", "dimanche de la semaine dernière");
     example!(v, check_moment!(c, [2013, 10, 3]), "3eme jour d'octobre", "le 3eme jour d'octobre");
     example!(v, check_moment!(c, [2014, 10, 6], Grain::Week), "premiere semaine d'octobre 2014", "la premiere semaine d'octobre 2014");
@@ -207,6 +214,9 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "dans une demi heure", "dans 1/2h", "dans 1/2 h", "dans 1/2 heure");
     example!(v, check_moment!(c, [2013, 2, 12, 5, 15, 0]), "dans trois quarts d'heure", "dans 3/4h", "dans 3/4 h", "dans 3/4 heure");
     example!(v, check_moment!(c, [2016, 12, 15]), "15.12.2016", "15.12.16");
+    example!(v, check_timezone("Europe/Paris"), "9h heure de Paris", "à 9h heure française");
+    example!(v, check_timezone("UTC"), "midi UTC", "15h gmt");
+    example!(v, check_timezone("America/New_York"), "18h heure de New York");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -219,6 +229,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 0, 15], Precision::Approximate), "pendant environ un quart d'heure", "environ 1/4h");
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durant une heure");
     example!(v, check_duration!([0, 0, 2]), "pendant 2 semaines");
+    example!(v, check_duration!([0, 0, 0, 0, 2, 30]), "pendant deux heures et demie", "pendant 2 1/2 heures");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -238,6 +249,9 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(5000), "5 milles", "cinq milles");
     example!(v, check_integer(200000), "deux cents milles");
     example!(v, check_integer(21011), "vingt-et-un mille onze");
+    example!(v, check_fraction(2, 3), "deux tiers", "2/3");
+    example!(v, check_fraction(3, 4), "trois quarts", "3/4");
+    example!(v, check_fraction(5, 2), "deux et demi", "2 1/2");
     example!(v,
              check_integer(721012),
              "sept cents vingt et un milles douze",
@@ -291,3 +305,72 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "troisième",
              "troisieme");
 }
+
+pub fn examples_numbers_be(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(70), "septante");
+    example!(v, check_integer(71), "septante et un", "septante-et-un");
+    example!(v, check_integer(92), "nonante deux", "nonante-deux");
+}
+
+pub fn examples_numbers_ch(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    examples_numbers_be(v);
+    example!(v, check_integer(80), "huitante", "octante");
+    example!(v, check_integer(87), "huitante-sept", "octante sept");
+}
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "entre 3 et 5", "entre 3-5", "de 3-5", "de trois à cinq", "3 à 5");
+    example!(v, check_number_interval(Some(3.0), None), "au moins 3", "trois ou plus");
+    example!(v, check_number_interval(None, Some(10.0)), "jusqu'à 10", "au plus dix");
+    example!(v, check_number_interval(Some(100.0), None), "plus de 100", "plus de cent");
+    example!(v, check_number_interval(None, Some(4.0)), "moins de 4", "moins de quatre");
+}
+
+pub fn examples_quantity(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity(5.0, QuantityUnit::Kilometre, Precision::Exact), "5 km", "cinq kilomètres");
+    example!(v, check_quantity(3.0, QuantityUnit::Mile, Precision::Exact), "3 miles", "trois miles");
+    example!(v, check_quantity(100.0, QuantityUnit::Metre, Precision::Exact), "100 m", "cent mètres");
+    example!(v, check_quantity(6.0, QuantityUnit::Foot, Precision::Exact), "6 pieds", "six pieds");
+    example!(v, check_quantity(2.0, QuantityUnit::Litre, Precision::Exact), "2 l", "deux litres");
+    example!(v, check_quantity(250.0, QuantityUnit::Millilitre, Precision::Exact), "250 ml", "250 millilitres");
+    example!(v, check_quantity(2.0, QuantityUnit::Cup, Precision::Exact), "2 tasses", "deux tasses");
+    example!(v, check_quantity(10.0, QuantityUnit::Gallon, Precision::Exact), "10 gallons", "dix gallons");
+    example!(v, check_quantity(3.0, QuantityUnit::Kilogram, Precision::Exact), "3 kg", "trois kilos", "trois kilogrammes");
+    example!(v, check_quantity(500.0, QuantityUnit::Gram, Precision::Exact), "500 g", "cinq cents grammes");
+    example!(v, check_quantity(3.0, QuantityUnit::Pound, Precision::Exact), "3 lbs");
+    example!(v, check_quantity(8.0, QuantityUnit::Ounce, Precision::Exact), "8 oz", "huit onces");
+    example!(v, check_quantity(10.0, QuantityUnit::Kilometre, Precision::Approximate), "environ 10 km", "aux alentours de dix kilomètres");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("john.doe@example.com"), "john.doe@example.com");
+    example!(v, check_url("https://www.example.com/path?q=1", "example.com"), "https://www.example.com/path?q=1");
+    example!(v, check_url("example.org", "example.org"), "example.org");
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78");
+    example!(v, check_phone_number("0612345678"), "zéro six un deux trois quatre cinq six sept huit", "06 12 34 56 78", "06.12.34.56.78");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 degrés", "trente degrés", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "moins cinq degrés", "-5°", "5 degrés en dessous de zéro");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5°C", "moins 5 celsius");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "entre 18 et 22 degrés", "de 18 à 22 degrés", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "plus de 30 degrés", "au moins 30 degrés");
+    example!(v, check_temperature_interval(None, Some(0.0), Some(TemperatureUnit::Celsius)), "en dessous du point de congélation");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Degree)), "moins de 10 degrés", "10 degrés ou moins");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Tue], None), "tous les mardis", "chaque mardi");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "tous les jours à 8h", "chaque jour à 8 heures", "quotidiennement à 8h");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "toutes les deux semaines", "toutes les 2 semaines");
+    example!(v, check_recurrence(Grain::Week, 1, vec![], None), "hebdomadaire", "chaque semaine", "toutes les semaines");
+    example!(v, check_recurrence(Grain::Week, 2, vec![Weekday::Fri], None), "un vendredi sur deux");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon, Weekday::Wed], Some(18)), "tous les lundis et mercredis à 18h");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::days(2).into()), Some(PeriodComp::days(3).into())), "entre deux et trois jours", "de 2 à 3 jours", "2-3 jours");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "au moins 20 minutes", "20 minutes ou plus");
+    example!(v, check_duration_interval(None, Some(PeriodComp::hours(1).into())), "moins d'une heure", "au plus 1 heure");
+}
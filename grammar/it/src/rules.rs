@@ -0,0 +1,1181 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, DateOrder};
+
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+        number_check!(),
+        b.reg(r"(?:%|per ?cento)")?,
+        |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect (X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, b| helpers::compose_money(a.value(), b.value()));
+    b.rule_3("intersect (and X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, _, b| helpers::compose_money(&a.value(), &b.value()));
+    b.rule_2("intersect",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             number_check!(),
+             |a, b| helpers::compose_money_number(&a.value(), &b.value()));
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::IT))?,
+                      |text_match| helpers::money_unit(names::IT, text_match.group(0))
+    );
+    b.rule_2("<amount> <unit>",
+             number_check!(),
+             money_unit!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("<unit> <amount>",
+             money_unit!(),
+             number_check!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: b.value().value(),
+                     unit: a.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_4("tra <amount-of-money> e <amount-of-money>",
+             b.reg(r#"tra|fra"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("tra <number> e <amount-of-money>",
+             b.reg(r#"tra|fra"#)?,
+             number_check!(),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("da <amount-of-money> a <amount-of-money>",
+             b.reg(r#"da"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"a"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("da <number> a <amount-of-money>",
+             b.reg(r#"da"#)?,
+             number_check!(),
+             b.reg(r#"a"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("meno di <amount-of-money>",
+             b.reg(r#"meno di|al massimo|fino a|non più di|sotto i"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> o meno",
+             amount_of_money_check!(),
+             b.reg(r#"o meno|al massimo"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("più di <amount-of-money>",
+             b.reg(r#"pi[ùu] di|almeno|come minimo|a partire da|sopra i"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> o più",
+             amount_of_money_check!(),
+             b.reg(r#"o pi[ùu]|come minimo|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("second (unit-of-duration)",
+                      b.reg(r#"sec(?:ond[oi])?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Second))
+    );
+    b.rule_1_terminal("minute (unit-of-duration)",
+                      b.reg(r#"min(?:ut[oi])?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Minute))
+    );
+    b.rule_1_terminal("hour (unit-of-duration)",
+                      b.reg(r#"or[ae]|h"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("day (unit-of-duration)",
+                      b.reg(r#"giorn[oi]|gg"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("week (unit-of-duration)",
+                      b.reg(r#"settiman[ae]"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("month (unit-of-duration)",
+                      b.reg(r#"mes[ei]"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("year (unit-of-duration)",
+                      b.reg(r#"ann[oi]"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Year))
+    );
+    b.rule_1_terminal("half an hour",
+                      b.reg(r#"mezz'? ?ora"#)?,
+                      |_| Ok(DurationValue::new(PeriodComp::minutes(30).into()))
+    );
+    b.rule_1_terminal("quarter of an hour",
+                      b.reg(r#"un quarto d'? ?ora"#)?,
+                      |_| Ok(DurationValue::new(PeriodComp::minutes(15).into()))
+    );
+    b.rule_2("<integer> <unit-of-duration>",
+             integer_check_by_range!(0),
+             unit_of_duration_check!(),
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+    );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
+    b.rule_3("<integer> <unit-of-duration> e mezzo",
+             integer_check_by_range!(0),
+             unit_of_duration_check!(),
+             b.reg(r#"e mezz[oa]"#)?,
+             |integer, uod, _| Ok(DurationValue::new(helpers::period_from_fraction(integer.value().value as f32 + 0.5, uod.value().grain)?))
+    );
+    b.rule_2("tra <duration>",
+             b.reg(r#"tra|fra|in"#)?,
+             duration_check!(),
+             |_, duration| duration.value().in_present()
+    );
+    b.rule_2("<duration> fa",
+             duration_check!(),
+             b.reg(r#"fa"#)?,
+             |duration, _| duration.value().ago()
+    );
+    b.rule_4("tra <duration> e <duration>",
+             b.reg(r#"tra|fra"#)?,
+             duration_check!(),
+             b.reg(r#"e"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("tra <number> e <duration>",
+             b.reg(r#"tra|fra"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"e"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("da <duration> a <duration>",
+             b.reg(r#"da"#)?,
+             duration_check!(),
+             b.reg(r#"a"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("da <number> a <duration>",
+             b.reg(r#"da"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"a"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|o"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|o"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("almeno <duration>",
+             b.reg(r#"almeno|come minimo|pi[ùu] di"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> o più",
+             duration_check!(),
+             b.reg(r#"o pi[ùu]|come minimo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("meno di <duration>",
+             b.reg(r#"meno di|al massimo|non pi[ùu] di"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> o meno",
+             duration_check!(),
+             b.reg(r#"o meno|al massimo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("secondo (cycle)",
+                      b.reg(r#"second[oi]"#)?,
+                      |_| CycleValue::new(Grain::Second)
+    );
+    b.rule_1_terminal("minuto (cycle)",
+                      b.reg(r#"minut[oi]"#)?,
+                      |_| CycleValue::new(Grain::Minute)
+    );
+    b.rule_1_terminal("ora (cycle)",
+                      b.reg(r#"or[ae]"#)?,
+                      |_| CycleValue::new(Grain::Hour)
+    );
+    b.rule_1_terminal("giorno (cycle)",
+                      b.reg(r#"giorn[oi]"#)?,
+                      |_| CycleValue::new(Grain::Day)
+    );
+    b.rule_1_terminal("settimana (cycle)",
+                      b.reg(r#"settiman[ae]"#)?,
+                      |_| CycleValue::new(Grain::Week)
+    );
+    b.rule_1_terminal("mese (cycle)",
+                      b.reg(r#"mes[ei]"#)?,
+                      |_| CycleValue::new(Grain::Month)
+    );
+    b.rule_1_terminal("trimestre (cycle)",
+                      b.reg(r#"trimestr[ei]"#)?,
+                      |_| CycleValue::new(Grain::Quarter)
+    );
+    b.rule_1_terminal("anno (cycle)",
+                      b.reg(r#"ann[oi]"#)?,
+                      |_| CycleValue::new(Grain::Year)
+    );
+    b.rule_2("questo <cycle>",
+             b.reg(r#"quest[oa]|quest'"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 0)
+    );
+    b.rule_3("il <cycle> scorso",
+             b.reg(r#"il|la|l'|lo"#)?,
+             cycle_check!(),
+             b.reg(r#"scors[oa]|passat[oa]"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_3("lo scorso <cycle>",
+             b.reg(r#"il|la|l'|lo"#)?,
+             b.reg(r#"scors[oa]|passat[oa]"#)?,
+             cycle_check!(),
+             |_, _, cycle| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_3("il <cycle> prossimo",
+             b.reg(r#"il|la|l'|lo"#)?,
+             cycle_check!(),
+             b.reg(r#"prossim[oa]|successiv[oa]|seguente"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_3("il prossimo <cycle>",
+             b.reg(r#"il|la|l'|lo"#)?,
+             b.reg(r#"prossim[oa]"#)?,
+             cycle_check!(),
+             |_, _, cycle| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_4("il <cycle> prima di <time>",
+             b.reg(r#"il|la|l'|lo"#)?,
+             cycle_check!(),
+             b.reg(r#"prima d(?:i|el|ella|ell')"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, -1, time.value())
+    );
+    b.rule_4("il <cycle> dopo <time>",
+             b.reg(r#"il|la|l'|lo"#)?,
+             cycle_check!(),
+             b.reg(r#"dopo"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, 1, time.value())
+    );
+    b.rule_3("ultimi n <cycle>",
+             b.reg(r#"(?:gli |le )?ultim[ie]"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_3("prossimi n <cycle>",
+             b.reg(r#"(?:i |le )?prossim[ie]"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_2("<ordinal> quarter",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             |ordinal, _| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 &helpers::cycle_nth(Grain::Year, 0)?
+             )
+    );
+    b.rule_3("<ordinal> quarter <year>",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             time_check!(),
+             |ordinal, _, time| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 time.value()
+             )
+    );
+    Ok(())
+}
+
+pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect",
+             time_check!(|time: &TimeValue| !time.latent),
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, b| a.value().intersect(b.value())
+    );
+    b.rule_3("intersect by `di`",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"di|del(?:l')?"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_3("two time tokens separated by \",\"",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#","#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"luned[ìi]|lun\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Mon)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"marted[ìi]|mar\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Tue)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"mercoled[ìi]|mer\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Wed)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"gioved[ìi]|gio\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Thu)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"venerd[ìi]|ven\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Fri)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"sabato|sab\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Sat)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"domenica|dom\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Sun)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"gennaio|gen\.?"#)?,
+                      |_| helpers::month(1)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"febbraio|feb\.?"#)?,
+                      |_| helpers::month(2)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"marzo|mar\."#)?,
+                      |_| helpers::month(3)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"aprile|apr\.?"#)?,
+                      |_| helpers::month(4)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"maggio|mag\.?"#)?,
+                      |_| helpers::month(5)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"giugno|giu\.?"#)?,
+                      |_| helpers::month(6)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"luglio|lug\.?"#)?,
+                      |_| helpers::month(7)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"agosto|ago\.?"#)?,
+                      |_| helpers::month(8)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"settembre|set\.?"#)?,
+                      |_| helpers::month(9)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"ottobre|ott\."#)?,
+                      |_| helpers::month(10)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"novembre|nov\.?"#)?,
+                      |_| helpers::month(11)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"dicembre|dic\.?"#)?,
+                      |_| helpers::month(12)
+    );
+    b.rule_1_terminal("Natale",
+                      b.reg(r#"natale"#)?,
+                      |_| helpers::month_day(12, 25)
+    );
+    b.rule_1_terminal("vigilia di Natale",
+                      b.reg(r#"(?:la )?vigilia di natale"#)?,
+                      |_| helpers::month_day(12, 24)
+    );
+    b.rule_1_terminal("San Silvestro",
+                      b.reg(r#"san silvestro"#)?,
+                      |_| helpers::month_day(12, 31)
+    );
+    b.rule_1_terminal("Capodanno",
+                      b.reg(r#"capodanno"#)?,
+                      |_| helpers::month_day(1, 1)
+    );
+    b.rule_1_terminal("Ferragosto",
+                      b.reg(r#"ferragosto"#)?,
+                      |_| helpers::month_day(8, 15)
+    );
+    b.rule_1_terminal("right now",
+                      b.reg(r#"adesso|subito|in questo momento|proprio ora"#)?,
+                      |_| helpers::cycle_nth(Grain::Second, 0)
+    );
+    b.rule_1_terminal("now",
+                      b.reg(r#"oggi"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 0)
+    );
+    b.rule_1_terminal("tomorrow",
+                      b.reg(r#"domani"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 1)
+    );
+    b.rule_1_terminal("yesterday",
+                      b.reg(r#"ieri"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -1)
+    );
+    b.rule_1_terminal("the day after tomorrow",
+                      b.reg(r#"dopodomani|dopo domani"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 2)
+    );
+    b.rule_1_terminal("the day before yesterday",
+                      b.reg(r#"l'altro ?ieri|altroieri|ieri l'altro"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -2)
+    );
+    b.rule_2("this <day-of-week>", //assumed to be in the future
+             b.reg(r#"quest[oa]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("this <time>",
+             b.reg(r#"quest[oa]|quest'"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(0)
+    );
+    b.rule_2("<named-month|named-day> next",
+             time_check!(),
+             b.reg(r#"prossim[oa]"#)?,
+             |time, _| time.value().the_nth(1)
+    );
+    b.rule_2("<named-month|named-day> past",
+             time_check!(),
+             b.reg(r#"scors[oa]|passat[oa]"#)?,
+             |time, _| time.value().the_nth(-1)
+    );
+    b.rule_1("year",
+             integer_check_by_range!(1000, 2100),
+             |integer| {
+                 helpers::year(integer.value().value as i32)
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(-1000, 999),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(2101, 2200),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1_terminal("day of month (1st)",
+                      b.reg(r#"primo|1º"#)?,
+                      |_| helpers::day_of_month(1)
+    );
+    b.rule_2("<day-of-month> <named-month>",
+             integer_check_by_range!(1, 31),
+             time_check!(form!(Form::Month(_))),
+             |integer, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_2("il <day-of-month>",
+             b.reg(r#"il|l'"#)?,
+             integer_check_by_range!(1, 31),
+             |_, integer| helpers::day_of_month(integer.value().value as u32)
+    );
+    b.rule_4("ultimo <day-of-week> di <time>",
+             b.reg(r#"(?:l')?ultim[oa]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"di|del(?:l')?|della"#)?,
+             time_check!(),
+             |_, dow, _, time| dow.value().last_of(time.value())
+    );
+    b.rule_4("nth <time> di <time>",
+             ordinal_check!(),
+             time_check!(),
+             b.reg(r#"di|del(?:l')?|della"#)?,
+             time_check!(),
+             |ordinal, a, _, b| b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+    );
+    b.rule_2("<day-of-week> <day-of-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             integer_check_by_range!(1, 31),
+             |dow, integer| dow.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_1("time-of-day (latent)",
+             integer_check_by_range!(0, 23),
+             |integer| Ok(helpers::hour(integer.value().value as u32, true)?.latent())
+    );
+    b.rule_1_terminal("noon",
+                      b.reg(r#"mezzogiorno"#)?,
+                      |_| helpers::hour(12, false)
+    );
+    b.rule_1_terminal("midnight",
+                      b.reg(r#"mezzanotte"#)?,
+                      |_| helpers::hour(0, false)
+    );
+    b.rule_2("alle <time-of-day>",
+             b.reg(r#"all[e']|a|verso l[e']|le|l'"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, tod| Ok(tod.value().clone().not_latent())
+    );
+    b.rule_2("<time-of-day> in punto",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"in punto"#)?,
+             |time, _| Ok(time.value().clone().not_latent())
+    );
+    b.rule_1_terminal("hh(:|.)mm (time-of-day)",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:\.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          true
+                      )
+    );
+    b.rule_1_terminal("hh:mm:ss",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)[:.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute_second(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?,
+                          true
+                      )
+    );
+    b.rule_1_terminal("quarter (relative minutes)",
+                      b.reg(r#"(?:un )?quarto"#)?,
+                      |_| Ok(RelativeMinuteValue(15))
+    );
+    b.rule_1_terminal("half (relative minutes)",
+                      b.reg(r#"mezz[oa]"#)?,
+                      |_| Ok(RelativeMinuteValue(30))
+    );
+    b.rule_1_terminal("3 quarters (relative minutes)",
+                      b.reg(r#"tre quarti"#)?,
+                      |_| Ok(RelativeMinuteValue(45))
+    );
+    b.rule_1("number (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             |integer| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_2("<integer> minutes (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             b.reg(r#"min(?:ut[oi])?"#)?,
+             |integer, _| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_3("<hour-of-day> and <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"e"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<hour-of-day> minus <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"meno"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_2("<time-of-day> di mattina",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"(?:di|del(?:la)?) mattin[ao]"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(12, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> del pomeriggio",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"(?:di|del) pomeriggio"#)?,
+             |time, _| {
+                 let period = helpers::hour(12, false)?
+                     .span_to(&helpers::hour(21, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> di sera",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"(?:di|della) sera"#)?,
+             |time, _| {
+                 let period = helpers::hour(18, false)?
+                     .span_to(&helpers::hour(0, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_1_terminal("dd[/-.]mm[/-.]yyyy",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_1_terminal("yyyy-mm-dd",
+                      b.reg(r#"(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::ymd(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?
+                      )
+    );
+    b.rule_1_terminal("dd[/-]mm",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          None,
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_1_terminal("morning",
+                      b.reg(r#"mattin[ao]"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
+    );
+    b.rule_1_terminal("afternoon",
+                      b.reg(r#"pomeriggio"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
+    );
+    b.rule_1_terminal("evening",
+                      b.reg(r#"sera(?:ta)?"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
+    );
+    b.rule_1_terminal("night",
+                      b.reg(r#"notte"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Night, 0, 4)?.latent())
+    );
+    b.rule_1_terminal("this morning",
+                      b.reg(r#"stamattina|stamani"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                          .intersect(&helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?)?
+                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+    );
+    b.rule_1_terminal("this evening",
+                      b.reg(r#"stasera"#)?,
+                      |_| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                          .intersect(&helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?)?
+                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+    );
+    b.rule_2("in the <part-of-day>",
+             b.reg(r#"di|in|nel(?:la)?|al|la|il"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(pod.value().clone().not_latent())
+    );
+    b.rule_2("this <part-of-day>",
+             b.reg(r#"quest[oa]"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                 .intersect(pod.value())?
+                 .form(pod.value().form.clone()))
+    );
+    b.rule_2("<time> <part-of-day>",
+             time_check!(),
+             time_check!(|time: &TimeValue| !time.latent && form!(Form::PartOfDay(_))(time)),
+             |time, pod| time.value().intersect(pod.value())
+    );
+    b.rule_2("<time> <part-of-day> (latent)",
+             time_check!(|time: &TimeValue| form!(Form::DayOfWeek{..})(time) || form!(Form::Cycle(_))(time)),
+             time_check!(form!(Form::PartOfDay(_))),
+             |time, pod| time.value().intersect(pod.value())
+    );
+    b.rule_1_terminal("week-end",
+                      b.reg(r#"week[ -]?end|fine ?settimana"#)?,
+                      |_| helpers::weekend(true)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"estate"#)?,
+                      |_| helpers::month_day(6, 21)?
+                          .span_to(&helpers::month_day(9, 23)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"autunno"#)?,
+                      |_| helpers::month_day(9, 23)?
+                          .span_to(&helpers::month_day(12, 21)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"inverno"#)?,
+                      |_| helpers::month_day(12, 21)?
+                          .span_to(&helpers::month_day(3, 20)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"primavera"#)?,
+                      |_| helpers::month_day(3, 20)?
+                          .span_to(&helpers::month_day(6, 21)?, false)
+    );
+    b.rule_2("il <time>",
+             b.reg(r#"il|l'|la|lo|nel(?:l')?|nella|a"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_4("dd-dd <month>(interval)",
+             b.reg(r#"(?:dal )?(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"\-|al?"#)?,
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             time_check!(form!(Form::Month(_))),
+             |a, _, b, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_3("<datetime> - <datetime> (interval)",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"\-"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("dalle <datetime> alle <datetime> (interval)",
+             b.reg(r#"da(?:l(?:l[e'a])?)?"#)?,
+             time_check!(),
+             b.reg(r#"\-|a(?:l(?:l[e'a])?)?|fino a(?:l(?:l[e'a])?)?"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("tra <datetime> e <datetime> (interval)",
+             b.reg(r#"tra l[e']|fra l[e']|tra|fra"#)?,
+             time_check!(),
+             b.reg(r#"e(?: l[e'])?"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_2("entro <duration>",
+             b.reg(r#"entro"#)?,
+             duration_check!(),
+             |_, duration| helpers::cycle_nth(Grain::Second, 0)?.span_to(&duration.value().in_present()?, false)
+    );
+    Ok(())
+}
+
+pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as temp", number_check!(), |a| {
+        Ok(TemperatureValue {
+            value: a.value().value(),
+            unit: None,
+            latent: true,
+            ..TemperatureValue::default()
+        })
+    });
+    b.rule_2("<latent temp> temp",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"grad[oi]|°"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Degree),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Celsius",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"centigrad[oi]|c(?:elsius)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Celsius),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Fahrenheit",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"f(?:ahrenheit)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<latent temp> temp sotto zero",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"(?:grad[oi] |°)?sotto (?:lo )?zero"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_1_terminal("sotto zero",
+                      b.reg(r#"sotto (?:lo )?zero"#)?,
+                      |_| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: None,
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("tra <temp> e <temp>",
+             b.reg(r#"tra|fra"#)?,
+             temperature_check!(),
+             b.reg(r#"e"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("da <temp> a <temp>",
+             b.reg(r#"da"#)?,
+             temperature_check!(),
+             b.reg(r#"a"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("meno di <temp>",
+             b.reg(r#"meno di|al massimo|fino a|sotto i"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> o meno",
+             temperature_check!(),
+             b.reg(r#"o meno|al massimo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("più di <temp>",
+             b.reg(r#"pi[ùu] di|almeno|sopra i"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> o più",
+             temperature_check!(),
+             b.reg(r#"o pi[ùu]|come minimo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             number_check!(),
+             |a, b| helpers::compose_numbers(&a.value(), &b.value()));
+    b.rule_3("intersect (with e)",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"e"#)?,
+             number_check!(),
+             |a, _, b| helpers::compose_numbers(&a.value(), &b.value()));
+    // longer words first, "tredici" would otherwise be read as "tre"
+    b.rule_1_terminal("number (0..19)",
+                      b.reg(r#"(zero|undici|un[oa]?|due|tredici|tr[eé]|quattordici|quattro|cinque|sei|sette|otto|nove|dieci|dodici|quindici|sedici|diciassette|diciotto|diciannove)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "zero" => 0,
+                              "un" | "uno" | "una" => 1,
+                              "due" => 2,
+                              "tre" | "tré" => 3,
+                              "quattro" => 4,
+                              "cinque" => 5,
+                              "sei" => 6,
+                              "sette" => 7,
+                              "otto" => 8,
+                              "nove" => 9,
+                              "dieci" => 10,
+                              "undici" => 11,
+                              "dodici" => 12,
+                              "tredici" => 13,
+                              "quattordici" => 14,
+                              "quindici" => 15,
+                              "sedici" => 16,
+                              "diciassette" => 17,
+                              "diciotto" => 18,
+                              "diciannove" => 19,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      }
+    );
+    // the final vowel is dropped before "uno" and "otto": "ventuno", "trentotto"
+    b.rule_1_terminal("number (20..90)",
+                      b.reg(r#"(vent|trent|quarant|cinquant|sessant|settant|ottant|novant)[ia]?"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "vent" => 20,
+                              "trent" => 30,
+                              "quarant" => 40,
+                              "cinquant" => 50,
+                              "sessant" => 60,
+                              "settant" => 70,
+                              "ottant" => 80,
+                              "novant" => 90,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      });
+    b.rule_2("number (21..29 31..39 41..49 51..59 61..69 71..79 81..89 91..99)",
+             integer_check_by_range!(20, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             integer_check_by_range!(1, 9),
+             |a, b| IntegerValue::new(a.value().value + b.value().value));
+    b.rule_1_terminal("hundred",
+        b.reg(r#"cento?"#)?,
+        |_| IntegerValue::new_with_grain(100, 2)
+    );
+    b.rule_1_terminal("thousand",
+        b.reg(r#"mille"#)?,
+        |_| IntegerValue::new_with_grain(1000, 3)
+    );
+    b.rule_1_terminal("million",
+        b.reg(r#"milione"#)?,
+        |_| IntegerValue::new_with_grain(1000000, 6)
+    );
+    b.rule_2("number hundreds",
+        integer_check_by_range!(2, 9),
+        b.reg(r#"cento?"#)?,
+        |a, _| {
+            Ok(IntegerValue {
+                   value: a.value().value * 100,
+                   grain: Some(2),
+                   ..IntegerValue::default()
+               })
+    });
+    b.rule_2("number thousands",
+        integer_check_by_range!(2, 999),
+        b.reg(r#"mila"#)?,
+        |a, _| {
+            Ok(IntegerValue {
+                   value: a.value().value * 1000,
+                   grain: Some(3),
+                   ..IntegerValue::default()
+               })
+    });
+    b.rule_2("number millions",
+        integer_check_by_range!(1, 999),
+        b.reg(r#"milion[ei]"#)?,
+        |a, _| {
+            Ok(IntegerValue {
+                   value: a.value().value * 1000000,
+                   grain: Some(6),
+                   ..IntegerValue::default()
+               })
+    });
+    b.rule_1_terminal("integer (numeric)",
+                      b.reg(r#"(\d{1,18})"#)?,
+                      |text_match| IntegerValue::new(text_match.group(0).parse()?));
+    b.rule_1_terminal("integer with thousands separator .",
+                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(".", "");
+                          let value: i64 = reformatted_string.parse()?;
+                          IntegerValue::new(value)
+                      });
+    b.rule_1_terminal("decimal number",
+                      b.reg(r#"(\d*,\d+)"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(",", ".");
+                          let value: f32 = reformatted_string.parse()?;
+                          FloatValue::new(value)
+                      });
+    b.rule_1_terminal("decimal with thousands separator",
+                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
+                          let value: f32 = reformatted_string.parse()?;
+                          FloatValue::new(value)
+                      });
+    b.rule_3("number comma number",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             b.reg(r#"virgola"#)?,
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             |a, _, b| {
+                 Ok(FloatValue {
+                     value: b.value().value() * 0.1 + a.value().value(),
+                     ..FloatValue::default()
+                 })
+             });
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(terz[oi]|quart[oi]|quint[oi]|sest[oi]|settim[oi]|ottav[oi]|non[oi]|decim[oi])"#)?,
+             |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> e mezzo",
+             integer_check_by_range!(0, 99),
+             b.reg(r#"e (mezz[oa]|un terzo|un quarto)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "mezzo" | "mezza" => 2,
+                     "un terzo" => 3,
+                     "un quarto" => 4,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, denominator)?)
+             });
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+                      |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+                      b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+                      |text_match| helpers::compose_mixed_fraction(
+                          text_match.group(1).parse()?,
+                          &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
+    );
+    b.rule_2("numbers prefix with -, negative or minus",
+             b.reg(r#"-|meno"#)?,
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             |_, a| -> RuleResult<NumberValue> {
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * -1,
+                             prefixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         FloatValue {
+                             value: float.value * -1.0,
+                             prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
+                             ..float
+                         }
+                             .into()
+                     }
+                 })
+             });
+    b.rule_2("numbers suffixes (K, M, G)",
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             b.reg_neg_lh(r#"([kmg])"#, r#"^[\W\$€]"#)?,
+             |a, text_match| -> RuleResult<NumberValue> {
+                 let multiplier = match text_match.group(0).as_ref() {
+                     "k" => 1000,
+                     "m" => 1000000,
+                     "g" => 1000000000,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * multiplier,
+                             suffixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         let product = float.value * (multiplier as f32);
+                         if product.floor() == product {
+                             IntegerValue {
+                                 value: product as i64,
+                                 suffixed: true,
+                                 ..IntegerValue::default()
+                             }
+                                 .into()
+                         } else {
+                             FloatValue {
+                                 value: product,
+                                 suffixed: true,
+                                 ..float
+                             }
+                                 .into()
+                         }
+                     }
+                 })
+             });
+    b.rule_1_terminal("ordinals (primo..decimo)",
+                      b.reg(r#"(prim|second|terz|quart|quint|sest|settim|ottav|non|decim)[oaie]"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "prim" => 1,
+                              "second" => 2,
+                              "terz" => 3,
+                              "quart" => 4,
+                              "quint" => 5,
+                              "sest" => 6,
+                              "settim" => 7,
+                              "ottav" => 8,
+                              "non" => 9,
+                              "decim" => 10,
+                              _ => return Err(RuleErrorKind::Invalid.into())
+                          };
+                          Ok(OrdinalValue::new(value))
+                      });
+    b.rule_1_terminal("ordinal (digits)",
+                      b.reg(r#"(\d+) ?[ºª]"#)?,
+                      |text_match| Ok(OrdinalValue::new(text_match.group(1).parse()?))
+    );
+    Ok(())
+}
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word.trim_right_matches(|c| c == 'o' || c == 'i') {
+        "terz" => Ok(3),
+        "quart" => Ok(4),
+        "quint" => Ok(5),
+        "sest" => Ok(6),
+        "settim" => Ok(7),
+        "ottav" => Ok(8),
+        "non" => Ok(9),
+        "decim" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
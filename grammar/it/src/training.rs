@@ -0,0 +1,150 @@
+use rustling_ontology_values::check::*;
+use rustling_ontology_moment::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::ResolverContext;
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "tra 10 e 20 euro", "10-20 euro", "da 10€ a 20€");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "più di 100 euro", "almeno cento euro");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "meno di 50 dollari", "al massimo $50");
+    example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10$", "dieci dollari");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Exact), "20€", "20 euro", "venti euro");
+    example!(v, check_finance(3.5, Some("EUR"), Precision::Exact), "3 euro e 50 centesimi", "tre euro e cinquanta centesimi");
+    example!(v, check_finance(9.0, Some("£"), Precision::Exact), "£9", "nove sterline");
+    example!(v, check_finance(15.0, Some("CHF"), Precision::Exact), "15 chf", "quindici franchi svizzeri");
+    example!(v, check_finance(10000.0, Some("GBP"), Precision::Exact), "10.000 sterline inglesi");
+}
+
+pub fn examples_duration(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration!([0, 0, 0, 0, 2]), "2 ore", "due ore");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 30]), "mezz'ora", "mezz ora", "30 minuti");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 15]), "un quarto d'ora", "quindici minuti");
+    example!(v, check_duration!([0, 0, 0, 3]), "tre giorni");
+    example!(v, check_duration_interval(Some(PeriodComp::days(2).into()), Some(PeriodComp::days(3).into())), "tra due e tre giorni", "da 2 a 3 giorni", "2-3 giorni");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "almeno 20 minuti", "20 minuti o più");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "meno di una settimana", "1 settimana o meno");
+}
+
+pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "adesso", "subito", "in questo momento");
+    example!(v, check_moment!(c, [2013, 2, 12]), "oggi");
+    example!(v, check_moment!(c, [2013, 2, 11]), "ieri");
+    example!(v, check_moment!(c, [2013, 2, 10]), "l'altro ieri", "altroieri");
+    example!(v, check_moment!(c, [2013, 2, 13]), "domani");
+    example!(v, check_moment!(c, [2013, 2, 14]), "dopodomani", "dopo domani");
+    example!(v, check_moment!(c, [2013, 2, 18]), "lunedì", "lunedi", "lun.", "questo lunedì");
+    example!(v, check_moment!(c, [2013, 2, 18]), "lunedì 18 febbraio", "lunedì, 18 febbraio");
+    example!(v, check_moment!(c, [2013, 2, 19]), "martedì", "martedì prossimo");
+    example!(v, check_moment!(c, [2013, 2, 13]), "mercoledì", "mer.");
+    example!(v, check_moment!(c, [2013, 2, 14]), "giovedì");
+    example!(v, check_moment!(c, [2013, 2, 15]), "venerdì");
+    example!(v, check_moment!(c, [2013, 2, 16]), "sabato");
+    example!(v, check_moment!(c, [2013, 2, 17]), "domenica");
+    example!(v, check_moment!(c, [2013, 2, 10]), "domenica scorsa");
+    example!(v, check_moment!(c, [2013, 5, 5]), "5 maggio", "cinque maggio", "il 5 maggio");
+    example!(v, check_moment!(c, [2013, 5, 5]), "5 maggio 2013", "5/5/2013");
+    example!(v, check_moment!(c, [2013, 7, 4]), "4 luglio", "4/7");
+    example!(v, check_moment!(c, [2013, 3, 1]), "1 marzo", "primo marzo", "il primo marzo", "1/3/2013", "1.3.2013");
+    example!(v, check_moment!(c, [2013, 12, 24]), "24 dic.", "24 dicembre", "la vigilia di natale");
+    example!(v, check_moment!(c, [2013, 2, 20]), "il 20", "20 febbraio", "20/2");
+    example!(v, check_moment!(c, [1974, 10, 31]), "31/10/1974", "31/10/74");
+    example!(v, check_moment!(c, [2014, 9, 12]), "venerdì 12 settembre 2014");
+    // Cycles
+    example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "questa settimana");
+    example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "la settimana scorsa", "la settimana passata");
+    example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "la settimana prossima", "la prossima settimana");
+    example!(v, check_moment!(c, [2013, 1]), "il mese scorso");
+    example!(v, check_moment!(c, [2013, 3]), "il mese prossimo", "il prossimo mese");
+    example!(v, check_moment!(c, [2012]), "l'anno scorso");
+    example!(v, check_moment!(c, [2013]), "quest'anno", "questo anno");
+    example!(v, check_moment!(c, [2014]), "l'anno prossimo");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "secondo trimestre");
+    // Hours
+    example!(v, check_moment!(c, [2013, 2, 12, 15]), "alle tre del pomeriggio", "alle 15", "alle 15 in punto");
+    example!(v, check_moment!(c, [2013, 2, 12, 20]), "alle otto di sera");
+    example!(v, check_moment!(c, [2013, 2, 13, 3]), "alle tre di mattina");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0]), "15:00", "15.00");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0, 10]), "15:00:10");
+    example!(v, check_moment!(c, [2013, 2, 13, 0]), "mezzanotte");
+    example!(v, check_moment!(c, [2013, 2, 12, 12]), "mezzogiorno", "a mezzogiorno");
+    example!(v, check_moment!(c, [2013, 2, 12, 12, 30]), "mezzogiorno e mezzo", "mezzogiorno e mezza");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 15]), "alle tre e un quarto", "alle tre e quindici del pomeriggio", "15:15");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "alle tre e mezza", "15:30");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 45]), "alle tre meno un quarto", "alle 15 meno 15", "14:45");
+    example!(v, check_moment!(c, [2013, 2, 13, 11]), "domani alle undici", "mercoledì alle 11 di mattina");
+    // Durations from now
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 31, 0]), "tra un minuto", "fra 1 minuto");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "tra un ora", "fra un ora");
+    example!(v, check_moment!(c, [2013, 2, 12, 2, 30]), "due ore fa");
+    example!(v, check_moment!(c, [2013, 2, 19]), "tra 7 giorni", "fra una settimana");
+    example!(v, check_moment!(c, [2013, 1, 22]), "tre settimane fa");
+    example!(v, check_moment!(c, [2013, 4]), "tra due mesi");
+    example!(v, check_moment!(c, [2011, 2]), "due anni fa");
+    // Holidays and seasons
+    example!(v, check_moment!(c, [2013, 12, 25]), "natale", "a natale");
+    example!(v, check_moment!(c, [2013, 12, 31]), "san silvestro");
+    example!(v, check_moment!(c, [2014, 1, 1]), "capodanno");
+    example!(v, check_moment!(c, [2013, 8, 15]), "ferragosto");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "quest'estate", "questa estate");
+    // Part of day
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "stasera", "questa sera", "oggi di sera");
+    example!(v, check_moment_span!(c, [2013, 2, 13, 4], [2013, 2, 13, 12]), "domani mattina", "domani di mattina");
+    example!(v, check_moment_span!(c, [2013, 2, 11, 12], [2013, 2, 11, 19]), "ieri pomeriggio");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "questo fine settimana", "questo weekend");
+    // Intervals
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "i prossimi 3 secondi");
+    example!(v, check_moment_span!(c, [2013, 2, 10], [2013, 2, 12]), "gli ultimi 2 giorni", "ultimi due giorni");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "i prossimi 3 giorni", "prossimi tre giorni");
+    example!(v, check_moment_span!(c, [2013, 7, 13], [2013, 7, 16]), "dal 13 al 15 luglio", "13-15 luglio");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9, 30], [2013, 2, 12, 11]), "9:30 - 11:00", "dalle 9:30 alle 11:00", "tra le 9:30 e le 11:00");
+}
+
+pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(0), "0", "zero");
+    example!(v, check_integer(1), "1", "uno", "una", "un");
+    example!(v, check_integer(3), "tre");
+    example!(v, check_integer(11), "undici");
+    example!(v, check_integer(13), "tredici");
+    example!(v, check_integer(14), "quattordici");
+    example!(v, check_integer(17), "diciassette");
+    example!(v, check_integer(21), "ventuno");
+    example!(v, check_integer(23), "ventitré", "ventitre");
+    example!(v, check_integer(28), "ventotto");
+    example!(v, check_integer(33), "33", "trentatré");
+    example!(v, check_integer(50), "cinquanta");
+    example!(v, check_integer(99), "novantanove");
+    example!(v, check_integer(100), "cento", "100");
+    example!(v, check_integer(123), "centoventitré");
+    example!(v, check_integer(300), "trecento");
+    example!(v, check_integer(1000), "mille", "1.000");
+    example!(v, check_integer(1980), "millenovecentottanta");
+    example!(v, check_integer(2020), "duemilaventi");
+    example!(v, check_integer(35000), "trentacinquemila");
+    example!(v, check_integer(200000), "duecentomila", "200.000", "200k");
+    example!(v, check_integer(3000000), "tre milioni", "3M", "3.000.000");
+    example!(v, check_integer(1000000), "un milione");
+    example!(v, check_integer(-1200000), "- 1.200.000", "-1200000", "meno 1.200.000", "-1,2M");
+    example!(v, check_float(1.5), "1,5", "uno virgola cinque");
+    example!(v, check_float(0.77), "0,77", ",77");
+    example!(v, check_fraction(2, 3), "due terzi", "2/3");
+    example!(v, check_fraction(3, 2), "uno e mezzo", "1 1/2");
+    example!(v, check_ordinal(1), "primo", "prima", "1º");
+    example!(v, check_ordinal(3), "terzo", "terza", "3ª");
+    example!(v, check_ordinal(7), "settimo", "settima");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 gradi", "trenta gradi", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "meno cinque gradi", "-5°", "5 gradi sotto zero");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Celsius)), "25°C", "25 gradi celsius", "venticinque gradi centigradi");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "settanta gradi fahrenheit");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "tra 18 e 22 gradi", "da 18 a 22 gradi", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "più di 30 gradi", "30 gradi o più");
+    example!(v, check_temperature_interval(None, Some(0.0), None), "sotto zero");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("mario.rossi@example.it"), "mario.rossi@example.it");
+    example!(v, check_url("https://www.example.it/path?q=1", "example.it"), "https://www.example.it/path?q=1");
+}
@@ -2,6 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
 
 pub trait JapaneseReplace {
@@ -75,6 +76,43 @@ impl<'a> JapaneseReplace for &'a str {
   }
 }
 
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("<number>から<number>まで",
+             number_check!(),
+             b.reg(r#"から"#)?,
+             number_check!(),
+             b.reg(r#"まで"#)?,
+             |a, _, b, _| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number>〜<number>",
+             number_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("<number>以上",
+             number_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number>超",
+             number_check!(),
+             b.reg(r#"超|を超える|より多い"#)?,
+             |a, _| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("<number>以下",
+             number_check!(),
+             b.reg(r#"以下|まで"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number>未満",
+             number_check!(),
+             b.reg(r#"未満|より少ない"#)?,
+             |a, _| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("intersect",
@@ -221,6 +259,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             |integer, _| Ok(OrdinalValue::new(integer.value().value))
     );
 
+    b.rule_3("fraction",
+            number_check!(|number: &NumberValue| !number.prefixed()),
+            b.reg(r#"分の"#)?,
+            number_check!(|number: &NumberValue| !number.suffixed()),
+            |a, _, b| helpers::fraction(b.value(), a.value())
+    );
+
     b.rule_1("float number", 
         b.reg(r#"(\d*[、,，\.]\d+)"#)?, |text_match| {
           let res = text_match.group(1).replace_japanese_digit().replace_comma();
@@ -298,95 +343,15 @@ pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()>
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect <money> (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value()));
     b.rule_2("intersect <money>",
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit != Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
              number_check!(),
              |a, b| helpers::compose_money_number(&a.value(), &b.value()));
-    b.rule_1_terminal("USD",
-        b.reg(r#"アメリカドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("$",
-        b.reg(r#"ドル|\$|＄"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("EUR",
-        b.reg(r#"ユーロ|€"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-        b.reg(r#"ポンド|£"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("GBP",
-        b.reg(r#"GBP|イギリスポンド"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("JPY",
-        b.reg(r#"JPY|円"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("JPY") })
-    );
-    b.rule_1_terminal("CNY",
-        b.reg(r#"CNY|(?:人民)?元"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CNY") })
-    );
-    b.rule_1_terminal("¥",
-        b.reg(r#"¥"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("¥") })
-    );
-    b.rule_1_terminal("Krones (Swedish, Danish, ...)",
-        b.reg(r#"KR|クローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KR") })
-    );
-    b.rule_1_terminal("Danish Krones",
-        b.reg(r#"DKK|デンマーククローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("DKK") })
-    );
-    b.rule_1_terminal("Swedish Krones",
-        b.reg(r#"SEK|スウェーデンクローナ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("SEK") })
-    );
-    b.rule_1_terminal("Norwegian Krones",
-        b.reg(r#"NOK|ノルウェークローネ"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("NOK") })
-    );
-    b.rule_1_terminal("Swiss Francs",
-        b.reg(r#"CHF|スイスフラン"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CHF") })
-    );
-    b.rule_1_terminal("KRW",
-        b.reg(r#"ウォン|₩"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("KRW") })
-    );
-    b.rule_1_terminal("INR",
-        b.reg(r#"ルピー"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("INR") })
-    );
-    b.rule_1_terminal("RUB",
-        b.reg(r#"ルーブル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("RUB") })
-    );
-    b.rule_1_terminal("AUD",
-        b.reg(r#"オーストラリアドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("AUD") })
-    );
-    b.rule_1_terminal("HKD",
-        b.reg(r#"香港ドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("HKD") })
-    );
-    b.rule_1_terminal("CAD",
-        b.reg(r#"カナダドル"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("CAD") })
-    );
-    b.rule_1_terminal("Bitcoin",
-        b.reg(r#"ビットコイン|฿"#)?,
-        |_| Ok(MoneyUnitValue { unit: Some("฿") })
-    );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"銭"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::JA))?,
+                      |text_match| helpers::money_unit(names::JA, text_match.group(0))
     );
     b.rule_2("<amount> <unit>",
              number_check!(),
@@ -426,6 +391,42 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_4("<amount-of-money>から<amount-of-money>まで",
+             amount_of_money_check!(),
+             b.reg(r#"から"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"まで"#)?,
+             |a, _, b, _| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("<number>から<amount-of-money>まで",
+             number_check!(),
+             b.reg(r#"から"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"まで"#)?,
+             |a, _, b, _| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money>〜<amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>〜<amount-of-money>",
+             number_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("<amount-of-money>以下",
+             amount_of_money_check!(),
+             b.reg(r#"以下|未満|まで|以内"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money>以上",
+             amount_of_money_check!(),
+             b.reg(r#"以上|超|から"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -436,8 +437,9 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Celsius",
@@ -446,8 +448,9 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
     b.rule_2("<temp> Fahrenheit",
@@ -456,8 +459,9 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
 
@@ -468,8 +472,9 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
             |_, a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
 
@@ -480,14 +485,15 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
         |_, a, _| {
             Ok(TemperatureValue {
                      value: a.value().value(),
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
         }
     );
     b.rule_2("<latent temp> below zero",
-             b.reg(r#"マイナス|零下"#)?,
-             temperature_check!(),
+             b.reg(r#"マイナス|零下|氷点下|-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              |_, a| {
                  Ok(TemperatureValue {
                      value: -1.0 * a.value().value,
@@ -495,13 +501,46 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      ..*a.value()
                  })
              });
+    b.rule_4("<temp>から<temp>まで",
+             temperature_check!(),
+             b.reg(r#"から"#)?,
+             temperature_check!(),
+             b.reg(r#"まで"#)?,
+             |a, _, b, _| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp>〜<temp>",
+             temperature_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>〜<temp>",
+             number_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(&TemperatureValue {
+                 value: a.value().value(),
+                 latent: true,
+                 ..TemperatureValue::default()
+             }, b.value())
+    );
+    b.rule_2("<temp>以下",
+             temperature_check!(),
+             b.reg(r#"以下|未満|まで"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp>以上",
+             temperature_check!(),
+             b.reg(r#"以上|超|から"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
 pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect <time>",
              time_check!(|time: &TimeValue| !time.latent && excluding_form!(Form::PartOfDay(_))(time)),
-             time_check!(|time: &TimeValue| (!time.latent || form!(Form::Meal)(time)) && excluding_form!(Form::PartOfDay(_))(time)),
+             time_check!(|time: &TimeValue| (!time.latent || form!(Form::Meal(_))(time)) && excluding_form!(Form::PartOfDay(_))(time)),
              |a, b| a.value().intersect(b.value())
     );
     b.rule_3("intersect <time>",
@@ -567,24 +606,24 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("new year's day",
                       b.reg(r#"元(?:日|旦)"#)?,
-                      |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(1, 1)?.form(Form::Celebration("new_years_day")))
     );
     b.rule_1_terminal("coming of age day",
                       b.reg(r#"成人(?:式|の(?:日の)?)"#)?,
                       |_| {
                             let monday_january = helpers::month(1)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
                             let second_week_of_january = helpers::cycle_nth_after(Grain::Week, 1, &helpers::month_day(1, 1)?)?;
-                            Ok(monday_january.intersect(&second_week_of_january)?.form(Form::Celebration)) // second monday of january
+                            Ok(monday_january.intersect(&second_week_of_january)?.form(Form::Celebration("coming_of_age_day"))) // second monday of january
                       }
     );
     b.rule_2("<celebration> に?",
-        time_check!(form!(Form::Celebration)),
+        time_check!(form!(Form::Celebration(_))),
         b.reg(r"に")?,
         |time, _| Ok(time.value().clone())
     );
     b.rule_1_terminal("setsubun",
                       b.reg(r#"節分の日"#)?,
-                      |_| Ok(helpers::month_day(2, 3)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(2, 3)?.form(Form::Celebration("setsubun")))
     );
     b.rule_1_terminal("vernal equinox day",
                       b.reg(r#"春分の日"#)?,
@@ -592,58 +631,58 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("national foundation day",
                       b.reg(r#"建国記念の?日"#)?,
-                      |_| Ok(helpers::month_day(2, 11)?.form(Form::Celebration))
+                      |_| Ok(helpers::month_day(2, 11)?.form(Form::Celebration("national_foundation_day")))
     );
     b.rule_1_terminal("the emperor's birthday",
                       b.reg(r#"天皇誕生日"#)?,
-                      |_| Ok(helpers::month_day(12, 23)?.form(Form::Celebration)) // To be deleted for 2019 as there will be no emperor's birthday in 2019 and a new date for 2020 as there will be a new emperor
+                      |_| Ok(helpers::month_day(12, 23)?.form(Form::Celebration("emperors_birthday"))) // To be deleted for 2019 as there will be no emperor's birthday in 2019 and a new date for 2020 as there will be a new emperor
     );
     b.rule_1_terminal("the emperor's birthday",
                       b.reg(r#"天皇誕生日"#)?,
-                      |_| Ok(helpers::month_day(2, 23)?.form(Form::Celebration)) // New date from 2020 to be uncomment for 2020
+                      |_| Ok(helpers::month_day(2, 23)?.form(Form::Celebration("emperors_birthday"))) // New date from 2020 to be uncomment for 2020
     );
     b.rule_1_terminal("girls day",
                       b.reg(r#"ひな(?:まつり|祭り)(?:の日)?"#)?,
-                      |_| Ok(helpers::month_day(3, 3)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(3, 3)?.form(Form::Celebration("girls_day"))) 
     );
     b.rule_1_terminal("womens day",
                       b.reg(r#"女性(?:の日)?"#)?,
-                      |_| Ok(helpers::month_day(3, 8)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(3, 8)?.form(Form::Celebration("womens_day"))) 
     );
     b.rule_1_terminal("showa day",
                       b.reg(r#"昭和の日"#)?,
-                      |_| Ok(helpers::month_day(4, 29)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(4, 29)?.form(Form::Celebration("showa_day"))) 
     );
     b.rule_1_terminal("constitution memorial day",
                       b.reg(r#"憲法記念日"#)?,
-                      |_| Ok(helpers::month_day(5, 3)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(5, 3)?.form(Form::Celebration("constitution_memorial_day"))) 
     );
     b.rule_1_terminal("greenery day",
                       b.reg(r#"みどりの日"#)?,
-                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(5, 4)?.form(Form::Celebration("greenery_day"))) 
     );
     b.rule_1_terminal("children's day",
                       b.reg(r#"(?:こども|子供)の日"#)?,
-                      |_| Ok(helpers::month_day(5, 5)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(5, 5)?.form(Form::Celebration("childrens_day"))) 
     );
     b.rule_1_terminal("marine day",
                       b.reg(r#"海の日"#)?,
                       |_| {
                             let monday_july = helpers::month(7)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
                             let third_week_of_july = helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(7, 1)?)?;
-                            Ok(monday_july.intersect(&third_week_of_july)?.form(Form::Celebration))  // third monday of july
+                            Ok(monday_july.intersect(&third_week_of_july)?.form(Form::Celebration("marine_day")))  // third monday of july
                       }
     );
     b.rule_1_terminal("mountain day",
                       b.reg(r#"山の日"#)?,
-                      |_| Ok(helpers::month_day(8, 11)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(8, 11)?.form(Form::Celebration("mountain_day"))) 
     );
     b.rule_1_terminal("respect for the aged day",
                       b.reg(r#"敬老の日"#)?,
                       |_| {
                             let monday_september = helpers::month(9)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
                             let third_week_of_september = helpers::cycle_nth_after(Grain::Week, 3, &helpers::month_day(9, 1)?)?;
-                            Ok(monday_september.intersect(&third_week_of_september)?.form(Form::Celebration))  // third monday of september
+                            Ok(monday_september.intersect(&third_week_of_september)?.form(Form::Celebration("respect_for_the_aged_day")))  // third monday of september
                       }
     );
     b.rule_1_terminal("autumnal equinox day",
@@ -652,35 +691,35 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("culture day",
                       b.reg(r#"文化の日"#)?,
-                      |_| Ok(helpers::month_day(11, 3)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(11, 3)?.form(Form::Celebration("culture_day"))) 
     );
     b.rule_1_terminal("health and sports day",
                       b.reg(r#"体育の日"#)?,
                       |_| {
                             let monday_october = helpers::month(10)?.intersect(&helpers::day_of_week(Weekday::Mon)?)?;
                             let second_week_of_october = helpers::cycle_nth_after(Grain::Week, 2, &helpers::month_day(10, 1)?)?;
-                            Ok(monday_october.intersect(&second_week_of_october)?.form(Form::Celebration))  // second monday of october
+                            Ok(monday_october.intersect(&second_week_of_october)?.form(Form::Celebration("health_and_sports_day")))  // second monday of october
                       }
     );
     b.rule_1_terminal("labor thanksgiving day",
                       b.reg(r#"勤労感謝の日"#)?,
-                      |_| Ok(helpers::month_day(11, 23)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(11, 23)?.form(Form::Celebration("labour_thanksgiving_day"))) 
     );
     b.rule_1_terminal("christmas eve",
                       b.reg(r#"クリスマスイブ(?:の?日に?)?"#)?,
-                      |_| Ok(helpers::month_day(12, 24)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(12, 24)?.form(Form::Celebration("christmas_eve"))) 
     );
     b.rule_1_terminal("christmas",
                       b.reg(r#"クリスマス(?:の?日に?)?"#)?,
-                      |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(12, 25)?.form(Form::Celebration("christmas"))) 
     );
     b.rule_1_terminal("halloween",
                       b.reg(r#"ハロウィン(?:の?日に?)?"#)?,
-                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(10, 31)?.form(Form::Celebration("halloween"))) 
     );
     b.rule_1_terminal("valentines's day",
                       b.reg(r#"バレンタインデー(?:の?日に?)?"#)?,
-                      |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration)) 
+                      |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration("valentines_day"))) 
     );
     b.rule_1_terminal("now",
                       b.reg(r#"今(?:すぐに?)?|現在|只今|(?:ただ)?いま"#)?,
@@ -1029,7 +1068,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("next <time>",
              b.reg(r#"次の?|翌|来"#)?,
              time_check!(|time: &TimeValue| 
-                excluding_form!(Form::Celebration)(time) &&  
+                excluding_form!(Form::Celebration(_))(time) &&  
                 excluding_form!(Form::Month(_))(time) && 
                 excluding_form!(Form::Year(_))(time) &&  
                 excluding_form!(Form::DayOfWeek{..})(time)),
@@ -1039,7 +1078,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_2("next <celebration>",
              b.reg(r#"次の?|翌"#)?,
-             time_check!(form!(Form::Celebration)),
+             time_check!(form!(Form::Celebration(_))),
              |_, a| {
                  a.value().the_nth(0)
              }
@@ -1391,18 +1430,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("morning",
         b.reg(r#"朝の?|午前中?|今朝"#)?,
         |_| {
-            Ok(helpers::hour(4, false)?
-                .span_to(&helpers::hour(12, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Morning)))
+            Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
         }
     );
     b.rule_1_terminal("breakfast",
         b.reg(r#"朝(?:食|ごはん|ご飯)"#)?,
-        |_| Ok(helpers::hour(6, false)?
-                .span_to(&helpers::hour(9, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Breakfast, 6, 9)?.latent())
     );
     b.rule_1_terminal("early morning",
         b.reg(r#"明け方|早朝|朝早く"#)?,
@@ -1441,45 +1474,30 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("evening",
         b.reg(r#"夕方"#)?,
         |_| {
-            Ok(helpers::hour(16, false)?
-                .span_to(&helpers::hour(19, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Evening)))
+            Ok(helpers::part_of_day(PartOfDayForm::Evening, 16, 19)?.latent())
         }
     );
 
     b.rule_1_terminal("night",
         b.reg(r#"夜中?|晩|晚"#)?,
         |_| {
-            Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(3, false)?, false)?
-                .latent()
-                .form(Form::PartOfDay(PartOfDayForm::Night)))
+            Ok(helpers::part_of_day(PartOfDayForm::Night, 18, 3)?.latent())
         }
     );
     b.rule_1_terminal("brunch",
         b.reg(r#"ブランチ"#)?,
-        |_| Ok(helpers::hour(10, false)?
-                .span_to(&helpers::hour(15, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Brunch, 10, 15)?.latent())
     );
     b.rule_1_terminal("lunch",
         b.reg(r#"昼食|ランチ|お昼ご飯|昼ごはん"#)?,
         |_| {
-            Ok(helpers::hour(12, false)?
-                .span_to(&helpers::hour(14, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+            Ok(helpers::meal(MealForm::Lunch, 12, 14)?.latent())
         }
     );
 
     b.rule_1_terminal("dinner",
         b.reg(r#"夕(?:食|ご飯|ごはん)|晩(?:御飯|ご(?:飯|はん))"#)?,
-        |_| Ok(helpers::hour(18, false)?
-                .span_to(&helpers::hour(23, false)?, false)?
-                .latent()
-                .form(Form::Meal))
+        |_| Ok(helpers::meal(MealForm::Dinner, 18, 23)?.latent())
     );
 
     b.rule_1_terminal("second dinner",
@@ -1487,35 +1505,33 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |_| Ok(helpers::hour(22, false)?
                 .span_to(&helpers::hour(2, false)?, false)?
                 .latent()
-                .form(Form::Meal))
+                .form(Form::Meal(MealForm::Snack)))
     );
 
     b.rule_1_terminal("snack",
         b.reg(r#"おやつ"#)?,
-        |_| Ok(helpers::hour(15, false)?
-                .span_to(&helpers::hour(17, false)?, false)?
-                .form(Form::Meal))
+        |_| helpers::meal(MealForm::Snack, 15, 17)
     );
     b.rule_2("at <meal>",
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         b.reg(r#"の?時間?に?|の途中に?|中に?"#)?,
         |a, _| Ok(a.value().clone().not_latent())
     );
     b.rule_2("around <meal>",
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         b.reg(r#"(?:ぐ|く)らいに"#)?,
         |a, _| Ok(a.value().clone().not_latent().precision(Approximate))
     );
     b.rule_3("around <meal>",
         b.reg(r#"だいたい"#)?,
-        time_check!(form!(Form::Meal)),
+        time_check!(form!(Form::Meal(_))),
         b.reg(r#"の時間に"#)?,
         |_, a, _| Ok(a.value().clone().not_latent().precision(Approximate))
     );
     b.rule_1_terminal("tonight",
         b.reg(r#"今夜"#)?,
         |_| {
-            let period = helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?;
+            let period = helpers::part_of_day(PartOfDayForm::Night, 18, 0)?;
             Ok(helpers::cycle_nth(Grain::Day, 0)?
                 .intersect(&period)?
                 .form(Form::PartOfDay(PartOfDayForm::Night)))
@@ -1552,12 +1568,12 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_3("<time> <part-of-day>",
              time_check!(|time: &TimeValue| !time.has_direction()),
              b.reg(r#"の"#)?,
-             time_check!(|time: &TimeValue| excluding_form!(Form::PartOfDay(PartOfDayForm::Night))(time) && (form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time))),
+             time_check!(|time: &TimeValue| excluding_form!(Form::PartOfDay(PartOfDayForm::Night))(time) && (form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time))),
              |time, _, part_of_day| part_of_day.value().intersect(time.value())
     );
     b.rule_2("<time> <part-of-day>",
              time_check!(),
-             time_check!(|time: &TimeValue| excluding_form!(Form::PartOfDay(PartOfDayForm::Night))(time) && (form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time))),
+             time_check!(|time: &TimeValue| excluding_form!(Form::PartOfDay(PartOfDayForm::Night))(time) && (form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time))),
              |time, part_of_day| part_of_day.value().intersect(time.value())
     );
     b.rule_3("<time> night",
@@ -1714,23 +1730,11 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("week-end",
                       b.reg(r#"週末"#)?,
-                      |_| {
-                          let saturday = helpers::day_of_week(Weekday::Sat)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          saturday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend(false)
     );
     b.rule_1_terminal("this week-end",
                       b.reg(r#"今週末"#)?,
-                      |_| {
-                          let saturday = helpers::day_of_week(Weekday::Sat)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          saturday.the_nth(0)?.span_to(&monday.the_nth(0)?, false)
-                      }
+                      |_| helpers::weekend(false)?.the_nth(0)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"夏"#)?,
@@ -1978,6 +1982,32 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("日本時間",
+                      b.reg(r#"日本時間(?:の|で)?|jst"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_1_terminal("協定世界時",
+                      b.reg(r#"協定世界時(?:の|で)?|グリニッジ標準時(?:の|で)?"#)?,
+                      |_| helpers::timezone("utc")
+    );
+    b.rule_2("<timezone> <time>",
+             timezone_check!(),
+             time_check!(|time: &TimeValue| !time.latent),
+             |timezone, time| time.value().in_timezone(timezone.value())
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (unit-of-duration)",
                       b.reg(r#"秒間?"#)?,
@@ -2024,6 +2054,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_3("<integer> more <unit-of-duration>",
              b.reg(r#"もう|後|あと"#)?,
              integer_check_by_range!(0),
@@ -2124,7 +2159,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_4("<duration> after <time>",
-             time_check!(form!(Form::Celebration)),
+             time_check!(form!(Form::Celebration(_))),
              b.reg(r#"の"#)?,
              duration_check!(),
              b.reg(r#"後に?"#)?,
@@ -2132,7 +2167,7 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_4("<duration> after <time>",
-             time_check!(excluding_form!(Form::Celebration)),
+             time_check!(excluding_form!(Form::Celebration(_))),
              b.reg(r#"から"#)?,
              duration_check!(),
              b.reg(r#"後に?"#)?,
@@ -2140,14 +2175,14 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_4("<duration> before <time>",
-             time_check!(form!(Form::Celebration)),
+             time_check!(form!(Form::Celebration(_))),
              b.reg(r#"の"#)?,
              duration_check!(),
              b.reg(r#"前に?"#)?,
              |time, _, duration, _| duration.value().before(time.value())
     );
     b.rule_4("<duration> before <time>",
-             time_check!(excluding_form!(Form::Celebration)),
+             time_check!(excluding_form!(Form::Celebration(_))),
              b.reg(r#"から"#)?,
              duration_check!(),
              b.reg(r#"前に?"#)?,
@@ -2203,6 +2238,99 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
 
+    b.rule_3("<duration>〜<duration>",
+             duration_check!(),
+             b.reg(r#"〜|~|-|から"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>〜<duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"〜|~|-|から"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("<duration>以上",
+             duration_check!(),
+             b.reg(r#"以上|超"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration>以下",
+             duration_check!(),
+             b.reg(r#"以下|未満|以内"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("毎時",
+                      b.reg(r#"毎時"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("毎日",
+                      b.reg(r#"毎日"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("隔日",
+                      b.reg(r#"隔日"#)?,
+                      |_| RecurrenceValue::new(Grain::Day).every(2)
+    );
+    b.rule_1_terminal("毎週",
+                      b.reg(r#"毎週"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("隔週",
+                      b.reg(r#"隔週"#)?,
+                      |_| RecurrenceValue::new(Grain::Week).every(2)
+    );
+    b.rule_1_terminal("毎月",
+                      b.reg(r#"毎月"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("毎年",
+                      b.reg(r#"毎年"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_3("<integer><cycle>ごと",
+             integer_check_by_range!(2),
+             cycle_check!(),
+             b.reg(r#"(?:間)?(?:ごと|毎)"#)?,
+             |n, a, _| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_2("毎週<day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.frequency == Grain::Week && recurrence.by_hour.is_none()),
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_2("毎<day-of-week>",
+             b.reg(r#"毎"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("<recurrence>と<day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"と|、"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence><time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<time>から<recurrence>",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"から"#)?,
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             |a, _, b| b.value().starting(a.value())
+    );
+    b.rule_3("<time>まで<recurrence>",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"まで"#)?,
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             |a, _, b| b.value().until(a.value())
+    );
     Ok(())
 }
 
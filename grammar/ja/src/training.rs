@@ -26,15 +26,27 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(7), "七番目");
     example!(v, check_ordinal(11), "十一番目");
     example!(v, check_ordinal(91), "九十一番目");
+    example!(v, check_fraction(2, 3), "三分の二");
+}
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "3から5まで", "3〜5", "三から五まで");
+    example!(v, check_number_interval(Some(3.0), None), "3以上", "三以上");
+    example!(v, check_number_interval(None, Some(10.0)), "10以下", "十以下");
+    example!(v, check_number_interval(None, Some(4.0)), "4未満");
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(5.0, Some("degree")), "五度");
-    example!(v, check_temperature(5.0, Some("celsius")), "摂氏五度");
-    example!(v, check_temperature(5.0, Some("fahrenheit")), "華氏五度");
-    example!(v, check_temperature(25.0, Some("degree")), "二十五度");
-    example!(v, check_temperature(-10.0, Some("degree")), "マイナス十度");
-    example!(v, check_temperature(-10.0, Some("degree")), "零下十度");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Degree)), "五度");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Celsius)), "摂氏五度");
+    example!(v, check_temperature(5.0, Some(TemperatureUnit::Fahrenheit)), "華氏五度");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Degree)), "二十五度");
+    example!(v, check_temperature(-10.0, Some(TemperatureUnit::Degree)), "マイナス十度");
+    example!(v, check_temperature(-10.0, Some(TemperatureUnit::Degree)), "零下十度");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5℃", "氷点下5℃");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "十八度から二十二度まで", "18〜22度");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "三十度以上");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Degree)), "十度以下");
 }
 
 pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -135,4 +147,20 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 6]), "一日から五日", "一日から五日まで");
     example!(v, check_moment_span!(c, [2013, 2, 28], [2013, 3, 4]), "二十八日から三日", "二十八日から三日まで");
     example!(v, check_moment!(c, [2013, 2, 10, 14, 57]), "一昨日の午後三時三分前");
-}
\ No newline at end of file
+    example!(v, check_timezone("Asia/Tokyo"), "日本時間の15時", "日本時間15時");
+    example!(v, check_timezone("UTC"), "協定世界時の9時", "9時UTC");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::hours(2).into()), Some(PeriodComp::hours(3).into())), "2〜3時間", "二時間から三時間");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "二十分以上");
+    example!(v, check_duration_interval(None, Some(PeriodComp::days(3).into())), "三日以内");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Day, 1, vec![], None), "毎日");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "毎日8時");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "毎週月曜日", "毎月曜日", "毎週月曜");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "隔週", "2週間ごと");
+    example!(v, check_recurrence(Grain::Week, 2, vec![Weekday::Fri], None), "隔週金曜日");
+}
@@ -2,58 +2,19 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
 use regex::Regex;
 use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
 
 pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect (X cents)",
              amount_of_money_check!(),
-             amount_of_money_check!(|money: &AmountOfMoneyValue| money.unit == Some("cent")),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
              |a, b| helpers::compose_money(a.value(), b.value())
     );
-    b.rule_1_terminal("KRW",
-                      b.reg(r#"₩|원|krw"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("KRW") })
-    );
-    b.rule_1_terminal("$",
-                      b.reg(r#"\$|달러|불"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("$") })
-    );
-    b.rule_1_terminal("cent",
-                      b.reg(r#"cents?|센[트|츠]|c|¢"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("cent") })
-    );
-    b.rule_1_terminal("EUR",
-                      b.reg(r#"€|유로|euro?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("EUR") })
-    );
-    b.rule_1_terminal("£",
-                      b.reg(r#"£|파운드|영국파운드"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("£") })
-    );
-    b.rule_1_terminal("GBP",
-                      b.reg(r#"gbp"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("GBP") })
-    );
-    b.rule_1_terminal("AUD",
-                      b.reg(r#"aud|호주달러"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AUD") })
-    );
-    b.rule_1_terminal("USD",
-                      b.reg(r#"us[d\$]"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("USD") })
-    );
-    b.rule_1_terminal("PTS",
-                      b.reg(r#"pta?s?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("PTS") })
-    );
-    b.rule_1_terminal("INR",
-                      b.reg(r#"inr|rs(?:. )?|(?:R|r)upees?|루피|인도루피"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("INR") })
-    );
-    b.rule_1_terminal("AED", //  Emirates Currency
-                      b.reg(r#"디르함|aed|dirhams?"#)?,
-                      |_| Ok(MoneyUnitValue { unit: Some("AED") })
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::KO))?,
+                      |text_match| helpers::money_unit(names::KO, text_match.group(0))
     );
     b.rule_2("<unit> <amount>",
              money_unit!(),
@@ -101,6 +62,42 @@ pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..a.value().clone()
                  })
              });
+    b.rule_4("<amount-of-money>에서 <amount-of-money>까지",
+             amount_of_money_check!(),
+             b.reg(r#"에서|부터"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"까지"#)?,
+             |a, _, b, _| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("<number>에서 <amount-of-money>까지",
+             number_check!(),
+             b.reg(r#"에서|부터"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"까지"#)?,
+             |a, _, b, _| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money>~<amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"~|-|에서"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>~<amount-of-money>",
+             number_check!(),
+             b.reg(r#"~|-|에서"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("<amount-of-money> 이하",
+             amount_of_money_check!(),
+             b.reg(r#"이하|미만|까지|이내"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> 이상",
+             amount_of_money_check!(),
+             b.reg(r#"이상|초과|부터"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -111,17 +108,19 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  value: a.value().value(),
                  unit: None,
                  latent: true,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("below <temp>",
              b.reg(r#"영하"#)?,
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              |_, temp| {
                  if temp.value().value >= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -130,13 +129,14 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     );
     b.rule_2("above <temp>",
              b.reg(r#"영상"#)?,
-             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             temperature_check!(|temp: &TemperatureValue| !temp.latent && !temp.is_interval()),
              |_, temp| {
                  if temp.value().value <= 0.0 {
                      Ok(TemperatureValue {
                          value: -1.0 * temp.value().value,
                          unit: temp.value().unit,
                          latent: false,
+                         ..TemperatureValue::default()
                      })
                  } else {
                      Ok(temp.value().clone())
@@ -145,50 +145,78 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     );
 
     b.rule_2("<latent temp> degrees",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"도|°"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
-                 unit: Some("degree"),
+                 unit: Some(TemperatureUnit::Degree),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("섭씨 <temp> (celsius)",
              b.reg(r#"섭씨"#)?,
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              |_, a| Ok(TemperatureValue {
                  value: a.value().value,
-                 unit: Some("celsius"),
+                 unit: Some(TemperatureUnit::Celsius),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> °C",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"c"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
-                 unit: Some("celsius"),
+                 unit: Some(TemperatureUnit::Celsius),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("화씨 <temp>",
              b.reg(r#"화씨"#)?,
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              |_, a| Ok(TemperatureValue {
                  value: a.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
     b.rule_2("<temp> °F",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"f"#)?,
              |a, _| Ok(TemperatureValue {
                  value: a.value().value,
-                 unit: Some("fahrenheit"),
+                 unit: Some(TemperatureUnit::Fahrenheit),
                  latent: false,
+                 ..TemperatureValue::default()
              })
     );
+    b.rule_4("<temp>에서 <temp>까지",
+             temperature_check!(),
+             b.reg(r#"에서|부터"#)?,
+             temperature_check!(),
+             b.reg(r#"까지"#)?,
+             |a, _, b, _| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp>~<temp>",
+             temperature_check!(),
+             b.reg(r#"~|-|에서"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("<temp> 이하",
+             temperature_check!(),
+             b.reg(r#"이하|미만|까지"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> 이상",
+             temperature_check!(),
+             b.reg(r#"이상|초과|부터"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
     Ok(())
 }
 
@@ -769,16 +797,11 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("morning",
                       b.reg(r#"아침"#)?,
-                      |_| Ok(helpers::hour(4, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                      |_| helpers::part_of_day(PartOfDayForm::Morning, 4, 12)
     );
     b.rule_1_terminal("morning (latent)",
                       b.reg(r#"오전"#)?,
-                      |_| Ok(helpers::hour(4, false)?
-                          .span_to(&helpers::hour(12, false)?, false)?
-                          .latent()
-                          .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
     );
     b.rule_1_terminal("late morning (latent)",
                       b.reg(r#"늦은 아침|오전 늦게|아침 늦게|아침 느지막이"#)?,
@@ -796,9 +819,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("afternoon",
                       b.reg(r#"오후"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(19, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                      |_| helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)
     );
     b.rule_1_terminal("late afternoon (latent)",
                       b.reg(r#"늦은 오후|오후 늦게"#)?,
@@ -816,9 +837,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("evening",
                       b.reg(r#"저녁"#)?,
-                      |_| Ok(helpers::hour(18, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                      |_| helpers::part_of_day(PartOfDayForm::Evening, 18, 0)
     );
     b.rule_1_terminal("late evening (latent)",
                       b.reg(r#"늦은 저녁|저녁 늦게"#)?,
@@ -836,9 +855,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("night",
                       b.reg(r#"밤"#)?,
-                      |_| Ok(helpers::hour(19, false)?
-                          .span_to(&helpers::hour(0, false)?, false)?
-                          .form(Form::PartOfDay(PartOfDayForm::Night)))
+                      |_| helpers::part_of_day(PartOfDayForm::Night, 19, 0)
     );
     b.rule_1_terminal("late night (latent)",
                       b.reg(r#"늦은 밤|밤 늦게|깊은 밤"#)?,
@@ -849,53 +866,38 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("breakfast (latent)",
                       b.reg(r#"아침(?: ?(?:식사|밥))?|조반"#)?,
-                      |_| Ok(helpers::hour(6, false)?
-                          .span_to(&helpers::hour(9, false)?, false)?
-                          .latent()
-                          .form(Form::Meal))
+                      |_| Ok(helpers::meal(MealForm::Breakfast, 6, 9)?.latent())
     );
     b.rule_1_terminal("brunch (latent)",
                       b.reg(r#"브런취|브런치|아침 겸 점심|늦은 아침|아점"#)?,
-                      |_| Ok(helpers::hour(11, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
-                          .latent()
-                          .form(Form::Meal))
+                      |_| Ok(helpers::meal(MealForm::Brunch, 11, 14)?.latent())
     );
     b.rule_1_terminal("lunch (latent)",
                       b.reg(r#"점심(?: ?(?:식사|밥))?"#)?,
-                      |_| Ok(helpers::hour(12, false)?
-                          .span_to(&helpers::hour(14, false)?, false)?
-                          .latent()
-                          .form(Form::Meal))
+                      |_| Ok(helpers::meal(MealForm::Lunch, 12, 14)?.latent())
     );
     b.rule_1_terminal("dinner (latent)",
                       b.reg(r#"저녁(?: ?(?:식사|밥))?"#)?,
                       |_| Ok(helpers::hour_minute(17, 30, false)?
                           .span_to(&helpers::hour(21, false)?, false)?
                           .latent()
-                          .form(Form::Meal))
+                          .form(Form::Meal(MealForm::Dinner)))
     );
     b.rule_2("in|during the <part-of-day>",
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              b.reg(r#"에|동안|때"#)?,
              |time, _| Ok(time.value().clone().not_latent())
     );
 
     b.rule_2("<time> <part-of-day>",
              time_check!(),
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |time, pod| pod.value().intersect(time.value())
     );
 
     b.rule_1_terminal("week-end",
                       b.reg(r#"주말"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend(true)
     );
     b.rule_1_terminal("season",
                       b.reg(r#"여름"#)?,
@@ -1018,6 +1020,32 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt|kst)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("한국 시간",
+                      b.reg(r#"한국\s?시간(?:으로)?"#)?,
+                      |_| helpers::timezone("asia/seoul")
+    );
+    b.rule_1_terminal("일본 시간",
+                      b.reg(r#"일본\s?시간(?:으로)?"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_2("<timezone> <time>",
+             timezone_check!(),
+             time_check!(|time: &TimeValue| !time.latent),
+             |timezone, time| time.value().in_timezone(timezone.value())
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("second (unit-of-duration)",
                       b.reg(r#"초"#)?,
@@ -1068,6 +1096,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
     b.rule_2_terminal("number.number hours",
                       b.reg(r#"(\d+)\.(\d+)"#)?,
                       b.reg(r#"시간"#)?,
@@ -1135,6 +1168,99 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           Ok(DurationValue::new(PeriodComp::new(Grain::Day, number_of_days).into()))
                       }
     );
+    b.rule_3("<duration>~<duration>",
+             duration_check!(),
+             b.reg(r#"~|-|에서"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>~<duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"~|-|에서"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("<duration> 이상",
+             duration_check!(),
+             b.reg(r#"이상|초과"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> 이하",
+             duration_check!(),
+             b.reg(r#"이하|미만|이내"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("매시간",
+                      b.reg(r#"매\s?시간"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("매일",
+                      b.reg(r#"매일|날마다"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("격일",
+                      b.reg(r#"격일"#)?,
+                      |_| RecurrenceValue::new(Grain::Day).every(2)
+    );
+    b.rule_1_terminal("매주",
+                      b.reg(r#"매주"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("격주",
+                      b.reg(r#"격주"#)?,
+                      |_| RecurrenceValue::new(Grain::Week).every(2)
+    );
+    b.rule_1_terminal("매달",
+                      b.reg(r#"매달|매월"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("매년",
+                      b.reg(r#"매년|해마다"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_3("<integer><cycle>마다",
+             integer_check_by_range!(2),
+             cycle_check!(),
+             b.reg(r#"마다"#)?,
+             |n, a, _| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_2("매주 <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.frequency == Grain::Week && recurrence.by_hour.is_none()),
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_2("<day-of-week>마다",
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"마다"#)?,
+             |a, _| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("<recurrence> and <day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"과|와|,"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence> <time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_3("<time>부터 <recurrence>",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"부터"#)?,
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             |a, _, b| b.value().starting(a.value())
+    );
+    b.rule_3("<time>까지 <recurrence>",
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"까지"#)?,
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             |a, _, b| b.value().until(a.value())
+    );
     Ok(())
 }
 
@@ -1233,6 +1359,43 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 }
 
 
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("<number>에서 <number>까지",
+             number_check!(),
+             b.reg(r#"에서|부터"#)?,
+             number_check!(),
+             b.reg(r#"까지"#)?,
+             |a, _, b, _| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number>~<number>",
+             number_check!(),
+             b.reg(r#"~|-|에서"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("<number> 이상",
+             number_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number> 초과",
+             number_check!(),
+             b.reg(r#"초과|넘게"#)?,
+             |a, _| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("<number> 이하",
+             number_check!(),
+             b.reg(r#"이하|까지"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number> 미만",
+             number_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("intersect",
              number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
@@ -1507,6 +1670,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -1523,13 +1687,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              number_check!(|number: &NumberValue| !number.prefixed()),
              b.reg(r#"분(의|에)"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
-             |a, _, b| FloatValue::new(b.value().value() / a.value().value())
+             |a, _, b| helpers::fraction(b.value(), a.value())
     );
     b.rule_3("fraction",
              number_check!(|number: &NumberValue| !number.prefixed()),
              b.reg(r#"/"#)?,
              number_check!(|number: &NumberValue| !number.suffixed()),
-             |a, _, b| FloatValue::new(a.value().value() / b.value().value())
+             |a, _, b| helpers::fraction(a.value(), b.value())
     );
     Ok(())
 }
@@ -4,6 +4,9 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
 pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(1000.0), Some(2000.0), Some("KRW")), "1000원에서 2000원까지", "1000~2000원");
+    example!(v, check_finance_interval(Some(500.0), None, Some("KRW")), "500원 이상");
+    example!(v, check_finance_interval(None, Some(500.0), Some("KRW")), "500원 이하");
     example!(v, check_finance(500.0, Some("KRW"), Precision::Exact), "500원");
     example!(v, check_finance(200.0, Some("$"), Precision::Exact), "200달러");
     example!(v, check_finance(31.0, Some("EUR"), Precision::Exact), "31유로");
@@ -17,15 +20,19 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(37.0, Some("celsius")), "37°C", "섭씨37°", "섭씨37도");
-    example!(v, check_temperature(70.0, Some("fahrenheit")), "70°F", "화씨70°", "화씨70도");
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "45도");
-    example!(v, check_temperature(-15.0, Some("degree")), "영하 15도");
-    example!(v, check_temperature(-3.0, Some("degree")), "영하 삼도");
-    example!(v, check_temperature(15.0, Some("degree")), "영상 15도");
-    example!(v, check_temperature(3.0, Some("degree")), "영상 삼도");
-    example!(v, check_temperature(6.0, Some("celsius")), "섭씨 6도");
-    example!(v, check_temperature(32.0, Some("fahrenheit")), "화씨 32도");
+    example!(v, check_temperature(37.0, Some(TemperatureUnit::Celsius)), "37°C", "섭씨37°", "섭씨37도");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "화씨70°", "화씨70도");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "45도");
+    example!(v, check_temperature(-15.0, Some(TemperatureUnit::Degree)), "영하 15도");
+    example!(v, check_temperature(-3.0, Some(TemperatureUnit::Degree)), "영하 삼도");
+    example!(v, check_temperature(15.0, Some(TemperatureUnit::Degree)), "영상 15도");
+    example!(v, check_temperature(3.0, Some(TemperatureUnit::Degree)), "영상 삼도");
+    example!(v, check_temperature(6.0, Some(TemperatureUnit::Celsius)), "섭씨 6도");
+    example!(v, check_temperature(32.0, Some(TemperatureUnit::Fahrenheit)), "화씨 32도");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Celsius)), "-5°C");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "18도에서 22도까지", "18~22도");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "30도 이상");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Degree)), "10도 이하");
 }
 
 pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -271,6 +278,8 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 10, 1]), "국군의날");
     example!(v, check_moment!(c, [2013, 10, 2]), "노인의날");
     example!(v, check_moment!(c, [2013, 10, 25]), "독도의 날");
+    example!(v, check_timezone("Asia/Seoul"), "한국 시간 3시", "한국시간으로 3시");
+    example!(v, check_timezone("UTC"), "3시 UTC");
 }
 
 pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
@@ -310,6 +319,27 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1.23), "일점이삼");
     example!(v, check_integer(-3), "-3", "마이너스3", "마이너스삼", "마이너스 3", "마이나스3", "마이나스 3");
     example!(v, check_float(3.0 / 4.0), "3/4", "사분의삼");
+    example!(v, check_fraction(3, 4), "3/4", "사분의삼");
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
 }
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "3에서 5까지", "3~5", "삼에서 오까지");
+    example!(v, check_number_interval(Some(3.0), None), "3 이상", "3이상");
+    example!(v, check_number_interval(None, Some(10.0)), "10 이하", "10이하");
+    example!(v, check_number_interval(None, Some(4.0)), "4 미만");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::hours(2).into()), Some(PeriodComp::hours(3).into())), "2~3시간", "2시간~3시간");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "20분 이상");
+    example!(v, check_duration_interval(None, Some(PeriodComp::days(3).into())), "3일 이내");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Day, 1, vec![], None), "매일", "날마다");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "매일 8시");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "매주 월요일", "월요일마다");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "격주", "2주마다");
+}
@@ -0,0 +1,1294 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, DateOrder};
+
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+        number_check!(),
+        b.reg(r"(?:%|procent|percent)")?,
+        |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect (X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, b| helpers::compose_money(a.value(), b.value()));
+    b.rule_3("intersect (and X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             b.reg(r#"en"#)?,
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, _, b| helpers::compose_money(&a.value(), &b.value()));
+    b.rule_2("intersect",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             number_check!(),
+             |a, b| helpers::compose_money_number(&a.value(), &b.value()));
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::NL))?,
+                      |text_match| helpers::money_unit(names::NL, text_match.group(0))
+    );
+    b.rule_2("<amount> <unit>",
+             number_check!(),
+             money_unit!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("<unit> <amount>",
+             money_unit!(),
+             number_check!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: b.value().value(),
+                     unit: a.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_4("tussen <amount-of-money> en <amount-of-money>",
+             b.reg(r#"tussen"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"en"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("tussen <number> en <amount-of-money>",
+             b.reg(r#"tussen"#)?,
+             number_check!(),
+             b.reg(r#"en"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("van <amount-of-money> tot <amount-of-money>",
+             b.reg(r#"van(?:af)?"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"tot(?: en met)?"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("van <number> tot <amount-of-money>",
+             b.reg(r#"van(?:af)?"#)?,
+             number_check!(),
+             b.reg(r#"tot(?: en met)?"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("minder dan <amount-of-money>",
+             b.reg(r#"minder dan|onder de|hooguit|maximaal|niet meer dan"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> of minder",
+             amount_of_money_check!(),
+             b.reg(r#"of minder|maximaal"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("meer dan <amount-of-money>",
+             b.reg(r#"meer dan|boven de|minstens|ten minste|minimaal"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> of meer",
+             amount_of_money_check!(),
+             b.reg(r#"of meer|minimaal|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("second (unit-of-duration)",
+                      b.reg(r#"sec(?:onden?)?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Second))
+    );
+    b.rule_1_terminal("minute (unit-of-duration)",
+                      b.reg(r#"minuten|minuut|min"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Minute))
+    );
+    b.rule_1_terminal("hour (unit-of-duration)",
+                      b.reg(r#"uren|uur|u"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("day (unit-of-duration)",
+                      b.reg(r#"dagen|dag"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("week (unit-of-duration)",
+                      b.reg(r#"weken|week"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("month (unit-of-duration)",
+                      b.reg(r#"maanden|maand"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("year (unit-of-duration)",
+                      b.reg(r#"jaren|jaar"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Year))
+    );
+    b.rule_1_terminal("1/4 hour",
+                      b.reg(r#"(?:een )?kwartier"#)?,
+                      |_| Ok(DurationValue::new(PeriodComp::minutes(15).into()))
+    );
+    b.rule_1_terminal("half an hour",
+                      b.reg(r#"(?:een )?half ?uur"#)?,
+                      |_| Ok(DurationValue::new(PeriodComp::minutes(30).into()))
+    );
+    b.rule_2("<integer> <unit-of-duration>",
+             integer_check_by_range!(0),
+             unit_of_duration_check!(),
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+    );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
+    b.rule_3("<integer> and a half <unit-of-duration>",
+             integer_check_by_range!(0),
+             b.reg(r#"en een half"#)?,
+             unit_of_duration_check!(),
+             |integer, _, uod| Ok(DurationValue::new(helpers::period_from_fraction(integer.value().value as f32 + 0.5, uod.value().grain)?))
+    );
+    b.rule_2("in <duration>",
+             b.reg(r#"over|na"#)?,
+             duration_check!(),
+             |_, duration| duration.value().in_present()
+    );
+    b.rule_2("<duration> ago",
+             duration_check!(),
+             b.reg(r#"geleden"#)?,
+             |duration, _| duration.value().ago()
+    );
+    b.rule_3("<duration> after <time>",
+             duration_check!(),
+             b.reg(r#"na"#)?,
+             time_check!(),
+             |duration, _, time| duration.value().after(time.value())
+    );
+    b.rule_3("<duration> before <time>",
+             duration_check!(),
+             b.reg(r#"voor"#)?,
+             time_check!(),
+             |duration, _, time| duration.value().before(time.value())
+    );
+    b.rule_4("tussen <duration> en <duration>",
+             b.reg(r#"tussen"#)?,
+             duration_check!(),
+             b.reg(r#"en"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("tussen <number> en <duration>",
+             b.reg(r#"tussen"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"en"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("van <duration> tot <duration>",
+             b.reg(r#"van"#)?,
+             duration_check!(),
+             b.reg(r#"tot"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("van <number> tot <duration>",
+             b.reg(r#"van"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"tot"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|tot|of"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|tot|of"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("minstens <duration>",
+             b.reg(r#"minstens|ten minste|minimaal|meer dan"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> of meer",
+             duration_check!(),
+             b.reg(r#"of meer|of langer"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("minder dan <duration>",
+             b.reg(r#"minder dan|korter dan|hooguit|maximaal"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> of minder",
+             duration_check!(),
+             b.reg(r#"of minder|of korter"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("second (cycle)",
+                      b.reg(r#"seconden?"#)?,
+                      |_| CycleValue::new(Grain::Second)
+    );
+    b.rule_1_terminal("minute (cycle)",
+                      b.reg(r#"minuten|minuut"#)?,
+                      |_| CycleValue::new(Grain::Minute)
+    );
+    b.rule_1_terminal("hour (cycle)",
+                      b.reg(r#"uren|uur"#)?,
+                      |_| CycleValue::new(Grain::Hour)
+    );
+    b.rule_1_terminal("day (cycle)",
+                      b.reg(r#"dagen|dag"#)?,
+                      |_| CycleValue::new(Grain::Day)
+    );
+    b.rule_1_terminal("week (cycle)",
+                      b.reg(r#"weken|week"#)?,
+                      |_| CycleValue::new(Grain::Week)
+    );
+    b.rule_1_terminal("month (cycle)",
+                      b.reg(r#"maanden|maand"#)?,
+                      |_| CycleValue::new(Grain::Month)
+    );
+    b.rule_1_terminal("quarter (cycle)",
+                      b.reg(r#"kwartalen|kwartaal"#)?,
+                      |_| CycleValue::new(Grain::Quarter)
+    );
+    b.rule_1_terminal("year (cycle)",
+                      b.reg(r#"jaren|jaar"#)?,
+                      |_| CycleValue::new(Grain::Year)
+    );
+    b.rule_2("this <cycle>",
+             b.reg(r#"deze|dit|(?:de|het) huidige"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 0)
+    );
+    b.rule_2("last <cycle>",
+             b.reg(r#"(?:de |het )?(?:vorige?|afgelopen)"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_2("next <cycle>",
+             b.reg(r#"(?:de |het )?(?:volgende?|komende?)"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_4("the <cycle> after <time>",
+             b.reg(r#"de|het"#)?,
+             cycle_check!(),
+             b.reg(r#"na"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, 1, time.value())
+    );
+    b.rule_4("the <cycle> before <time>",
+             b.reg(r#"de|het"#)?,
+             cycle_check!(),
+             b.reg(r#"voor"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, -1, time.value())
+    );
+    b.rule_3("last n <cycle>",
+             b.reg(r#"(?:de )?(?:afgelopen|laatste|vorige)"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_3("next n <cycle>",
+             b.reg(r#"(?:de )?(?:komende|volgende)"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_2("<ordinal> quarter",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             |ordinal, _| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 &helpers::cycle_nth(Grain::Year, 0)?
+             )
+    );
+    b.rule_3("<ordinal> quarter <year>",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             time_check!(),
+             |ordinal, _, time| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 time.value()
+             )
+    );
+    Ok(())
+}
+
+pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect <time>",
+             time_check!(|time: &TimeValue| !time.latent),
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, b| a.value().intersect(b.value())
+    );
+    b.rule_3("intersect by 'van', 'in'",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"van|in"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_3("intersect by ','",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#","#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_2("on a named-day",
+             b.reg(r#"op"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"maandags?|ma\."#)?,
+                      |_| helpers::day_of_week(Weekday::Mon)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"dinsdags?|di\."#)?,
+                      |_| helpers::day_of_week(Weekday::Tue)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"woensdags?|wo\."#)?,
+                      |_| helpers::day_of_week(Weekday::Wed)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"donderdags?|do\."#)?,
+                      |_| helpers::day_of_week(Weekday::Thu)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"vrijdags?|vr\."#)?,
+                      |_| helpers::day_of_week(Weekday::Fri)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"zaterdags?|za\."#)?,
+                      |_| helpers::day_of_week(Weekday::Sat)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"zondags?|zo\."#)?,
+                      |_| helpers::day_of_week(Weekday::Sun)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"januari|jan\.?"#)?,
+                      |_| helpers::month(1)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"februari|feb\.?"#)?,
+                      |_| helpers::month(2)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"maart|mrt\.?"#)?,
+                      |_| helpers::month(3)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"april|apr\.?"#)?,
+                      |_| helpers::month(4)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"mei"#)?,
+                      |_| helpers::month(5)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"juni|jun\.?"#)?,
+                      |_| helpers::month(6)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"juli|jul\.?"#)?,
+                      |_| helpers::month(7)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"augustus|aug\.?"#)?,
+                      |_| helpers::month(8)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"september|sept?\.?"#)?,
+                      |_| helpers::month(9)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"oktober|okt\.?"#)?,
+                      |_| helpers::month(10)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"november|nov\.?"#)?,
+                      |_| helpers::month(11)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"december|dec\.?"#)?,
+                      |_| helpers::month(12)
+    );
+    b.rule_1_terminal("christmas",
+                      b.reg(r#"kerstmis|(?:eerste )?kerstdag|kerst"#)?,
+                      |_| helpers::month_day(12, 25)
+    );
+    b.rule_1_terminal("christmas eve",
+                      b.reg(r#"kerstavond"#)?,
+                      |_| helpers::month_day(12, 24)
+    );
+    b.rule_1_terminal("boxing day",
+                      b.reg(r#"tweede kerstdag"#)?,
+                      |_| helpers::month_day(12, 26)
+    );
+    b.rule_1_terminal("new year's eve",
+                      b.reg(r#"oudejaarsavond|oudejaarsdag|oudjaar"#)?,
+                      |_| helpers::month_day(12, 31)
+    );
+    b.rule_1_terminal("new year's day",
+                      b.reg(r#"nieuwjaarsdag|nieuwjaar"#)?,
+                      |_| helpers::month_day(1, 1)
+    );
+    b.rule_1_terminal("king's day",
+                      b.reg(r#"koningsdag"#)?,
+                      |_| helpers::month_day(4, 27)
+    );
+    b.rule_1_terminal("sinterklaas",
+                      b.reg(r#"sinterklaas(?:avond)?|pakjesavond"#)?,
+                      |_| helpers::month_day(12, 5)
+    );
+    b.rule_1_terminal("valentine's day",
+                      b.reg(r#"valentijn(?:sdag)?"#)?,
+                      |_| helpers::month_day(2, 14)
+    );
+    b.rule_1_terminal("now",
+                      b.reg(r#"op dit moment|nu|meteen|direct|onmiddellijk"#)?,
+                      |_| helpers::cycle_nth(Grain::Second, 0)
+    );
+    b.rule_1_terminal("today",
+                      b.reg(r#"vandaag"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 0)
+    );
+    b.rule_1_terminal("tomorrow",
+                      b.reg(r#"morgen"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 1)
+    );
+    b.rule_1_terminal("after tomorrow",
+                      b.reg(r#"overmorgen"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 2)
+    );
+    b.rule_1_terminal("yesterday",
+                      b.reg(r#"gister(?:en)?"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -1)
+    );
+    b.rule_1_terminal("before yesterday",
+                      b.reg(r#"eergister(?:en)?"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -2)
+    );
+    b.rule_2("this <time>",
+             b.reg(r#"deze|dit"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(0)
+    );
+    b.rule_2("next <time>",
+             b.reg(r#"(?:de |het )?(?:volgende?|komende?)"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("last <time>",
+             b.reg(r#"(?:de |het )?(?:vorige?|afgelopen)"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(-1)
+    );
+    b.rule_4("last <day-of-week> of <time>",
+             b.reg(r#"(?:de )?laatste"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"van|in"#)?,
+             time_check!(),
+             |_, dow, _, time| dow.value().last_of(time.value())
+    );
+    b.rule_4("nth <time> of <time>",
+             ordinal_check!(),
+             time_check!(),
+             b.reg(r#"van|in"#)?,
+             time_check!(),
+             |ordinal, a, _, b| b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+    );
+    b.rule_2("in <month>",
+             b.reg(r#"in"#)?,
+             time_check!(form!(Form::Month(_))),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_1("year",
+             integer_check_by_range!(1900, 2100),
+             |integer| {
+                 helpers::year(integer.value().value as i32)
+             }
+    );
+    b.rule_2("year",
+             b.reg(r#"(?:het )?jaar"#)?,
+             integer_check_by_range!(-1000, 2100),
+             |_, integer| {
+                 helpers::year(integer.value().value as i32)
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(-1000, 1899),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(2101, 2200),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_2("the <day-of-month> (ordinal)",
+             b.reg(r#"de"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 31),
+             |_, ordinal| helpers::day_of_month(ordinal.value().value as u32)
+    );
+    b.rule_2("<day-of-month> (non ordinal) <named-month>",
+             integer_check_by_range!(1, 31),
+             time_check!(form!(Form::Month(_))),
+             |integer, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_2("<day-of-month> (ordinal) <named-month>",
+             ordinal_check!(|ordinal: &OrdinalValue| 1 <= ordinal.value && ordinal.value <= 31),
+             time_check!(form!(Form::Month(_))),
+             |ordinal, month| month.value().intersect(&helpers::day_of_month(ordinal.value().value as u32)?)
+    );
+    b.rule_2("<day-of-week> <day-of-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             integer_check_by_range!(1, 31),
+             |dow, integer| dow.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_1("time-of-day (latent)",
+             integer_check_by_range!(1, 23),
+             |integer| Ok(helpers::hour(integer.value().value as u32, integer.value().value < 12)?.latent())
+    );
+    b.rule_1("midnight (latent)",
+             integer_check_by_range!(0, 0),
+             |_| Ok(helpers::hour(0, false)?.latent())
+    );
+    b.rule_2("<time-of-day> o'clock",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"uur|u|h"#)?,
+             |time, _| Ok(time.value().clone().not_latent())
+    );
+    b.rule_2("at <time-of-day>",
+             b.reg(r#"om|rond|omstreeks|tegen"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, time| Ok(time.value().clone().not_latent())
+    );
+    b.rule_1_terminal("hh:mm",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          false)
+    );
+    b.rule_1_terminal("hh:mm:ss",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)[:.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute_second(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?,
+                          false)
+    );
+    b.rule_1_terminal("noon",
+                      b.reg(r#"(?:het )?middaguur"#)?,
+                      |_| helpers::hour(12, false)
+    );
+    b.rule_1_terminal("midnight",
+                      b.reg(r#"middernacht"#)?,
+                      |_| helpers::hour(0, false)
+    );
+    b.rule_1_terminal("quarter (relative minutes)",
+                      b.reg(r#"kwart"#)?,
+                      |_| Ok(RelativeMinuteValue(15))
+    );
+    b.rule_1("number (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             |integer| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_2("number <minutes> (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             b.reg(r#"minuten|minuut|min"#)?,
+             |a, _| Ok(RelativeMinuteValue(a.value().value as i32))
+    );
+    b.rule_2("<hour-of-day> <integer> (as relative minutes)",
+             time_check!(|time: &TimeValue| !time.latent && form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))(time)),
+             relative_minute_check!(),
+             |time, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("relative minutes before <integer> (hour-of-day)",
+             relative_minute_check!(),
+             b.reg(r#"voor"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("relative minutes past <integer> (hour-of-day)",
+             relative_minute_check!(),
+             b.reg(r#"over"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    // "half drie" is half an hour before three
+    b.rule_2("half <integer> (dutch style hour-of-day)",
+             b.reg(r#"half"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |_, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -30,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("relative minutes before half <integer> (dutch style hour-of-day)",
+             relative_minute_check!(),
+             b.reg(r#"voor half"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -30 - relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("relative minutes past half <integer> (dutch style hour-of-day)",
+             relative_minute_check!(),
+             b.reg(r#"over half"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -30 + relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_1_terminal("dd-mm-yyyy",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_1_terminal("yyyy-mm-dd",
+                      b.reg(r#"(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::ymd(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?
+                      )
+    );
+    b.rule_1_terminal("dd-mm",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          None,
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_2("<time-of-day> morning",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"'s ochtends|'s morgens|in de ochtend"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(12, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> afternoon",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"'s middags|in de middag"#)?,
+             |time, _| {
+                 let period = helpers::hour(12, false)?
+                     .span_to(&helpers::hour(19, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> evening",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"'s avonds|in de avond"#)?,
+             |time, _| {
+                 let period = helpers::hour(18, false)?
+                     .span_to(&helpers::hour(0, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> night",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"'s nachts|in de nacht"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(6, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_1_terminal("morning (latent)",
+                      b.reg(r#"ochtend"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
+    );
+    b.rule_1_terminal("afternoon (latent)",
+                      b.reg(r#"middag"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
+    );
+    b.rule_1_terminal("evening (latent)",
+                      b.reg(r#"avond"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
+    );
+    b.rule_1_terminal("night (latent)",
+                      b.reg(r#"nacht"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Night, 0, 4)?.latent())
+    );
+    b.rule_1_terminal("morning",
+                      b.reg(r#"'s ochtends|'s morgens"#)?,
+                      |_| helpers::part_of_day(PartOfDayForm::Morning, 4, 12)
+    );
+    b.rule_1_terminal("afternoon",
+                      b.reg(r#"'s middags"#)?,
+                      |_| helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)
+    );
+    b.rule_1_terminal("evening",
+                      b.reg(r#"'s avonds"#)?,
+                      |_| helpers::part_of_day(PartOfDayForm::Evening, 18, 0)
+    );
+    b.rule_1_terminal("night",
+                      b.reg(r#"'s nachts"#)?,
+                      |_| helpers::part_of_day(PartOfDayForm::Night, 0, 4)
+    );
+    b.rule_2("in the <part-of-day>",
+             b.reg(r#"in de|tijdens de"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(pod.value().clone().not_latent())
+    );
+    b.rule_2("this <part-of-day>",
+             b.reg(r#"van|deze"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                 .intersect(pod.value())?
+                 .form(pod.value().form.clone()))
+    );
+    b.rule_2("<time> <part-of-day>",
+             time_check!(),
+             time_check!(|time: &TimeValue| !time.latent && form!(Form::PartOfDay(_))(time)),
+             |time, pod| time.value().intersect(pod.value())
+    );
+    b.rule_2("<time> <part-of-day> (latent)",
+             time_check!(|time: &TimeValue| form!(Form::DayOfWeek{..})(time) || form!(Form::Cycle(_))(time)),
+             time_check!(form!(Form::PartOfDay(_))),
+             |time, pod| time.value().intersect(pod.value())
+    );
+    b.rule_1_terminal("week-end",
+                      b.reg(r#"week-?end"#)?,
+                      |_| helpers::weekend(true)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"zomer"#)?,
+                      |_| helpers::month_day(6, 21)?
+                          .span_to(&helpers::month_day(9, 23)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"herfst|najaar"#)?,
+                      |_| helpers::month_day(9, 23)?
+                          .span_to(&helpers::month_day(12, 21)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"winter"#)?,
+                      |_| helpers::month_day(12, 21)?
+                          .span_to(&helpers::month_day(3, 20)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"lente|voorjaar"#)?,
+                      |_| helpers::month_day(3, 20)?
+                          .span_to(&helpers::month_day(6, 21)?, false)
+    );
+    b.rule_2("<article> <time>",
+             b.reg(r#"de|het|op|in het|in de"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_4("dd-dd (interval) <month>",
+             b.reg(r#"(?:van )?(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"\-|tot(?: en met)?"#)?,
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             time_check!(form!(Form::Month(_))),
+             |a, _, b, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_3("<datetime> - <datetime> (interval)",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"\-"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("from <time> to <time>",
+             b.reg(r#"van(?:af)?"#)?,
+             time_check!(),
+             b.reg(r#"\-|tot(?: en met)?"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("between <datetime> and <datetime> (interval)",
+             b.reg(r#"tussen"#)?,
+             time_check!(),
+             b.reg(r#"en"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_2("within <duration>",
+             b.reg(r#"binnen(?: de| het)?"#)?,
+             duration_check!(),
+             |_, duration| helpers::cycle_nth(Grain::Second, 0)?.span_to(&duration.value().in_present()?, false)
+    );
+    Ok(())
+}
+
+pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as temp", number_check!(), |a| {
+        Ok(TemperatureValue {
+            value: a.value().value(),
+            unit: None,
+            latent: true,
+            ..TemperatureValue::default()
+        })
+    });
+    b.rule_2("<latent temp> temp",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"graden|graad|°"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Degree),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Celsius",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"c(?:elsius)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Celsius),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Fahrenheit",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"f(?:ahrenheit)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<latent temp> temp below zero",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"(?:graden |graad |°)?onder (?:nul|het vriespunt)"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_1_terminal("below zero",
+                      b.reg(r#"onder (?:nul|het vriespunt)"#)?,
+                      |_| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: None,
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("tussen <temp> en <temp>",
+             b.reg(r#"tussen"#)?,
+             temperature_check!(),
+             b.reg(r#"en"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("van <temp> tot <temp>",
+             b.reg(r#"van"#)?,
+             temperature_check!(),
+             b.reg(r#"tot"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("minder dan <temp>",
+             b.reg(r#"minder dan|onder de|hooguit|maximaal"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> of minder",
+             temperature_check!(),
+             b.reg(r#"of minder|of lager"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("meer dan <temp>",
+             b.reg(r#"meer dan|boven de|minstens|minimaal"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> of meer",
+             temperature_check!(),
+             b.reg(r#"of meer|of hoger"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             number_check!(),
+             |a, b| helpers::compose_numbers(&a.value(), &b.value()));
+    b.rule_3("intersect",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"en"#)?,
+             number_check!(),
+             |a, _, b| helpers::compose_numbers(&a.value(), &b.value()));
+    b.rule_1_terminal("null",
+                      b.reg(r#"nul|geen"#)?,
+                      |_| IntegerValue::new(0)
+    );
+    b.rule_1_terminal("integer one",
+                      b.reg(r#"een|één|eén"#)?,
+                      |_| IntegerValue::new(1)
+    );
+    b.rule_1_terminal("integer (2..19)",
+                      b.reg(r#"(twee|drie|vier|vijf(?:tien)?|zes(?:tien)?|zeven(?:tien)?|acht(?:tien)?|negen(?:tien)?|elf|twaalf|dertien|veertien)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "twee" => 2,
+                              "drie" => 3,
+                              "vier" => 4,
+                              "vijf" => 5,
+                              "zes" => 6,
+                              "zeven" => 7,
+                              "acht" => 8,
+                              "negen" => 9,
+                              "elf" => 11,
+                              "twaalf" => 12,
+                              "dertien" => 13,
+                              "veertien" => 14,
+                              "vijftien" => 15,
+                              "zestien" => 16,
+                              "zeventien" => 17,
+                              "achttien" => 18,
+                              "negentien" => 19,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      }
+    );
+    b.rule_1_terminal("ten",
+                      b.reg(r#"tien"#)?,
+                      |_| IntegerValue::new_with_grain(10, 1)
+    );
+    b.rule_1_terminal("dozen",
+                      b.reg(r#"dozijn"#)?,
+                      |_| Ok(IntegerValue {
+                          value: 12,
+                          grain: Some(1),
+                          group: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_1_terminal("hundred",
+                      b.reg(r#"honderd"#)?,
+                      |_| IntegerValue::new_with_grain(100, 2)
+    );
+    b.rule_1_terminal("thousand",
+                      b.reg(r#"duizend"#)?,
+                      |_| IntegerValue::new_with_grain(1_000, 3)
+    );
+    b.rule_1_terminal("million",
+                      b.reg(r#"miljoen"#)?,
+                      |_| IntegerValue::new_with_grain(1_000_000, 6)
+    );
+    b.rule_1_terminal("billion",
+                      b.reg(r#"miljard"#)?,
+                      |_| IntegerValue::new_with_grain(1_000_000_000, 9)
+    );
+    b.rule_1_terminal("integer (20..90)",
+                      b.reg(r#"(twintig|dertig|veertig|vijftig|zestig|zeventig|tachtig|negentig)"#)?,
+                      |text_match| {
+                        let value = match text_match.group(1).as_ref() {
+                            "twintig" => 20,
+                            "dertig" => 30,
+                            "veertig" => 40,
+                            "vijftig" => 50,
+                            "zestig" => 60,
+                            "zeventig" => 70,
+                            "tachtig" => 80,
+                            "negentig" => 90,
+                            _ => return Err(RuleErrorKind::Invalid.into()),
+                        };
+                        IntegerValue::new_with_grain(value, 1)
+                      }
+    );
+    // units come before tens, "eenentwintig" and "tweeëntwintig" are 21 and 22
+    b.rule_3("integer ([2-9][1-9])",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"en|ën"#)?,
+             integer_check_by_range!(20, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             |a, _, b| IntegerValue::new(a.value().value + b.value().value)
+    );
+    b.rule_1_terminal("integer (numeric)",
+                      b.reg(r#"(\d{1,18})"#)?,
+                      |text_match| IntegerValue::new(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("integer with thousands separator .",
+                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
+                      |text_match| IntegerValue::new(text_match.group(1).replace(".", "").parse()?)
+    );
+    b.rule_2("number hundreds",
+        integer_check_by_range!(1, 99),
+        b.reg(r#"honderd"#)?,
+        |a, _| Ok(IntegerValue {
+            value: a.value().value * 100,
+            grain: Some(2),
+            ..IntegerValue::default()
+        })
+    );
+    b.rule_2("number thousands",
+        integer_check_by_range!(1, 999),
+        b.reg(r#"duizend"#)?,
+        |a, _| Ok(IntegerValue {
+            value: a.value().value * 1_000,
+            grain: Some(3),
+            ..IntegerValue::default()
+        })
+    );
+    b.rule_2("number millions",
+        integer_check_by_range!(1, 999),
+        b.reg(r#"miljoen"#)?,
+        |a, _| Ok(IntegerValue {
+            value: a.value().value * 1_000_000,
+            grain: Some(6),
+            ..IntegerValue::default()
+        })
+    );
+    b.rule_2("number billions",
+        integer_check_by_range!(1, 999),
+        b.reg(r#"miljard"#)?,
+        |a, _| Ok(IntegerValue {
+            value: a.value().value * 1_000_000_000,
+            grain: Some(9),
+            ..IntegerValue::default()
+        })
+    );
+    b.rule_1_terminal("decimal number",
+                      b.reg(r#"(\d*,\d+)"#)?,
+                      |text_match| FloatValue::new(text_match.group(1).replace(",", ".").parse()?)
+    );
+    b.rule_1_terminal("decimal with thousands separator",
+                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
+                      |text_match| FloatValue::new(text_match.group(1).replace(".", "").replace(",", ".").parse()?)
+    );
+    b.rule_3("number comma number",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             b.reg(r#"komma"#)?,
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             |a, _, b| FloatValue::new(b.value().value() * 0.1 + a.value().value())
+    );
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(halve|half|derden|derde|kwart|vierden|vierde|vijfden|vijfde|zesden|zesde|zevenden|zevende|achtsten|achtste|negenden|negende|tienden|tiende)"#)?,
+             |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> en een half",
+             integer_check_by_range!(0, 99),
+             b.reg(r#"en een half"#)?,
+             |integer, _| helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, 2)?)
+    );
+    b.rule_1_terminal("anderhalf",
+                      b.reg(r#"anderhalf"#)?,
+                      |_| FloatValue::new_fraction(3, 2)
+    );
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+                      |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+                      b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+                      |text_match| helpers::compose_mixed_fraction(
+                          text_match.group(1).parse()?,
+                          &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
+    );
+    b.rule_2("numbers prefix with -, negative or minus",
+             b.reg(r#"-|min|negatief"#)?,
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             |_, a| -> RuleResult<NumberValue> {
+                 Ok(match a.value().clone() {
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * -1,
+                             prefixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         FloatValue {
+                             value: float.value * -1.0,
+                             prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
+                             ..float
+                         }
+                             .into()
+                     }
+                 })
+             });
+    b.rule_2("numbers suffixes (K, M, G)",
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             b.reg_neg_lh(r#"([kmg])"#, r#"^[^\W\$€]"#)?,
+             |a, text_match| -> RuleResult<NumberValue> {
+                 let multiplier = match text_match.group(0).as_ref() {
+                     "k" => 1000,
+                     "m" => 1000000,
+                     "g" => 1000000000,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * multiplier,
+                             suffixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         let product = float.value * (multiplier as f32);
+                         if product.floor() == product {
+                             IntegerValue {
+                                 value: product as i64,
+                                 suffixed: true,
+                                 ..IntegerValue::default()
+                             }
+                                 .into()
+                         } else {
+                             FloatValue {
+                                 value: product,
+                                 suffixed: true,
+                                 ..float
+                             }
+                                 .into()
+                         }
+                     }
+                 })
+             });
+    b.rule_1_terminal("ordinals (first..19th)",
+                      b.reg(r#"(eerste|tweede|derde|vierde|vijfde|zesde|zevende|achtste|negende|tiende|elfde|twaalfde|dertiende|veertiende|vijftiende|zestiende|zeventiende|achttiende|negentiende)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "eerste" => 1,
+                              "tweede" => 2,
+                              "derde" => 3,
+                              "vierde" => 4,
+                              "vijfde" => 5,
+                              "zesde" => 6,
+                              "zevende" => 7,
+                              "achtste" => 8,
+                              "negende" => 9,
+                              "tiende" => 10,
+                              "elfde" => 11,
+                              "twaalfde" => 12,
+                              "dertiende" => 13,
+                              "veertiende" => 14,
+                              "vijftiende" => 15,
+                              "zestiende" => 16,
+                              "zeventiende" => 17,
+                              "achttiende" => 18,
+                              "negentiende" => 19,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
+    b.rule_1_terminal("ordinal (20..90)",
+                      b.reg(r#"(twintig|dertig|veertig|vijftig|zestig|zeventig|tachtig|negentig)ste"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "twintig" => 20,
+                              "dertig" => 30,
+                              "veertig" => 40,
+                              "vijftig" => 50,
+                              "zestig" => 60,
+                              "zeventig" => 70,
+                              "tachtig" => 80,
+                              "negentig" => 90,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          Ok(OrdinalValue::new(value))
+                      }
+    );
+    b.rule_3("ordinal [2-9][1-9]",
+             integer_check_by_range!(1, 9),
+             b.reg(r#"en|ën"#)?,
+             ordinal_check!(|ordinal: &OrdinalValue| ordinal.value % 10 == 0),
+             |integer, _, ordinal| Ok(OrdinalValue::new(integer.value().value + ordinal.value().value))
+    );
+    b.rule_1_terminal("ordinal (digits)",
+                      b.reg(r#"0*(\d+)(?:ste|de|e)"#)?,
+                      |text_match| Ok(OrdinalValue::new(text_match.group(1).parse()?))
+    );
+    Ok(())
+}
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word {
+        "half" | "halve" => Ok(2),
+        "derde" | "derden" => Ok(3),
+        "kwart" | "vierde" | "vierden" => Ok(4),
+        "vijfde" | "vijfden" => Ok(5),
+        "zesde" | "zesden" => Ok(6),
+        "zevende" | "zevenden" => Ok(7),
+        "achtste" | "achtsten" => Ok(8),
+        "negende" | "negenden" => Ok(9),
+        "tiende" | "tienden" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
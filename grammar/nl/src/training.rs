@@ -0,0 +1,152 @@
+use rustling_ontology_values::check::*;
+use rustling_ontology_moment::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::ResolverContext;
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "tussen 10 en 20 euro", "10-20 euro", "van 10€ tot 20€");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "meer dan 100 euro", "minstens honderd euro");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "minder dan 50 dollar", "hooguit $50");
+    example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10$", "tien dollar");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Exact), "20€", "€ 20", "20 euro", "twintig euro");
+    example!(v, check_finance(3.5, Some("EUR"), Precision::Exact), "3 euro en 50 cent", "drie euro vijftig cent");
+    example!(v, check_finance(9.0, Some("£"), Precision::Exact), "£9", "negen pond");
+    example!(v, check_finance(15.0, Some("CHF"), Precision::Exact), "15 chf", "vijftien zwitserse frank");
+    example!(v, check_finance(10000.0, Some("GBP"), Precision::Exact), "10.000 britse pond");
+}
+
+pub fn examples_duration(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration!([0, 0, 0, 0, 2]), "2 uur", "twee uur", "2 uren");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 30]), "een half uur", "halfuur", "30 minuten");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 15]), "een kwartier", "vijftien minuten");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30]), "anderhalf uur", "1 en een half uur");
+    example!(v, check_duration!([0, 0, 0, 3]), "drie dagen");
+    example!(v, check_duration_interval(Some(PeriodComp::days(2).into()), Some(PeriodComp::days(3).into())), "tussen twee en drie dagen", "van 2 tot 3 dagen", "2-3 dagen");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "minstens 20 minuten", "20 minuten of meer");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "minder dan een week", "1 week of minder");
+}
+
+pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "nu", "op dit moment", "meteen");
+    example!(v, check_moment!(c, [2013, 2, 12]), "vandaag");
+    example!(v, check_moment!(c, [2013, 2, 11]), "gisteren", "gister");
+    example!(v, check_moment!(c, [2013, 2, 10]), "eergisteren");
+    example!(v, check_moment!(c, [2013, 2, 13]), "morgen");
+    example!(v, check_moment!(c, [2013, 2, 14]), "overmorgen");
+    example!(v, check_moment!(c, [2013, 2, 18]), "maandag", "ma.", "op maandag", "deze maandag");
+    example!(v, check_moment!(c, [2013, 2, 18]), "maandag 18 februari", "maandag, 18 februari");
+    example!(v, check_moment!(c, [2013, 2, 19]), "dinsdag", "volgende dinsdag");
+    example!(v, check_moment!(c, [2013, 2, 13]), "woensdag", "wo.");
+    example!(v, check_moment!(c, [2013, 2, 14]), "donderdag");
+    example!(v, check_moment!(c, [2013, 2, 15]), "vrijdag");
+    example!(v, check_moment!(c, [2013, 2, 16]), "zaterdag");
+    example!(v, check_moment!(c, [2013, 2, 17]), "zondag");
+    example!(v, check_moment!(c, [2013, 2, 10]), "afgelopen zondag", "vorige zondag");
+    example!(v, check_moment!(c, [2013, 5, 5]), "5 mei", "vijf mei", "op 5 mei", "5e mei");
+    example!(v, check_moment!(c, [2013, 5, 5]), "5 mei 2013", "5-5-2013");
+    example!(v, check_moment!(c, [2013, 7, 4]), "4 juli", "4/7");
+    example!(v, check_moment!(c, [2013, 3, 1]), "1 maart", "eerste maart", "1e maart", "1-3-2013", "1.3.2013");
+    example!(v, check_moment!(c, [2013, 12, 24]), "24 dec.", "24 december", "kerstavond");
+    example!(v, check_moment!(c, [2013, 2, 20]), "de twintigste", "de 20e", "20 februari", "20/2");
+    example!(v, check_moment!(c, [1974, 10, 31]), "31-10-1974", "31/10/74");
+    example!(v, check_moment!(c, [2014, 9, 12]), "vrijdag 12 september 2014");
+    // Cycles
+    example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "deze week");
+    example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "vorige week", "afgelopen week");
+    example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "volgende week", "komende week");
+    example!(v, check_moment!(c, [2013, 1]), "vorige maand");
+    example!(v, check_moment!(c, [2013, 3]), "volgende maand");
+    example!(v, check_moment!(c, [2012]), "vorig jaar");
+    example!(v, check_moment!(c, [2013]), "dit jaar");
+    example!(v, check_moment!(c, [2014]), "volgend jaar");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "tweede kwartaal");
+    // Hours
+    example!(v, check_moment!(c, [2013, 2, 12, 15]), "om drie uur 's middags", "om 15 uur", "15u");
+    example!(v, check_moment!(c, [2013, 2, 12, 20]), "om acht uur 's avonds");
+    example!(v, check_moment!(c, [2013, 2, 13, 3]), "om drie uur 's nachts");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0]), "15:00", "15.00");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0, 10]), "15:00:10");
+    example!(v, check_moment!(c, [2013, 2, 13, 0]), "middernacht");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 30]), "half drie", "om half drie", "14:30");
+    example!(v, check_moment!(c, [2013, 2, 12, 16, 15]), "kwart over vier", "om kwart over vier", "16:15");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 45]), "kwart voor vier", "15:45");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 40]), "tien over half drie");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 20]), "tien voor half drie");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 20]), "drie uur twintig", "twintig over drie");
+    example!(v, check_moment!(c, [2013, 2, 13, 11]), "morgen om elf uur", "woensdag om 11 uur 's ochtends");
+    // Durations from now
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 31, 0]), "over een minuut", "over 1 minuut");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 0, 0]), "over een half uur");
+    example!(v, check_moment!(c, [2013, 2, 12, 2, 30]), "twee uur geleden");
+    example!(v, check_moment!(c, [2013, 2, 19]), "over 7 dagen", "over een week");
+    example!(v, check_moment!(c, [2013, 1, 22]), "drie weken geleden");
+    example!(v, check_moment!(c, [2013, 4]), "over twee maanden");
+    example!(v, check_moment!(c, [2011, 2]), "twee jaar geleden");
+    // Holidays and seasons
+    example!(v, check_moment!(c, [2013, 12, 25]), "kerstmis", "kerst", "eerste kerstdag");
+    example!(v, check_moment!(c, [2013, 12, 26]), "tweede kerstdag");
+    example!(v, check_moment!(c, [2013, 12, 31]), "oudejaarsavond");
+    example!(v, check_moment!(c, [2014, 1, 1]), "nieuwjaarsdag");
+    example!(v, check_moment!(c, [2013, 4, 27]), "koningsdag");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "deze zomer");
+    // Part of day
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "vanavond", "deze avond");
+    example!(v, check_moment_span!(c, [2013, 2, 13, 4], [2013, 2, 13, 12]), "morgenochtend", "morgen in de ochtend", "morgen 's ochtends");
+    example!(v, check_moment_span!(c, [2013, 2, 11, 12], [2013, 2, 11, 19]), "gistermiddag", "gisteren 's middags");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "dit weekend", "het weekend");
+    // Intervals
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "de komende 3 seconden");
+    example!(v, check_moment_span!(c, [2013, 2, 10], [2013, 2, 12]), "de afgelopen 2 dagen", "de laatste twee dagen");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "de komende 3 dagen", "volgende drie dagen");
+    example!(v, check_moment_span!(c, [2013, 7, 13], [2013, 7, 16]), "van 13 tot 15 juli", "13-15 juli");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9, 30], [2013, 2, 12, 11]), "9:30 - 11:00", "van 9:30 tot 11:00", "tussen 9:30 en 11:00");
+}
+
+pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(0), "0", "nul");
+    example!(v, check_integer(1), "1", "een", "één");
+    example!(v, check_integer(3), "drie");
+    example!(v, check_integer(10), "tien");
+    example!(v, check_integer(13), "dertien");
+    example!(v, check_integer(16), "zestien");
+    example!(v, check_integer(18), "achttien");
+    example!(v, check_integer(21), "eenentwintig", "21");
+    example!(v, check_integer(22), "tweeëntwintig");
+    example!(v, check_integer(33), "33", "drieëndertig");
+    example!(v, check_integer(50), "vijftig");
+    example!(v, check_integer(99), "negenennegentig");
+    example!(v, check_integer(100), "honderd", "100");
+    example!(v, check_integer(123), "honderddrieëntwintig");
+    example!(v, check_integer(300), "driehonderd");
+    example!(v, check_integer(1000), "duizend", "1.000");
+    example!(v, check_integer(1980), "negentienhonderdtachtig", "duizend negenhonderd tachtig");
+    example!(v, check_integer(2020), "tweeduizend twintig", "tweeduizendtwintig");
+    example!(v, check_integer(35000), "vijfendertigduizend");
+    example!(v, check_integer(200000), "tweehonderdduizend", "200.000", "200k");
+    example!(v, check_integer(3000000), "drie miljoen", "3M", "3.000.000");
+    example!(v, check_integer(1000000), "een miljoen");
+    example!(v, check_integer(-1200000), "- 1.200.000", "-1200000", "min 1.200.000", "-1,2M");
+    example!(v, check_float(1.5), "1,5", "een komma vijf");
+    example!(v, check_float(0.77), "0,77", ",77");
+    example!(v, check_fraction(2, 3), "twee derde", "2/3");
+    example!(v, check_fraction(3, 2), "anderhalf", "een en een half", "1 1/2");
+    example!(v, check_ordinal(1), "eerste", "1e", "1ste");
+    example!(v, check_ordinal(3), "derde", "3de");
+    example!(v, check_ordinal(21), "eenentwintigste");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 graden", "dertig graden", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "min vijf graden", "-5°", "5 graden onder nul");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Celsius)), "25°C", "25 graden celsius", "vijfentwintig graden celsius");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "zeventig graden fahrenheit");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "tussen 18 en 22 graden", "van 18 tot 22 graden", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "meer dan 30 graden", "30 graden of meer");
+    example!(v, check_temperature_interval(None, Some(0.0), None), "onder nul");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("jan.jansen@example.nl"), "jan.jansen@example.nl");
+    example!(v, check_url("https://www.example.nl/pad?q=1", "example.nl"), "https://www.example.nl/pad?q=1");
+}
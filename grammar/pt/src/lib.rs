@@ -0,0 +1,65 @@
+extern crate rustling;
+#[macro_use]
+extern crate rustling_ontology_values;
+extern crate rustling_ontology_moment;
+
+mod rules;
+mod training;
+
+use rustling_ontology_values::DimensionKind::*;
+
+pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    let mut b = ::rustling::RuleSetBuilder::new(
+                    ::rustling::BoundariesChecker::detailed(),
+                    ::rustling::BoundariesChecker::separated_alphanumeric_word());
+    rules::rules_numbers(&mut b)?;
+    rules::rules_time(&mut b)?;
+    rules::rules_cycle(&mut b)?;
+    rules::rules_duration(&mut b)?;
+    rules::rules_temperature(&mut b)?;
+    rules::rules_finance(&mut b)?;
+    rules::rules_percentage(&mut b)?;
+    rustling_ontology_values::common_rules::rules_email(&mut b)?;
+    rustling_ontology_values::common_rules::rules_url(&mut b)?;
+    rustling_ontology_values::common_rules::rules_phone_number(&mut b)?;
+    Ok(b.build())
+}
+
+pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
+    return vec![Number, Ordinal, Time, Duration, Temperature, AmountOfMoney, Percentage, Email, Url, PhoneNumber, DurationInterval];
+}
+
+pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = vec![];
+    training::examples_numbers(&mut v);
+    training::examples_duration(&mut v);
+    training::examples_time(&mut v);
+    training::examples_temperature(&mut v);
+    training::examples_finance(&mut v);
+    training::examples_contact(&mut v);
+    v
+}
+
+#[cfg(test)]
+mod test {
+    use rustling::*;
+    use rustling_ontology_values::dimension::Dimension;
+
+    use super::*;
+    fn assert_examples(rules: &RuleSet<Dimension>, examples: Vec<Example<Dimension>>) {
+        for ex in examples.iter() {
+            let stash = rules.apply_all(&ex.text.to_lowercase()).unwrap();
+            let correct_results = stash
+                        .into_iter()
+                        .filter(|candidate| candidate.root_node.byte_range == Range(0, ex.text.len()) && ex.predicate.check(&candidate))
+                        .collect::<Vec<_>>();
+            assert!(!correct_results.is_empty(), format!("No full match found for: {:?}", ex.text));
+        }
+    }
+    #[test]
+    fn test_examples() {
+        let rules = rule_set().unwrap();
+        let examples = examples();
+        assert_examples(&rules, examples);
+    }
+}
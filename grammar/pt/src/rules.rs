@@ -0,0 +1,1196 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+use rustling_ontology_values::currency::names;
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, DateOrder};
+
+pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> per cent",
+        number_check!(),
+        b.reg(r"(?:%|por ?cento)")?,
+        |number, _| Ok(PercentageValue(number.value().value()))
+    );
+    Ok(())
+}
+
+pub fn rules_finance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect (X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, b| helpers::compose_money(a.value(), b.value()));
+    b.rule_3("intersect (and X cents)",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(|money: &AmountOfMoneyValue| money.is_minor_unit()),
+             |a, _, b| helpers::compose_money(&a.value(), &b.value()));
+    b.rule_2("intersect",
+             amount_of_money_check!(|money: &AmountOfMoneyValue| !money.is_minor_unit()),
+             number_check!(),
+             |a, b| helpers::compose_money_number(&a.value(), &b.value()));
+    b.rule_1_terminal("currency",
+                      b.reg(&helpers::money_unit_regex(names::PT))?,
+                      |text_match| helpers::money_unit(names::PT, text_match.group(0))
+    );
+    b.rule_2("<amount> <unit>",
+             number_check!(),
+             money_unit!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: a.value().value(),
+                     unit: b.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_2("<unit> <amount>",
+             money_unit!(),
+             number_check!(),
+             |a, b| {
+                 Ok(AmountOfMoneyValue {
+                     value: b.value().value(),
+                     unit: a.value().unit,
+                     ..AmountOfMoneyValue::default()
+                 })
+             });
+    b.rule_4("entre <amount-of-money> e <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> e <amount-of-money>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"e"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <amount-of-money> a <amount-of-money>",
+             b.reg(r#"de"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"a|at[ée]"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> a <amount-of-money>",
+             b.reg(r#"de"#)?,
+             number_check!(),
+             b.reg(r#"a|at[ée]"#)?,
+             amount_of_money_check!(),
+             |_, a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"-|a"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"-|a"#)?,
+             amount_of_money_check!(),
+             |a, _, b| helpers::money_number_interval(a.value(), b.value())
+    );
+    b.rule_2("menos de <amount-of-money>",
+             b.reg(r#"menos de|no m[áa]ximo|at[ée]|n[ãa]o mais de|abaixo de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<amount-of-money> ou menos",
+             amount_of_money_check!(),
+             b.reg(r#"ou menos|no m[áa]ximo"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("mais de <amount-of-money>",
+             b.reg(r#"mais de|pelo menos|no m[íi]nimo|a partir de|acima de"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::money_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<amount-of-money> ou mais",
+             amount_of_money_check!(),
+             b.reg(r#"ou mais|no m[íi]nimo|\+"#)?,
+             |a, _| helpers::money_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("second (unit-of-duration)",
+                      b.reg(r#"seg(?:undo)?s?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Second))
+    );
+    b.rule_1_terminal("minute (unit-of-duration)",
+                      b.reg(r#"min(?:uto)?s?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Minute))
+    );
+    b.rule_1_terminal("hour (unit-of-duration)",
+                      b.reg(r#"h(?:ora)?s?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("day (unit-of-duration)",
+                      b.reg(r#"dias?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("week (unit-of-duration)",
+                      b.reg(r#"semanas?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("month (unit-of-duration)",
+                      b.reg(r#"m[eê]s(?:es)?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("year (unit-of-duration)",
+                      b.reg(r#"anos?"#)?,
+                      |_| Ok(UnitOfDurationValue::new(Grain::Year))
+    );
+    b.rule_1_terminal("half an hour",
+                      b.reg(r#"meia hora"#)?,
+                      |_| Ok(DurationValue::new(PeriodComp::minutes(30).into()))
+    );
+    b.rule_2("<integer> <unit-of-duration>",
+             integer_check_by_range!(0),
+             unit_of_duration_check!(),
+             |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
+    );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
+    b.rule_3("<integer> <unit-of-duration> e meia",
+             integer_check_by_range!(0),
+             unit_of_duration_check!(),
+             b.reg(r#"e meia|e meio"#)?,
+             |integer, uod, _| Ok(DurationValue::new(helpers::period_from_fraction(integer.value().value as f32 + 0.5, uod.value().grain)?))
+    );
+    b.rule_2("em <duration>",
+             b.reg(r#"em|daqui a|dentro de"#)?,
+             duration_check!(),
+             |_, duration| duration.value().in_present()
+    );
+    b.rule_2("há <duration>",
+             b.reg(r#"h[áa]"#)?,
+             duration_check!(),
+             |_, duration| duration.value().ago()
+    );
+    b.rule_2("<duration> atrás",
+             duration_check!(),
+             b.reg(r#"atr[áa]s"#)?,
+             |duration, _| duration.value().ago()
+    );
+    b.rule_4("entre <duration> e <duration>",
+             b.reg(r#"entre"#)?,
+             duration_check!(),
+             b.reg(r#"e"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("entre <number> e <duration>",
+             b.reg(r#"entre"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"e"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_4("de <duration> a <duration>",
+             b.reg(r#"de"#)?,
+             duration_check!(),
+             b.reg(r#"a|at[ée]"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_4("de <number> a <duration>",
+             b.reg(r#"de"#)?,
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"a|at[ée]"#)?,
+             duration_check!(),
+             |_, a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_3("<duration> - <duration>",
+             duration_check!(),
+             b.reg(r#"-|a|ou"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number> - <duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"-|a|ou"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("pelo menos <duration>",
+             b.reg(r#"pelo menos|no m[íi]nimo|mais de"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration> ou mais",
+             duration_check!(),
+             b.reg(r#"ou mais|no m[íi]nimo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("menos de <duration>",
+             b.reg(r#"menos de|no m[áa]ximo|n[ãa]o mais de"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<duration> ou menos",
+             duration_check!(),
+             b.reg(r#"ou menos|no m[áa]ximo"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("segundo (cycle)",
+                      b.reg(r#"segundos?"#)?,
+                      |_| CycleValue::new(Grain::Second)
+    );
+    b.rule_1_terminal("minuto (cycle)",
+                      b.reg(r#"minutos?"#)?,
+                      |_| CycleValue::new(Grain::Minute)
+    );
+    b.rule_1_terminal("hora (cycle)",
+                      b.reg(r#"horas?"#)?,
+                      |_| CycleValue::new(Grain::Hour)
+    );
+    b.rule_1_terminal("dia (cycle)",
+                      b.reg(r#"dias?"#)?,
+                      |_| CycleValue::new(Grain::Day)
+    );
+    b.rule_1_terminal("semana (cycle)",
+                      b.reg(r#"semanas?"#)?,
+                      |_| CycleValue::new(Grain::Week)
+    );
+    b.rule_1_terminal("mês (cycle)",
+                      b.reg(r#"m[eê]s(?:es)?"#)?,
+                      |_| CycleValue::new(Grain::Month)
+    );
+    b.rule_1_terminal("trimestre (cycle)",
+                      b.reg(r#"trimestres?"#)?,
+                      |_| CycleValue::new(Grain::Quarter)
+    );
+    b.rule_1_terminal("ano (cycle)",
+                      b.reg(r#"anos?"#)?,
+                      |_| CycleValue::new(Grain::Year)
+    );
+    b.rule_2("este <cycle>",
+             b.reg(r#"n?est[ea]s?"#)?,
+             cycle_check!(),
+             |_, cycle| helpers::cycle_nth(cycle.value().grain, 0)
+    );
+    b.rule_3("o <cycle> passado",
+             b.reg(r#"n?[oa]s?"#)?,
+             cycle_check!(),
+             b.reg(r#"passad[oa]s?"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_3("o último <cycle>",
+             b.reg(r#"n?[oa]s?"#)?,
+             b.reg(r#"[úu]ltim[oa]s?"#)?,
+             cycle_check!(),
+             |_, _, cycle| helpers::cycle_nth(cycle.value().grain, -1)
+    );
+    b.rule_3("o <cycle> que vem",
+             b.reg(r#"n?[oa]s?"#)?,
+             cycle_check!(),
+             b.reg(r#"que vem|seguintes?"#)?,
+             |_, cycle, _| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_3("o próximo <cycle>",
+             b.reg(r#"n?[oa]s?"#)?,
+             b.reg(r#"pr[óo]xim[oa]s?"#)?,
+             cycle_check!(),
+             |_, _, cycle| helpers::cycle_nth(cycle.value().grain, 1)
+    );
+    b.rule_4("o <cycle> antes de <time>",
+             b.reg(r#"n?[oa]s?"#)?,
+             cycle_check!(),
+             b.reg(r#"antes d[eoa]"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, -1, time.value())
+    );
+    b.rule_4("o <cycle> depois de <time>",
+             b.reg(r#"n?[oa]s?"#)?,
+             cycle_check!(),
+             b.reg(r#"depois d[eoa]"#)?,
+             time_check!(),
+             |_, cycle, _, time| helpers::cycle_nth_after(cycle.value().grain, 1, time.value())
+    );
+    b.rule_3("últimos n <cycle>",
+             b.reg(r#"(?:n?[oa]s )?[úu]ltim[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, -1 * integer.value().value)
+    );
+    b.rule_3("próximos n <cycle>",
+             b.reg(r#"(?:n?[oa]s )?pr[óo]xim[oa]s"#)?,
+             integer_check_by_range!(2, 9999),
+             cycle_check!(),
+             |_, integer, cycle| helpers::cycle_n_not_immediate(cycle.value().grain, integer.value().value)
+    );
+    b.rule_2("<ordinal> quarter",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             |ordinal, _| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 &helpers::cycle_nth(Grain::Year, 0)?
+             )
+    );
+    b.rule_4("<ordinal> quarter <year>",
+             ordinal_check!(),
+             cycle_check!(|cycle: &CycleValue| cycle.grain == Grain::Quarter),
+             b.reg(r#"de"#)?,
+             time_check!(),
+             |ordinal, _, _, time| helpers::cycle_nth_after(
+                 Grain::Quarter,
+                 ordinal.value().value - 1,
+                 time.value()
+             )
+    );
+    Ok(())
+}
+
+pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect",
+             time_check!(|time: &TimeValue| !time.latent),
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, b| a.value().intersect(b.value())
+    );
+    b.rule_3("intersect by `de`",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"de"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_3("two time tokens separated by \",\"",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#","#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().intersect(b.value())
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"segunda(?:-feira| feira)?|seg\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Mon)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"ter[çc]a(?:-feira| feira)?|ter\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Tue)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"quarta(?:-feira| feira)?|qua\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Wed)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"quinta(?:-feira| feira)?|qui\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Thu)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"sexta(?:-feira| feira)?|sex\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Fri)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"s[áa]bados?|s[áa]b\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Sat)
+    );
+    b.rule_1_terminal("named-day",
+                      b.reg(r#"domingos?|dom\.?"#)?,
+                      |_| helpers::day_of_week(Weekday::Sun)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"janeiro|jan\.?"#)?,
+                      |_| helpers::month(1)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"fevereiro|fev\.?"#)?,
+                      |_| helpers::month(2)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"mar[çc]o|mar\.?"#)?,
+                      |_| helpers::month(3)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"abril|abr\.?"#)?,
+                      |_| helpers::month(4)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"maio|mai\.?"#)?,
+                      |_| helpers::month(5)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"junho|jun\.?"#)?,
+                      |_| helpers::month(6)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"julho|jul\.?"#)?,
+                      |_| helpers::month(7)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"agosto|ago\.?"#)?,
+                      |_| helpers::month(8)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"setembro|set\.?"#)?,
+                      |_| helpers::month(9)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"outubro|out\.?"#)?,
+                      |_| helpers::month(10)
+    );
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"novembro|nov\.?"#)?,
+                      |_| helpers::month(11)
+    );
+    // "dez" alone is the number ten
+    b.rule_1_terminal("named-month",
+                      b.reg(r#"dezembro|dez\."#)?,
+                      |_| helpers::month(12)
+    );
+    b.rule_1_terminal("Natal",
+                      b.reg(r#"(?:o )?natal"#)?,
+                      |_| helpers::month_day(12, 25)
+    );
+    b.rule_1_terminal("véspera de Natal",
+                      b.reg(r#"v[ée]spera de natal"#)?,
+                      |_| helpers::month_day(12, 24)
+    );
+    b.rule_1_terminal("réveillon",
+                      b.reg(r#"r[ée]veillon|v[ée]spera de ano novo"#)?,
+                      |_| helpers::month_day(12, 31)
+    );
+    b.rule_1_terminal("ano novo",
+                      b.reg(r#"(?:o )?ano novo"#)?,
+                      |_| helpers::month_day(1, 1)
+    );
+    b.rule_1_terminal("right now",
+                      b.reg(r#"agora(?: mesmo)?|j[áa]|imediatamente"#)?,
+                      |_| helpers::cycle_nth(Grain::Second, 0)
+    );
+    b.rule_1_terminal("now",
+                      b.reg(r#"hoje|neste momento"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 0)
+    );
+    b.rule_1_terminal("tomorrow",
+                      b.reg(r#"amanh[ãa]"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 1)
+    );
+    b.rule_1_terminal("yesterday",
+                      b.reg(r#"ontem"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -1)
+    );
+    b.rule_1_terminal("the day after tomorrow",
+                      b.reg(r#"depois de amanh[ãa]"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 2)
+    );
+    b.rule_1_terminal("the day before yesterday",
+                      b.reg(r#"anteontem|antes de ontem"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, -2)
+    );
+    b.rule_2("this <day-of-week>", //assumed to be in the future
+             b.reg(r#"n?est[ea]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("this <time>",
+             b.reg(r#"n?est[ea]"#)?,
+             time_check!(),
+             |_, time| time.value().the_nth(0)
+    );
+    b.rule_2("próximo <day-of-week>",
+             b.reg(r#"(?:n?[oa] )?pr[óo]xim[oa]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, time| time.value().the_nth_not_immediate(0)
+    );
+    b.rule_2("<named-month|named-day> next",
+             time_check!(),
+             b.reg(r#"que vem|seguinte"#)?,
+             |time, _| time.value().the_nth(1)
+    );
+    b.rule_2("<named-month|named-day> past",
+             time_check!(),
+             b.reg(r#"passad[oa]"#)?,
+             |time, _| time.value().the_nth(-1)
+    );
+    b.rule_1("year",
+             integer_check_by_range!(1000, 2100),
+             |integer| {
+                 helpers::year(integer.value().value as i32)
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(-1000, 999),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1("year (latent)",
+             integer_check_by_range!(2101, 2200),
+             |integer| {
+                 Ok(helpers::year(integer.value().value as i32)?.latent())
+             }
+    );
+    b.rule_1_terminal("day of month (1st)",
+                      b.reg(r#"primeiro|1[ºo]"#)?,
+                      |_| helpers::day_of_month(1)
+    );
+    b.rule_2("dia <day-of-month>",
+             b.reg(r#"(?:n?o )?dia"#)?,
+             integer_check_by_range!(1, 31),
+             |_, integer| helpers::day_of_month(integer.value().value as u32)
+    );
+    b.rule_3("<day-of-month> de <named-month>",
+             integer_check_by_range!(1, 31),
+             b.reg(r#"de"#)?,
+             time_check!(form!(Form::Month(_))),
+             |integer, _, month| month.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_4("último <day-of-week> de <time>",
+             b.reg(r#"(?:n?[oa] )?[úu]ltim[oa]"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             b.reg(r#"de|d[oa]|em|n[oa]"#)?,
+             time_check!(),
+             |_, dow, _, time| dow.value().last_of(time.value())
+    );
+    b.rule_4("nth <time> de <time>",
+             ordinal_check!(),
+             time_check!(),
+             b.reg(r#"de|d[oa]|em|n[oa]"#)?,
+             time_check!(),
+             |ordinal, a, _, b| b.value().intersect(a.value())?.the_nth(ordinal.value().value - 1)
+    );
+    b.rule_2("<day-of-week> <day-of-month>",
+             time_check!(form!(Form::DayOfWeek{..})),
+             integer_check_by_range!(1, 31),
+             |dow, integer| dow.value().intersect(&helpers::day_of_month(integer.value().value as u32)?)
+    );
+    b.rule_1("time-of-day (latent)",
+             integer_check_by_range!(0, 23),
+             |integer| Ok(helpers::hour(integer.value().value as u32, true)?.latent())
+    );
+    b.rule_1_terminal("noon",
+                      b.reg(r#"meio[- ]dia"#)?,
+                      |_| helpers::hour(12, false)
+    );
+    b.rule_1_terminal("midnight",
+                      b.reg(r#"meia[- ]noite"#)?,
+                      |_| helpers::hour(0, false)
+    );
+    b.rule_2("<time-of-day> horas",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"h\.?(?:oras?)?"#)?,
+             |time, _| Ok(time.value().clone().not_latent())
+    );
+    b.rule_2("às <time-of-day>",
+             b.reg(r#"[àa]s?|pel[oa]s?|ao"#)?,
+             time_check!(form!(Form::TimeOfDay(_))),
+             |_, tod| Ok(tod.value().clone().not_latent())
+    );
+    b.rule_1_terminal("hh(:|.|h)mm (time-of-day)",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:h\.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          true
+                      )
+    );
+    b.rule_1_terminal("hh:mm:ss",
+                      b.reg(r#"((?:[01]?\d)|(?:2[0-3]))[:.]([0-5]\d)[:.]([0-5]\d)"#)?,
+                      |text_match| helpers::hour_minute_second(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?,
+                          true
+                      )
+    );
+    b.rule_1_terminal("quarter (relative minutes)",
+                      b.reg(r#"(?:um )?quarto"#)?,
+                      |_| Ok(RelativeMinuteValue(15))
+    );
+    b.rule_1_terminal("half (relative minutes)",
+                      b.reg(r#"meia"#)?,
+                      |_| Ok(RelativeMinuteValue(30))
+    );
+    b.rule_1("number (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             |integer| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_2("<integer> minutes (as relative minutes)",
+             integer_check_by_range!(1, 59),
+             b.reg(r#"min\.?(?:uto)?s?"#)?,
+             |integer, _| Ok(RelativeMinuteValue(integer.value().value as i32))
+    );
+    b.rule_3("<hour-of-day> and <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"e"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<hour-of-day> minus <relative minutes>",
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             b.reg(r#"menos"#)?,
+             relative_minute_check!(),
+             |time, _, relative_minute| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_3("<relative minutes> para as <hour-of-day>",
+             relative_minute_check!(),
+             b.reg(r#"para (?:[àa]s|a|o)"#)?,
+             time_check!(form!(Form::TimeOfDay(TimeOfDayForm::Hour { .. }))),
+             |relative_minute, _, time| helpers::hour_relative_minute(
+                 time.value().form_time_of_day()?.full_hour(),
+                 -1 * relative_minute.value().0,
+                 time.value().form_time_of_day()?.is_12_clock())
+    );
+    b.rule_2("<time-of-day> da manhã",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da manh[ãa]"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(12, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> da tarde",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da tarde"#)?,
+             |time, _| {
+                 let period = helpers::hour(12, false)?
+                     .span_to(&helpers::hour(21, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> da noite",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da noite"#)?,
+             |time, _| {
+                 let period = helpers::hour(18, false)?
+                     .span_to(&helpers::hour(0, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_2("<time-of-day> da madrugada",
+             time_check!(form!(Form::TimeOfDay(_))),
+             b.reg(r#"da madrugada"#)?,
+             |time, _| {
+                 let period = helpers::hour(0, false)?
+                     .span_to(&helpers::hour(6, false)?, false)?;
+                 time.value().intersect(&period)
+             }
+    );
+    b.rule_1_terminal("dd[/-.]mm[/-.]yyyy",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[-/.](3[01]|[12]\d|0?[1-9])[-/.](\d{2,4})"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          Some(text_match.group(3).parse()?),
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_1_terminal("yyyy-mm-dd",
+                      b.reg(r#"(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::ymd(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?
+                      )
+    );
+    b.rule_1_terminal("dd[/-]mm",
+                      b.reg(r#"(3[01]|[12]\d|0?[1-9])[/-](3[01]|[12]\d|0?[1-9])"#)?,
+                      |text_match| helpers::numeric_date(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          None,
+                          DateOrder::DayMonth
+                      )
+    );
+    b.rule_1_terminal("morning",
+                      b.reg(r#"manh[ãa]"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
+    );
+    b.rule_1_terminal("afternoon",
+                      b.reg(r#"tarde"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
+    );
+    b.rule_1_terminal("evening",
+                      b.reg(r#"noite"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
+    );
+    b.rule_1_terminal("night",
+                      b.reg(r#"madrugada"#)?,
+                      |_| Ok(helpers::part_of_day(PartOfDayForm::Night, 0, 6)?.latent())
+    );
+    b.rule_2("in the <part-of-day>",
+             b.reg(r#"de|[àa]|pela|na|durante a"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(pod.value().clone().not_latent())
+    );
+    b.rule_2("this <part-of-day>",
+             b.reg(r#"n?esta"#)?,
+             time_check!(form!(Form::PartOfDay(_))),
+             |_, pod| Ok(helpers::cycle_nth(Grain::Day, 0)?
+                 .intersect(pod.value())?
+                 .form(pod.value().form.clone()))
+    );
+    b.rule_2("<time> <part-of-day>",
+             time_check!(),
+             time_check!(|time: &TimeValue| !time.latent && form!(Form::PartOfDay(_))(time)),
+             |time, pod| time.value().intersect(pod.value())
+    );
+    b.rule_1_terminal("week-end",
+                      b.reg(r#"(?:fim|final) de semana|fds"#)?,
+                      |_| helpers::weekend(true)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"ver[ãa]o"#)?,
+                      |_| helpers::month_day(6, 21)?
+                          .span_to(&helpers::month_day(9, 23)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"outono"#)?,
+                      |_| helpers::month_day(9, 23)?
+                          .span_to(&helpers::month_day(12, 21)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"inverno"#)?,
+                      |_| helpers::month_day(12, 21)?
+                          .span_to(&helpers::month_day(3, 20)?, false)
+    );
+    b.rule_1_terminal("season",
+                      b.reg(r#"primavera"#)?,
+                      |_| helpers::month_day(3, 20)?
+                          .span_to(&helpers::month_day(6, 21)?, false)
+    );
+    b.rule_2("o <time>",
+             b.reg(r#"n?[oa]|d[oa]"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |_, time| Ok(time.value().clone())
+    );
+    b.rule_5("dd-dd <month>(interval)",
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"\-|a|at[ée]"#)?,
+             b.reg(r#"(3[01]|[12]\d|0?[1-9])"#)?,
+             b.reg(r#"de"#)?,
+             time_check!(form!(Form::Month(_))),
+             |a, _, b, _, month| {
+                 let start = month.value().intersect(&helpers::day_of_month(a.group(1).parse()?)?)?;
+                 let end = month.value().intersect(&helpers::day_of_month(b.group(1).parse()?)?)?;
+                 start.span_to(&end, true)
+             }
+    );
+    b.rule_3("<datetime> - <datetime> (interval)",
+             time_check!(|time: &TimeValue| !time.latent),
+             b.reg(r#"\-|a|at[ée]"#)?,
+             time_check!(|time: &TimeValue| !time.latent),
+             |a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("de <datetime> até <datetime> (interval)",
+             b.reg(r#"d[eoa]s?"#)?,
+             time_check!(),
+             b.reg(r#"\-|a|at[ée]|[àa]s"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    b.rule_4("entre <datetime> e <datetime> (interval)",
+             b.reg(r#"entre"#)?,
+             time_check!(),
+             b.reg(r#"e"#)?,
+             time_check!(),
+             |_, a, _, b| a.value().span_to(b.value(), false)
+    );
+    Ok(())
+}
+
+pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("number as temp", number_check!(), |a| {
+        Ok(TemperatureValue {
+            value: a.value().value(),
+            unit: None,
+            latent: true,
+            ..TemperatureValue::default()
+        })
+    });
+    b.rule_2("<latent temp> temp",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"graus?|°"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Degree),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Celsius",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"cent[íi]grados?|c(?:el[cs]ius)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Celsius),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<temp> Fahrenheit",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"f(?:ahrenheit)?\.?"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: a.value().value,
+                     unit: Some(TemperatureUnit::Fahrenheit),
+                     latent: false,
+                     ..TemperatureValue::default()
+                 })
+             });
+    b.rule_2("<latent temp> temp abaixo de zero",
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
+             b.reg(r#"(?:graus? |°)?abaixo de zero"#)?,
+             |a, _| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+    b.rule_1_terminal("abaixo de zero",
+                      b.reg(r#"abaixo de zero"#)?,
+                      |_| Ok(TemperatureValue {
+                          value: 0.0,
+                          unit: None,
+                          direction: Some(Direction::Before),
+                          ..TemperatureValue::default()
+                      })
+    );
+    b.rule_4("entre <temp> e <temp>",
+             b.reg(r#"entre"#)?,
+             temperature_check!(),
+             b.reg(r#"e"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_4("de <temp> a <temp>",
+             b.reg(r#"de"#)?,
+             temperature_check!(),
+             b.reg(r#"a|at[ée]"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_3("<temp> - <temp>",
+             temperature_check!(),
+             b.reg(r#"-|a"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+    b.rule_2("menos de <temp>",
+             b.reg(r#"menos de|no m[áa]ximo|at[ée]|abaixo de"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("<temp> ou menos",
+             temperature_check!(),
+             b.reg(r#"ou menos|no m[áa]ximo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("mais de <temp>",
+             b.reg(r#"mais de|pelo menos|no m[íi]nimo|acima de"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<temp> ou mais",
+             temperature_check!(),
+             b.reg(r#"ou mais|no m[íi]nimo"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+    Ok(())
+}
+
+pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("intersect",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             number_check!(),
+             |a, b| helpers::compose_numbers(&a.value(), &b.value()));
+    b.rule_3("intersect (with e)",
+             number_check!(|number: &NumberValue| number.grain().unwrap_or(0) > 1),
+             b.reg(r#"e"#)?,
+             number_check!(),
+             |a, _, b| helpers::compose_numbers(&a.value(), &b.value()));
+    b.rule_1_terminal("number (0..15)",
+                      b.reg(r#"(zero|uma?|dois|duas|tr[êe]s|quatro|cinco|seis|sete|oito|nove|dez|onze|doze|treze|quatorze|catorze|quinze)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "zero" => 0,
+                              "um" => 1,
+                              "uma" => 1,
+                              "dois" => 2,
+                              "duas" => 2,
+                              "três" => 3,
+                              "tres" => 3,
+                              "quatro" => 4,
+                              "cinco" => 5,
+                              "seis" => 6,
+                              "sete" => 7,
+                              "oito" => 8,
+                              "nove" => 9,
+                              "dez" => 10,
+                              "onze" => 11,
+                              "doze" => 12,
+                              "treze" => 13,
+                              "quatorze" => 14,
+                              "catorze" => 14,
+                              "quinze" => 15,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      }
+    );
+    // "dezesseis" in Brazil, "dezasseis" in Portugal
+    b.rule_1_terminal("number (16..19)",
+                      b.reg(r#"(dez[ea]sseis|dez[ea]ssete|dezoito|dez[ea]nove)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "dezesseis" | "dezasseis" => 16,
+                              "dezessete" | "dezassete" => 17,
+                              "dezoito" => 18,
+                              "dezenove" | "dezanove" => 19,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      }
+    );
+    b.rule_1_terminal("number (20..90)",
+                      b.reg(r#"(vinte|trinta|quarenta|cinq[uü]enta|sessenta|setenta|oitenta|noventa)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "vinte" => 20,
+                              "trinta" => 30,
+                              "quarenta" => 40,
+                              "cinquenta" => 50,
+                              "cinqüenta" => 50,
+                              "sessenta" => 60,
+                              "setenta" => 70,
+                              "oitenta" => 80,
+                              "noventa" => 90,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new(value)
+                      });
+    b.rule_3("number (21..29 31..39 41..49 51..59 61..69 71..79 81..89 91..99)",
+             integer_check_by_range!(20, 90, |integer: &IntegerValue| integer.value % 10 == 0),
+             b.reg(r#"e"#)?,
+             integer_check_by_range!(1, 9),
+             |a, _, b| IntegerValue::new(a.value().value + b.value().value));
+    b.rule_1_terminal("hundreds",
+                      b.reg(r#"(cento|cem|duzent[oa]s|trezent[oa]s|quatrocent[oa]s|quinhent[oa]s|seiscent[oa]s|setecent[oa]s|oitocent[oa]s|novecent[oa]s)"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "cento" | "cem" => 100,
+                              "duzentos" | "duzentas" => 200,
+                              "trezentos" | "trezentas" => 300,
+                              "quatrocentos" | "quatrocentas" => 400,
+                              "quinhentos" | "quinhentas" => 500,
+                              "seiscentos" | "seiscentas" => 600,
+                              "setecentos" | "setecentas" => 700,
+                              "oitocentos" | "oitocentas" => 800,
+                              "novecentos" | "novecentas" => 900,
+                              _ => return Err(RuleErrorKind::Invalid.into()),
+                          };
+                          IntegerValue::new_with_grain(value, 2)
+                      });
+    b.rule_1_terminal("thousand",
+        b.reg(r#"mil"#)?,
+        |_| IntegerValue::new_with_grain(1000, 3)
+    );
+    b.rule_1_terminal("million",
+        b.reg(r#"milh[ãa]o"#)?,
+        |_| IntegerValue::new_with_grain(1000000, 6)
+    );
+    b.rule_2("number thousands",
+        integer_check_by_range!(2, 999),
+        b.reg(r#"mil"#)?,
+        |a, _| {
+            Ok(IntegerValue {
+                   value: a.value().value * 1000,
+                   grain: Some(3),
+                   ..IntegerValue::default()
+               })
+    });
+    b.rule_2("number millions",
+        integer_check_by_range!(1, 999),
+        b.reg(r#"milh(?:[ãa]o|[õo]es)"#)?,
+        |a, _| {
+            Ok(IntegerValue {
+                   value: a.value().value * 1000000,
+                   grain: Some(6),
+                   ..IntegerValue::default()
+               })
+    });
+    b.rule_1_terminal("integer (numeric)",
+                      b.reg(r#"(\d{1,18})"#)?,
+                      |text_match| IntegerValue::new(text_match.group(0).parse()?));
+    b.rule_1_terminal("integer with thousands separator .",
+                      b.reg(r#"(\d{1,3}(\.\d\d\d){1,5})"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(".", "");
+                          let value: i64 = reformatted_string.parse()?;
+                          IntegerValue::new(value)
+                      });
+    b.rule_1_terminal("decimal number",
+                      b.reg(r#"(\d*,\d+)"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(",", ".");
+                          let value: f32 = reformatted_string.parse()?;
+                          FloatValue::new(value)
+                      });
+    b.rule_1_terminal("decimal with thousands separator",
+                      b.reg(r#"(\d+(\.\d\d\d)+,\d+)"#)?,
+                      |text_match| {
+                          let reformatted_string = text_match.group(1).replace(".", "").replace(",", ".");
+                          let value: f32 = reformatted_string.parse()?;
+                          FloatValue::new(value)
+                      });
+    b.rule_3("number comma number",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             b.reg(r#"v[íi]rgula"#)?,
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             |a, _, b| {
+                 Ok(FloatValue {
+                     value: b.value().value() * 0.1 + a.value().value(),
+                     ..FloatValue::default()
+                 })
+             });
+    b.rule_2("<integer> <fraction denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(ter[çc]os?|quartos?|quintos?|sextos?|s[ée]timos?|oitavos?|nonos?|d[ée]cimos?)"#)?,
+             |integer, text_match| FloatValue::new_fraction(integer.value().value, fraction_denominator(text_match.group(1))?)
+    );
+    b.rule_2("<integer> e meio",
+             integer_check_by_range!(0, 99),
+             b.reg(r#"e (mei[oa]|um ter[çc]o|um quarto)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "meio" | "meia" => 2,
+                     "um terço" | "um terco" => 3,
+                     "um quarto" => 4,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 helpers::compose_mixed_fraction(integer.value().value, &FloatValue::new_fraction(1, denominator)?)
+             });
+    b.rule_1_terminal("fraction (numeric)",
+                      b.reg(r#"(\d{1,4}) ?/ ?(\d{1,4})"#)?,
+                      |text_match| FloatValue::new_fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)
+    );
+    b.rule_1_terminal("mixed fraction (numeric)",
+                      b.reg(r#"(\d{1,6}) (\d{1,4})/(\d{1,4})"#)?,
+                      |text_match| helpers::compose_mixed_fraction(
+                          text_match.group(1).parse()?,
+                          &FloatValue::new_fraction(text_match.group(2).parse()?, text_match.group(3).parse()?)?)
+    );
+    b.rule_2("numbers prefix with -, negative or minus",
+             b.reg(r#"-|menos"#)?,
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             |_, a| -> RuleResult<NumberValue> {
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * -1,
+                             prefixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         FloatValue {
+                             value: float.value * -1.0,
+                             prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
+                             ..float
+                         }
+                             .into()
+                     }
+                 })
+             });
+    b.rule_2("numbers suffixes (K, M, G)",
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             b.reg_neg_lh(r#"([kmg])"#, r#"^[\W\$€]"#)?,
+             |a, text_match| -> RuleResult<NumberValue> {
+                 let multiplier = match text_match.group(0).as_ref() {
+                     "k" => 1000,
+                     "m" => 1000000,
+                     "g" => 1000000000,
+                     _ => return Err(RuleErrorKind::Invalid.into()),
+                 };
+                 Ok(match a.value().clone() {
+                     // checked
+                     NumberValue::Integer(integer) => {
+                         IntegerValue {
+                             value: integer.value * multiplier,
+                             suffixed: true,
+                             ..integer
+                         }
+                             .into()
+                     }
+                     NumberValue::Float(float) => {
+                         let product = float.value * (multiplier as f32);
+                         if product.floor() == product {
+                             IntegerValue {
+                                 value: product as i64,
+                                 suffixed: true,
+                                 ..IntegerValue::default()
+                             }
+                                 .into()
+                         } else {
+                             FloatValue {
+                                 value: product,
+                                 suffixed: true,
+                                 ..float
+                             }
+                                 .into()
+                         }
+                     }
+                 })
+             });
+    b.rule_1_terminal("ordinals (primeiro..décimo)",
+                      b.reg(r#"(primeir|segund|terceir|quart|quint|sext|s[ée]tim|oitav|non|d[ée]cim)[oa]s?"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "primeir" => 1,
+                              "segund" => 2,
+                              "terceir" => 3,
+                              "quart" => 4,
+                              "quint" => 5,
+                              "sext" => 6,
+                              "sétim" | "setim" => 7,
+                              "oitav" => 8,
+                              "non" => 9,
+                              "décim" | "decim" => 10,
+                              _ => return Err(RuleErrorKind::Invalid.into())
+                          };
+                          Ok(OrdinalValue::new(value))
+                      });
+    b.rule_1_terminal("ordinal (digits)",
+                      b.reg(r#"(\d+) ?[ºª]"#)?,
+                      |text_match| Ok(OrdinalValue::new(text_match.group(1).parse()?))
+    );
+    Ok(())
+}
+
+fn fraction_denominator(word: &str) -> RuleResult<i64> {
+    match word.trim_right_matches('s') {
+        "terço" | "terco" => Ok(3),
+        "quarto" => Ok(4),
+        "quinto" => Ok(5),
+        "sexto" => Ok(6),
+        "sétimo" | "setimo" => Ok(7),
+        "oitavo" => Ok(8),
+        "nono" => Ok(9),
+        "décimo" | "decimo" => Ok(10),
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
@@ -0,0 +1,144 @@
+use rustling_ontology_values::check::*;
+use rustling_ontology_moment::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::ResolverContext;
+
+pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_finance_interval(Some(10.0), Some(20.0), Some("EUR")), "entre 10 e 20 euros", "10-20 euros", "de 10€ a 20€");
+    example!(v, check_finance_interval(Some(100.0), None, Some("EUR")), "mais de 100 euros", "pelo menos cem euros");
+    example!(v, check_finance_interval(None, Some(50.0), Some("$")), "menos de 50 dólares", "no máximo $50");
+    example!(v, check_finance(10.0, Some("$"), Precision::Exact), "$10", "10$", "dez dólares");
+    example!(v, check_finance(20.0, Some("EUR"), Precision::Exact), "20€", "20 euros", "vinte euros");
+    example!(v, check_finance(15.0, Some("BRL"), Precision::Exact), "R$15", "R$ 15", "15 reais", "quinze reais");
+    example!(v, check_finance(3.5, Some("BRL"), Precision::Exact), "3 reais e 50 centavos", "três reais e cinquenta centavos");
+    example!(v, check_finance(9.0, Some("£"), Precision::Exact), "£9", "nove libras");
+    example!(v, check_finance(10000.0, Some("GBP"), Precision::Exact), "10.000 libras esterlinas");
+}
+
+pub fn examples_duration(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration!([0, 0, 0, 0, 2]), "2 horas", "duas horas");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 30]), "meia hora", "30 minutos");
+    example!(v, check_duration!([0, 0, 0, 3]), "três dias");
+    example!(v, check_duration_interval(Some(PeriodComp::days(2).into()), Some(PeriodComp::days(3).into())), "entre dois e três dias", "de 2 a 3 dias", "2-3 dias");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "pelo menos 20 minutos", "20 minutos ou mais");
+    example!(v, check_duration_interval(None, Some(PeriodComp::weeks(1).into())), "menos de uma semana", "1 semana ou menos");
+}
+
+pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::new(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "agora", "já", "agora mesmo", "imediatamente");
+    example!(v, check_moment!(c, [2013, 2, 12]), "hoje");
+    example!(v, check_moment!(c, [2013, 2, 11]), "ontem");
+    example!(v, check_moment!(c, [2013, 2, 10]), "anteontem", "antes de ontem");
+    example!(v, check_moment!(c, [2013, 2, 13]), "amanhã");
+    example!(v, check_moment!(c, [2013, 2, 14]), "depois de amanhã");
+    example!(v, check_moment!(c, [2013, 2, 18]), "segunda-feira", "segunda", "seg.", "esta segunda");
+    example!(v, check_moment!(c, [2013, 2, 18]), "segunda, 18 de fevereiro");
+    example!(v, check_moment!(c, [2013, 2, 19]), "terça-feira", "terça que vem");
+    example!(v, check_moment!(c, [2013, 2, 13]), "quarta-feira", "quarta feira", "qua.", "próxima quarta");
+    example!(v, check_moment!(c, [2013, 2, 14]), "quinta-feira");
+    example!(v, check_moment!(c, [2013, 2, 15]), "sexta-feira");
+    example!(v, check_moment!(c, [2013, 2, 16]), "sábado");
+    example!(v, check_moment!(c, [2013, 2, 17]), "domingo");
+    example!(v, check_moment!(c, [2013, 2, 10]), "domingo passado");
+    example!(v, check_moment!(c, [2013, 5, 5]), "5 de maio", "cinco de maio", "o dia 5 de maio");
+    example!(v, check_moment!(c, [2013, 5, 5]), "cinco de maio de 2013", "5/5/2013");
+    example!(v, check_moment!(c, [2013, 7, 4]), "4 de julho", "4/7");
+    example!(v, check_moment!(c, [2013, 3, 1]), "1 de março", "primeiro de março", "1º de março", "1/3/2013", "1.3.2013");
+    example!(v, check_moment!(c, [2013, 12, 24]), "24 de dez.", "24 de dezembro");
+    example!(v, check_moment!(c, [2013, 2, 20]), "dia 20", "no dia 20", "20 de fevereiro", "20/2");
+    example!(v, check_moment!(c, [1974, 10, 31]), "31/10/1974", "31/10/74");
+    example!(v, check_moment!(c, [2014, 9, 12]), "sexta-feira, 12 de setembro de 2014");
+    // Cycles
+    example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "esta semana", "nesta semana");
+    example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "a semana passada", "a última semana");
+    example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "a semana que vem", "a próxima semana");
+    example!(v, check_moment!(c, [2013, 1]), "o mês passado");
+    example!(v, check_moment!(c, [2013, 3]), "o mês que vem", "o próximo mês");
+    example!(v, check_moment!(c, [2012]), "o ano passado");
+    example!(v, check_moment!(c, [2013]), "este ano");
+    example!(v, check_moment!(c, [2014]), "o ano que vem", "o próximo ano");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "segundo trimestre");
+    // Hours
+    example!(v, check_moment!(c, [2013, 2, 12, 15]), "às três da tarde", "às 15 horas");
+    example!(v, check_moment!(c, [2013, 2, 12, 20]), "às oito da noite");
+    example!(v, check_moment!(c, [2013, 2, 13, 3]), "às três da madrugada", "três da manhã");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0]), "15:00", "15.00");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 0, 10]), "15:00:10");
+    example!(v, check_moment!(c, [2013, 2, 13, 0]), "meia-noite");
+    example!(v, check_moment!(c, [2013, 2, 12, 12]), "meio-dia", "ao meio-dia");
+    example!(v, check_moment!(c, [2013, 2, 12, 12, 30]), "meio-dia e meia");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 15]), "às três e quinze da tarde", "às 3 e um quarto da tarde", "15:15", "15h15");
+    example!(v, check_moment!(c, [2013, 2, 12, 15, 30]), "às três e meia da tarde", "15:30", "15h30");
+    example!(v, check_moment!(c, [2013, 2, 12, 14, 45]), "quinze para as três da tarde", "às três menos um quarto da tarde", "14:45");
+    example!(v, check_moment!(c, [2013, 2, 13, 11]), "amanhã às onze", "quarta às 11 da manhã");
+    // Durations from now
+    example!(v, check_moment!(c, [2013, 2, 12, 4, 31, 0]), "em um minuto", "daqui a 1 minuto");
+    example!(v, check_moment!(c, [2013, 2, 12, 5, 30]), "em uma hora", "daqui a uma hora");
+    example!(v, check_moment!(c, [2013, 2, 12, 2, 30]), "há duas horas", "duas horas atrás");
+    example!(v, check_moment!(c, [2013, 2, 19]), "em 7 dias", "daqui a uma semana");
+    example!(v, check_moment!(c, [2013, 1, 22]), "há três semanas");
+    example!(v, check_moment!(c, [2013, 4]), "em dois meses");
+    example!(v, check_moment!(c, [2011, 2]), "há dois anos");
+    // Holidays and seasons
+    example!(v, check_moment!(c, [2013, 12, 25]), "natal", "o natal");
+    example!(v, check_moment!(c, [2013, 12, 31]), "réveillon");
+    example!(v, check_moment!(c, [2014, 1, 1]), "ano novo");
+    example!(v, check_moment_span!(c, [2013, 6, 21], [2013, 9, 24]), "este verão");
+    // Part of day
+    example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 00]), "esta noite", "hoje à noite");
+    example!(v, check_moment_span!(c, [2013, 2, 13, 4], [2013, 2, 13, 12]), "amanhã de manhã", "amanhã pela manhã");
+    example!(v, check_moment_span!(c, [2013, 2, 11, 12], [2013, 2, 11, 19]), "ontem à tarde");
+    example!(v, check_moment_span!(c, [2013, 2, 15, 18], [2013, 2, 18, 00]), "este fim de semana", "este final de semana");
+    // Intervals
+    example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "próximos 3 segundos");
+    example!(v, check_moment_span!(c, [2013, 2, 10], [2013, 2, 12]), "últimos 2 dias", "os últimos dois dias");
+    example!(v, check_moment_span!(c, [2013, 2, 13], [2013, 2, 16]), "próximos 3 dias", "os próximos três dias");
+    example!(v, check_moment_span!(c, [2013, 7, 13], [2013, 7, 16]), "13 a 15 de julho", "13-15 de julho");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9, 30], [2013, 2, 12, 11]), "9:30 - 11:00", "das 9:30 às 11:00", "entre 9:30 e 11:00");
+}
+
+pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(0), "0", "zero");
+    example!(v, check_integer(1), "1", "um", "uma");
+    example!(v, check_integer(2), "dois", "duas");
+    example!(v, check_integer(14), "catorze", "quatorze");
+    example!(v, check_integer(16), "dezesseis", "dezasseis");
+    example!(v, check_integer(19), "dezenove", "dezanove");
+    example!(v, check_integer(21), "vinte e um", "vinte e uma");
+    example!(v, check_integer(33), "33", "trinta e três", "trinta e 3");
+    example!(v, check_integer(50), "cinquenta", "cinqüenta");
+    example!(v, check_integer(100), "cem", "100");
+    example!(v, check_integer(123), "cento e vinte e três");
+    example!(v, check_integer(300), "trezentos", "trezentas");
+    example!(v, check_integer(1000), "mil", "1.000");
+    example!(v, check_integer(1980), "mil novecentos e oitenta");
+    example!(v, check_integer(2020), "dois mil e vinte");
+    example!(v, check_integer(35000), "trinta e cinco mil");
+    example!(v, check_integer(200000), "duzentos mil", "200.000", "200k");
+    example!(v, check_integer(3000000), "três milhões", "3M", "3.000.000");
+    example!(v, check_integer(1000000), "um milhão");
+    example!(v, check_integer(-1200000), "- 1.200.000", "-1200000", "menos 1.200.000", "-1,2M");
+    example!(v, check_float(1.5), "1,5", "um vírgula cinco");
+    example!(v, check_float(0.77), "0,77", ",77");
+    example!(v, check_fraction(2, 3), "dois terços", "2/3");
+    example!(v, check_fraction(3, 2), "um e meio", "1 1/2");
+    example!(v, check_ordinal(1), "primeiro", "primeira", "1º");
+    example!(v, check_ordinal(3), "terceiro", "terceira", "3ª");
+    example!(v, check_ordinal(7), "sétimo", "sétima");
+}
+
+pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_temperature(30.0, Some(TemperatureUnit::Degree)), "30 graus", "trinta graus", "30°");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "menos cinco graus", "-5°", "5 graus abaixo de zero");
+    example!(v, check_temperature(25.0, Some(TemperatureUnit::Celsius)), "25°C", "25 graus celsius", "vinte e cinco graus centígrados");
+    example!(v, check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)), "70°F", "setenta graus fahrenheit");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "entre 18 e 22 graus", "de 18 a 22 graus", "18-22°");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "mais de 30 graus", "30 graus ou mais");
+    example!(v, check_temperature_interval(None, Some(0.0), None), "abaixo de zero");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_email("joao.silva@example.com"), "joao.silva@example.com");
+    example!(v, check_url("https://www.example.com.br/path?q=1", "example.com.br"), "https://www.example.com.br/path?q=1");
+}
@@ -4,12 +4,18 @@ extern crate rustling_ontology_de as de;
 extern crate rustling_ontology_en as en;
 extern crate rustling_ontology_es as es;
 extern crate rustling_ontology_fr as fr;
+extern crate rustling_ontology_it as it;
 extern crate rustling_ontology_ja as ja;
 extern crate rustling_ontology_ko as ko;
+extern crate rustling_ontology_nl as nl;
+extern crate rustling_ontology_pt as pt;
 extern crate rustling_ontology_zh as zh;
 
+use std::fmt;
 use std::result;
 
+use rustling_ontology_values::locale::Region;
+
 macro_rules! lang_enum {
     ([$($lang:ident),*]) => {
         /// Enumerates all language supported for the general purpose ontology.
@@ -47,7 +53,103 @@ macro_rules! lang_enum {
     }
 }
 
-lang_enum!([DE, EN, ES, FR, JA, KO, ZH]);
+lang_enum!([DE, EN, ES, FR, IT, JA, KO, NL, PT, ZH]);
+
+/// Regions having rules of their own, on top of the ones of their language
+static REGIONAL_RULES: &'static [(Lang, &'static str)] = &[
+    (Lang::DE, "AT"),
+    (Lang::ES, "MX"),
+    (Lang::FR, "BE"),
+    (Lang::FR, "CH"),
+];
+
+/// Language with an optional region, such as "fr-CH"
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub lang: Lang,
+    pub region: Option<Region>,
+}
+
+impl Locale {
+    pub fn new(lang: Lang, region: Option<Region>) -> Locale {
+        Locale { lang, region }
+    }
+
+    /// Locales having a model of their own: every language alone, and the regions with rules of
+    /// their own
+    pub fn all() -> Vec<Locale> {
+        Lang::all().into_iter()
+            .map(Locale::from)
+            .chain(REGIONAL_RULES.iter().map(|&(lang, region)| Locale::new(lang, Region::from_code(region))))
+            .collect()
+    }
+
+    /// Locale whose rules and model are used for this one: the locale itself if its region has
+    /// rules of its own, or else its language alone
+    pub fn grammar_locale(&self) -> Locale {
+        let regional = self.region.map_or(false, |region| {
+            REGIONAL_RULES.iter().any(|&(lang, code)| lang == self.lang && code == region.as_str())
+        });
+        if regional { *self } else { Locale::from(self.lang) }
+    }
+
+    /// Region of the locale, or else the main region of the language. It sets the defaults of
+    /// the resolver, such as the currency of "$", the order of numeric dates and the first day
+    /// of the week.
+    pub fn default_region(&self) -> Region {
+        let code = match self.lang {
+            Lang::DE => "DE",
+            Lang::EN => "US",
+            Lang::ES => "ES",
+            Lang::FR => "FR",
+            Lang::IT => "IT",
+            Lang::JA => "JP",
+            Lang::KO => "KR",
+            Lang::NL => "NL",
+            Lang::PT => "BR",
+            Lang::ZH => "CN",
+        };
+        // the codes above are all valid
+        self.region.unwrap_or_else(|| Region::from_code(code).unwrap())
+    }
+}
+
+impl From<Lang> for Locale {
+    fn from(lang: Lang) -> Locale {
+        Locale::new(lang, None)
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = String;
+    /// Reads a BCP 47 language tag such as "fr-CH" or "zh-Hant-TW". The script and the variants
+    /// are ignored, and so are the numeric areas such as "419" in "es-419".
+    fn from_str(tag: &str) -> result::Result<Locale, Self::Err> {
+        let mut subtags = tag.split(|c| c == '-' || c == '_');
+        let lang = subtags.next().unwrap_or("").parse::<Lang>()?;
+        let mut region = None;
+        for subtag in subtags {
+            match subtag.len() {
+                4 => continue,
+                2 => {
+                    region = Some(Region::from_code(subtag).ok_or_else(|| format!("Unknown region {}", subtag))?);
+                    break
+                }
+                _ => break,
+            }
+        }
+        Ok(Locale::new(lang, region))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.region {
+            Some(region) => write!(f, "{}-{}", self.lang.to_string().to_lowercase(), region),
+            None => write!(f, "{}", self.lang.to_string().to_lowercase()),
+        }
+    }
+}
 
 
 /// Obtain rules for a given language.
@@ -57,8 +159,11 @@ pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustl
         Lang::EN => en::rule_set(),
         Lang::ES => es::rule_set(),
         Lang::FR => fr::rule_set(),
+        Lang::IT => it::rule_set(),
         Lang::JA => ja::rule_set(),
         Lang::KO => ko::rule_set(),
+        Lang::NL => nl::rule_set(),
+        Lang::PT => pt::rule_set(),
         Lang::ZH => zh::rule_set(),
     }
 }
@@ -70,8 +175,11 @@ pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
         Lang::EN => en::dims(),
         Lang::ES => es::dims(),
         Lang::FR => fr::dims(),
+        Lang::IT => it::dims(),
         Lang::JA => ja::dims(),
         Lang::KO => ko::dims(),
+        Lang::NL => nl::dims(),
+        Lang::PT => pt::dims(),
         Lang::ZH => zh::dims(),
     }
 }
@@ -83,8 +191,33 @@ pub fn examples(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_
         Lang::EN => en::examples(),
         Lang::ES => es::examples(),
         Lang::FR => fr::examples(),
+        Lang::IT => it::examples(),
         Lang::JA => ja::examples(),
         Lang::KO => ko::examples(),
+        Lang::NL => nl::examples(),
+        Lang::PT => pt::examples(),
         Lang::ZH => zh::examples(),
     }
-}
\ No newline at end of file
+}
+
+/// Obtain rules for a given locale, the ones of its language with the ones of its region.
+pub fn rules_for_locale(locale: Locale) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    let locale = locale.grammar_locale();
+    match locale.lang {
+        Lang::DE => de::rule_set_for_region(locale.region),
+        Lang::ES => es::rule_set_for_region(locale.region),
+        Lang::FR => fr::rule_set_for_region(locale.region),
+        lang => rules(lang),
+    }
+}
+
+/// Obtain examples for a given locale, the ones of its language with the ones of its region.
+pub fn examples_for_locale(locale: Locale) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let locale = locale.grammar_locale();
+    match locale.lang {
+        Lang::DE => de::examples_for_region(locale.region),
+        Lang::ES => es::examples_for_region(locale.region),
+        Lang::FR => fr::examples_for_region(locale.region),
+        lang => examples(lang),
+    }
+}
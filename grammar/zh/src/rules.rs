@@ -45,7 +45,111 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              unit_of_duration_check!(),
              |integer, uod| Ok(DurationValue::new(PeriodComp::new(uod.value().grain, integer.value().value).into()))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() > 0.0),
+             unit_of_duration_check!(),
+             |fraction, uod| Ok(DurationValue::new(helpers::period_from_fraction(fraction.value().value(), uod.value().grain)?))
+    );
 
+    b.rule_3("<duration>到<duration>",
+             duration_check!(),
+             b.reg(r#"到|至|~|-"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_interval(a.value(), b.value())
+    );
+    b.rule_3("<number>到<duration>",
+             number_check!(|number: &NumberValue| number.value() >= 0.0),
+             b.reg(r#"到|至|~|-"#)?,
+             duration_check!(),
+             |a, _, b| helpers::duration_number_interval(a.value(), b.value())
+    );
+    b.rule_2("<duration>以上",
+             duration_check!(),
+             b.reg(r#"以上|或以上"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("<duration>以内",
+             duration_check!(),
+             b.reg(r#"以内|以內|以下|或以下"#)?,
+             |a, _| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    b.rule_2("至少<duration>",
+             b.reg(r#"至少|超过|超過|多于|多於"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::After)
+    );
+    b.rule_2("不到<duration>",
+             b.reg(r#"不到|少于|少於|不超过|不超過|最多"#)?,
+             duration_check!(),
+             |_, a| helpers::duration_bound(a.value(), Direction::Before)
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("每小时",
+                      b.reg(r#"每(?:个|個)?(?:小时|小時|鐘頭)"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Hour))
+    );
+    b.rule_1_terminal("每天",
+                      b.reg(r#"每天|每日|天天"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Day))
+    );
+    b.rule_1_terminal("隔天",
+                      b.reg(r#"隔天|隔日"#)?,
+                      |_| RecurrenceValue::new(Grain::Day).every(2)
+    );
+    b.rule_1_terminal("每周",
+                      b.reg(r#"每(?:个|個)?(?:周|週|星期|礼拜|禮拜)"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Week))
+    );
+    b.rule_1_terminal("隔周",
+                      b.reg(r#"隔(?:周|週)"#)?,
+                      |_| RecurrenceValue::new(Grain::Week).every(2)
+    );
+    b.rule_1_terminal("每月",
+                      b.reg(r#"每(?:个|個)?月"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Month))
+    );
+    b.rule_1_terminal("每年",
+                      b.reg(r#"每年|年年"#)?,
+                      |_| Ok(RecurrenceValue::new(Grain::Year))
+    );
+    b.rule_3("每<integer><cycle>",
+             b.reg(r#"每隔?"#)?,
+             integer_check_by_range!(2),
+             cycle_check!(),
+             |_, n, a| RecurrenceValue::new(a.value().grain).every(n.value().value)
+    );
+    b.rule_2("每<day-of-week>",
+             b.reg(r#"每(?:个|個)?"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |_, a| RecurrenceValue::new(Grain::Week).at(a.value())
+    );
+    b.rule_3("<recurrence>和<day-of-week>",
+             recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+             b.reg(r#"和|跟|、"#)?,
+             time_check!(form!(Form::DayOfWeek{..})),
+             |a, _, b| a.value().at(b.value())
+    );
+    b.rule_2("<recurrence><time-of-day>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.by_hour.is_none()),
+             time_check!(form!(Form::TimeOfDay(_))),
+             |a, b| a.value().at(b.value())
+    );
+    b.rule_4("从<time>开始<recurrence>",
+             b.reg(r#"从|從|自"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             b.reg(r#"开始|開始|起"#)?,
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.start.is_none()),
+             |_, a, _, b| b.value().starting(a.value())
+    );
+    b.rule_3("<recurrence>直到<time>",
+             recurrence_check!(|recurrence: &RecurrenceValue| recurrence.end.is_none()),
+             b.reg(r#"直到"#)?,
+             time_check!(excluding_form!(Form::TimeOfDay(_))),
+             |a, _, b| a.value().until(b.value())
+    );
     Ok(())
 }
 
@@ -278,10 +382,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("morning",
                       b.reg(r#"早上|早晨|朝頭?早"#)?,
                       |_| {
-                          Ok(helpers::hour(4, false)?
-                              .span_to(&helpers::hour(12, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Morning)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Morning, 4, 12)?.latent())
                       }
     );
 
@@ -289,8 +390,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"昨晚|昨天晚上|尋晚"#)?,
                       |_| {
                           let yesterday = helpers::cycle_nth(Grain::Day, -1)?;
-                          let night = helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?;
+                          let night = helpers::part_of_day(PartOfDayForm::Night, 18, 0)?;
                           Ok(yesterday.intersect(&night)?.form(Form::PartOfDay(PartOfDayForm::Night)))
                       }
     );
@@ -303,7 +403,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("tonight",
                       b.reg(r#"今晚|今天晚上"#)?,
                       |_| {
-                          let period = helpers::hour(18, false)?.span_to(&helpers::hour(0, false)?, false)?;
+                          let period = helpers::part_of_day(PartOfDayForm::Night, 18, 0)?;
                           Ok(helpers::cycle_nth(Grain::Day, 0)?
                               .intersect(&period)?
                               .form(Form::PartOfDay(PartOfDayForm::Night)))
@@ -314,8 +414,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"明晚|明天晚上|聽晚"#)?,
                       |_| {
                           let tomorrow = helpers::cycle_nth(Grain::Day, 1)?;
-                          let night = helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?;
+                          let night = helpers::part_of_day(PartOfDayForm::Night, 18, 0)?;
                           Ok(tomorrow.intersect(&night)?.form(Form::PartOfDay(PartOfDayForm::Night)))
                       }
     );
@@ -339,10 +438,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("evening|night",
                       b.reg(r#"晚上|晚间"#)?,
                       |_| {
-                          Ok(helpers::hour(18, false)?
-                              .span_to(&helpers::hour(0, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Evening)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Evening, 18, 0)?.latent())
                       }
     );
 
@@ -515,10 +611,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("afternoon",
                       b.reg(r#"下午|中午|晏晝"#)?,
                       |_| {
-                          Ok(helpers::hour(12, false)?
-                              .span_to(&helpers::hour(19, false)?, false)?
-                              .latent()
-                              .form(Form::PartOfDay(PartOfDayForm::Afternoon)))
+                          Ok(helpers::part_of_day(PartOfDayForm::Afternoon, 12, 19)?.latent())
                       }
     );
 
@@ -528,7 +621,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_2("in|during the <part-of-day>",
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              b.reg(r#"点|點"#)?,
              |time, _| Ok(time.value().clone().not_latent())
     );
@@ -583,7 +676,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_2("<time> <part-of-day>",
              time_check!(),
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              |time, part_of_day| part_of_day.value().intersect(time.value())
     );
 
@@ -654,7 +747,7 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_2("<part-of-day> <time>",
-             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal)(time)),
+             time_check!(|time: &TimeValue| form!(Form::PartOfDay(_))(time) || form!(Form::Meal(_))(time)),
              time_check!(),
              |part_of_day, time| part_of_day.value().intersect(time.value())
     );
@@ -674,6 +767,36 @@ pub fn rules_time(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     Ok(())
 }
 
+pub fn rules_timezone(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("timezone abbreviation",
+                      b.reg(r#"(utc|gmt)"#)?,
+                      |text_match| helpers::timezone(text_match.group(1))
+    );
+    b.rule_1_terminal("北京时间",
+                      b.reg(r#"北京(?:时间|時間)"#)?,
+                      |_| helpers::timezone("asia/shanghai")
+    );
+    b.rule_1_terminal("东京时间",
+                      b.reg(r#"(?:东京|東京|日本)(?:时间|時間)"#)?,
+                      |_| helpers::timezone("asia/tokyo")
+    );
+    b.rule_1_terminal("格林尼治时间",
+                      b.reg(r#"格林(?:尼治|威治)(?:标准)?(?:时间|時間)|世界(?:标准|協調|协调)?(?:时间|時間)"#)?,
+                      |_| helpers::timezone("utc")
+    );
+    b.rule_2("<timezone> <time>",
+             timezone_check!(),
+             time_check!(|time: &TimeValue| !time.latent),
+             |timezone, time| time.value().in_timezone(timezone.value())
+    );
+    b.rule_2("<time> <timezone>",
+             time_check!(|time: &TimeValue| !time.latent),
+             timezone_check!(),
+             |time, timezone| time.value().in_timezone(timezone.value())
+    );
+    Ok(())
+}
+
 
 pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1("number as temp",
@@ -683,72 +806,189 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                      value: a.value().value(),
                      unit: None,
                      latent: true,
+                     ..TemperatureValue::default()
                  })
              });
 
     b.rule_2("<latent temp> degrees",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"度|°"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("degree"),
+                     unit: Some(TemperatureUnit::Degree),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
 
     b.rule_2("<temp> Celcius",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:摄|攝)氏(?:°|度)|°c"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
 
     b.rule_3("Celcius <temp>",
              b.reg(r#"(?:摄|攝)氏"#)?,
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"度|°"#)?,
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
-                     unit: Some("celsius"),
+                     unit: Some(TemperatureUnit::Celsius),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              }
     );
 
     b.rule_2("<temp> Fahrenheit",
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"(?:华|華)氏(?:°|度)|°f"#)?,
              |a, _| {
                  Ok(TemperatureValue {
                      value: a.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              });
 
     b.rule_3("Fahrenheit <temp>",
              b.reg(r#"(?:华|華)氏"#)?,
-             temperature_check!(),
+             temperature_check!(|temp: &TemperatureValue| !temp.is_interval()),
              b.reg(r#"度|°"#)?,
              |_, b, _| {
                  Ok(TemperatureValue {
                      value: b.value().value,
-                     unit: Some("fahrenheit"),
+                     unit: Some(TemperatureUnit::Fahrenheit),
                      latent: false,
+                     ..TemperatureValue::default()
                  })
              }
     );
 
+    b.rule_2("零下<temp>",
+             b.reg(r#"零下"#)?,
+             temperature_check!(|temp: &TemperatureValue| temp.value >= 0.0 && !temp.is_interval()),
+             |_, a| {
+                 Ok(TemperatureValue {
+                     value: -1.0 * a.value().value,
+                     latent: false,
+                     ..*a.value()
+                 })
+             });
+
+    b.rule_4("从<temp>到<temp>",
+             b.reg(r#"从|從"#)?,
+             temperature_check!(),
+             b.reg(r#"到|至"#)?,
+             temperature_check!(),
+             |_, a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+
+    b.rule_4("<temp>到<temp>之间",
+             temperature_check!(),
+             b.reg(r#"到|至|和"#)?,
+             temperature_check!(),
+             b.reg(r#"之间|之間"#)?,
+             |a, _, b, _| helpers::temperature_interval(a.value(), b.value())
+    );
+
+    b.rule_3("<temp>到<temp>",
+             temperature_check!(),
+             b.reg(r#"到|至|~|-"#)?,
+             temperature_check!(),
+             |a, _, b| helpers::temperature_interval(a.value(), b.value())
+    );
+
+    b.rule_2("<temp>以上",
+             temperature_check!(),
+             b.reg(r#"以上|或以上"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::After)
+    );
+
+    b.rule_2("<temp>以下",
+             temperature_check!(),
+             b.reg(r#"以下|或以下"#)?,
+             |a, _| helpers::temperature_bound(a.value(), Direction::Before)
+    );
+
+    b.rule_2("超过<temp>",
+             b.reg(r#"超过|超過|高于|高於|至少"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::After)
+    );
+
+    b.rule_2("低于<temp>",
+             b.reg(r#"低于|低於|不超过|不超過|最多"#)?,
+             temperature_check!(),
+             |_, a| helpers::temperature_bound(a.value(), Direction::Before)
+    );
     Ok(())
 }
 
 
+pub fn rules_number_interval(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("从<number>到<number>",
+             b.reg(r#"从|從"#)?,
+             number_check!(),
+             b.reg(r#"到|至"#)?,
+             number_check!(),
+             |_, a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_4("<number>到<number>之间",
+             number_check!(),
+             b.reg(r#"到|至|和"#)?,
+             number_check!(),
+             b.reg(r#"之间|之間"#)?,
+             |a, _, b, _| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_3("<number>到<number>",
+             number_check!(),
+             b.reg(r#"到|至|~|-"#)?,
+             number_check!(),
+             |a, _, b| NumberIntervalValue::between(a.value().value(), b.value().value())
+    );
+    b.rule_2("至少<number>",
+             b.reg(r#"至少|最少|不少于"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("<number>以上",
+             number_check!(),
+             b.reg(r#"以上|或以上"#)?,
+             |a, _| Ok(NumberIntervalValue::at_least(a.value().value()))
+    );
+    b.rule_2("超过<number>",
+             b.reg(r#"超过|超過|多于"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::more_than(a.value().value()))
+    );
+    b.rule_2("最多<number>",
+             b.reg(r#"最多|至多|不超过|不超過"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("<number>以下",
+             number_check!(),
+             b.reg(r#"以下|或以下"#)?,
+             |a, _| Ok(NumberIntervalValue::up_to(a.value().value()))
+    );
+    b.rule_2("少于<number>",
+             b.reg(r#"少于|少於|不到"#)?,
+             number_check!(),
+             |_, a| Ok(NumberIntervalValue::less_than(a.value().value()))
+    );
+    Ok(())
+}
+
 pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_1_terminal("integer (0..10)",
                       b.reg(r#"(〇|零|一|二|两|兩|三|四|五|六|七|八|九|十)(?:个|個)?"#)?,
@@ -804,6 +1044,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                          FloatValue {
                              value: float.value * -1.0,
                              prefixed: true,
+                             fraction: float.fraction.map(|f| f.negated()),
                              ..float
                          }
                              .into()
@@ -899,6 +1140,13 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  })
              });
 
+    b.rule_3("fraction",
+             number_check!(|number: &NumberValue| !number.prefixed()),
+             b.reg(r#"分之"#)?,
+             number_check!(|number: &NumberValue| !number.suffixed()),
+             |a, _, b| helpers::fraction(b.value(), a.value())
+    );
+
     b.rule_2("ordinal (digits)",
              b.reg(r#"第"#)?,
              integer_check!(),
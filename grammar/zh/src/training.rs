@@ -97,14 +97,16 @@ pub fn examples_time(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 12, 25]), "圣诞", "聖誕", "圣诞节", "聖誕節");
     example!(v, check_moment_span!(c, [2013, 10, 1, 18], [2013, 10, 2, 0]), "国庆节晚上", "國慶節晚上");
     example!(v, check_moment!(c, [2013, 6, 1, 15, 15]), "儿童节下午三点十五", "兒童節下午三點十五");
+    example!(v, check_timezone("Asia/Shanghai"), "北京时间8点", "北京時間八點");
+    example!(v, check_timezone("UTC"), "8点UTC");
 }
 
 
 pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
-    example!(v, check_temperature(45.0, Some("degree")), "45°", "45度");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "45度");
+    example!(v, check_temperature(45.0, Some(TemperatureUnit::Degree)), "45°", "45度");
     example!(v,
-             check_temperature(50.0, Some("fahrenheit")),
+             check_temperature(50.0, Some(TemperatureUnit::Fahrenheit)),
              "50°F",
              "华氏50°",
              "華氏50°",
@@ -115,7 +117,7 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
              "50华氏度",
              "50華氏度");
     example!(v,
-             check_temperature(70.0, Some("fahrenheit")),
+             check_temperature(70.0, Some(TemperatureUnit::Fahrenheit)),
              "70°F",
              "华氏70°",
              "華氏70°",
@@ -126,7 +128,7 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
              "70华氏度",
              "70華氏度");
     example!(v,
-             check_temperature(23.0, Some("celsius")),
+             check_temperature(23.0, Some(TemperatureUnit::Celsius)),
              "23°C",
              "摄氏23°",
              "攝氏23°",
@@ -137,7 +139,7 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
              "23摄氏度",
              "23攝氏度");
     example!(v,
-             check_temperature(37.0, Some("celsius")),
+             check_temperature(37.0, Some(TemperatureUnit::Celsius)),
              "37°C",
              "摄氏37°",
              "攝氏37°",
@@ -147,6 +149,10 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
              "37攝氏°",
              "37摄氏度",
              "37攝氏度");
+    example!(v, check_temperature(-5.0, Some(TemperatureUnit::Degree)), "零下5度", "-5度");
+    example!(v, check_temperature_interval(Some(18.0), Some(22.0), Some(TemperatureUnit::Degree)), "18到22度", "从18度到22度");
+    example!(v, check_temperature_interval(Some(30.0), None, Some(TemperatureUnit::Degree)), "30度以上", "超过30度");
+    example!(v, check_temperature_interval(None, Some(10.0), Some(TemperatureUnit::Degree)), "10度以下", "低于10度");
 }
 
 
@@ -164,6 +170,7 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_integer(10), "10", "十");
     example!(v, check_float(1.1), "1.1", "1.10", "01.10");
     example!(v, check_float(0.77), "0.77", ".77");
+    example!(v, check_fraction(2, 3), "三分之二");
     example!(v, check_integer(20), "20", "二十");
     example!(v, check_integer(30), "30", "三十");
     example!(v, check_integer(40), "40", "四十");
@@ -191,3 +198,23 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
 }
+
+pub fn examples_number_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_number_interval(Some(3.0), Some(5.0)), "三到五", "3到5", "从三到五", "3~5");
+    example!(v, check_number_interval(Some(3.0), None), "至少三", "3以上");
+    example!(v, check_number_interval(None, Some(10.0)), "最多十", "10以下");
+    example!(v, check_number_interval(Some(100.0), None), "超过100", "超过一百");
+}
+
+pub fn examples_duration_interval(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_duration_interval(Some(PeriodComp::hours(2).into()), Some(PeriodComp::hours(3).into())), "2到3小时", "2小时到3小时");
+    example!(v, check_duration_interval(Some(PeriodComp::minutes(20).into()), None), "至少20分钟", "20分钟以上");
+    example!(v, check_duration_interval(None, Some(PeriodComp::days(3).into())), "3天以内", "不到3天");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_recurrence(Grain::Day, 1, vec![], None), "每天", "天天");
+    example!(v, check_recurrence(Grain::Day, 1, vec![], Some(8)), "每天8点", "每天八点");
+    example!(v, check_recurrence(Grain::Week, 1, vec![Weekday::Mon], None), "每周一", "每个星期一");
+    example!(v, check_recurrence(Grain::Week, 2, vec![], None), "隔周", "每两周");
+}
@@ -5,15 +5,15 @@ extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 
-use rustling_ontology::{Output, dimension, output::TimeIntervalOutput};
-use moment::{Moment, Local};
+use rustling_ontology::{Output, dimension, output::{TimeIntervalOutput, AmountOfMoneyIntervalOutput, TemperatureIntervalOutput}};
+use moment::{Moment, Local, Zone};
 use ::std::f64;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Utterance {
     pub phrase: String,
-    #[serde(with = "moment_json")]
+    #[serde(with = "moment_json::local")]
     pub context: Moment<Local>,
     #[serde(rename = "in_grammar")]
     pub in_grammar: bool,
@@ -66,7 +66,7 @@ pub struct TestOutput {
     pub phrase: String,
     #[serde(rename = "in_grammar")]
     pub in_grammar: bool,
-    #[serde(with = "moment_json")]
+    #[serde(with = "moment_json::local")]
     pub context: Moment<Local>,
     pub translation: Option<String>,
     pub output: TestAssertion<Vec<SlotValue>, Vec<SlotValue>>,
@@ -81,8 +81,17 @@ pub enum SlotValue {
     InstantTime(InstantTimeValue),
     TimeInterval(TimeIntervalValue),
     AmountOfMoney(AmountOfMoneyValue),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalValue),
     Temperature(TemperatureValue),
+    TemperatureInterval(TemperatureIntervalValue),
     Duration(DurationValue),
+    DurationInterval(DurationIntervalValue),
+    Recurrence(RecurrenceValue),
+    Quantity(QuantityValue),
+    Email(EmailValue),
+    Url(UrlValue),
+    PhoneNumber(PhoneNumberValue),
+    NumberInterval(NumberIntervalValue),
 }
 
 impl From<Output> for SlotValue {
@@ -96,38 +105,95 @@ impl From<Output> for SlotValue {
                 value: time.moment,
                 grain: time.grain.into(),
                 precision: time.precision.into(),
+                timezone: time.timezone.map(|it| it.name.to_string()),
             }),
             Output::TimeInterval(TimeIntervalOutput::After(time)) => SlotValue::TimeInterval( TimeIntervalValue {
                 from: Some(time.moment),
                 to: None,
+                timezone: time.timezone.map(|it| it.name.to_string()),
             }),
             Output::TimeInterval(TimeIntervalOutput::Before(time)) => SlotValue::TimeInterval( TimeIntervalValue {
                 from: None,
                 to: Some(time.moment),
+                timezone: time.timezone.map(|it| it.name.to_string()),
             }),
-            Output::TimeInterval(TimeIntervalOutput::Between { start, end, .. }) => SlotValue::TimeInterval( TimeIntervalValue {
+            Output::TimeInterval(TimeIntervalOutput::Between { start, end, timezone, .. }) => SlotValue::TimeInterval( TimeIntervalValue {
                 from: Some(start),
                 to: Some(end),
+                timezone: timezone.map(|it| it.name.to_string()),
             }),
             Output::AmountOfMoney(amount) => SlotValue::AmountOfMoney( AmountOfMoneyValue {
                 value: amount.value,
                 precision: amount.precision.into(),
-                unit: amount.unit.map(|it| it.to_string()),
+                unit: amount.currency.map(|it| it.code).or(amount.unit).map(|it| it.to_string()),
+            }),
+            Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::After(amount)) => SlotValue::AmountOfMoneyInterval( AmountOfMoneyIntervalValue {
+                from: Some(amount.value),
+                to: None,
+                precision: amount.precision.into(),
+                unit: amount.currency.map(|it| it.code).or(amount.unit).map(|it| it.to_string()),
+            }),
+            Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::Before(amount)) => SlotValue::AmountOfMoneyInterval( AmountOfMoneyIntervalValue {
+                from: None,
+                to: Some(amount.value),
+                precision: amount.precision.into(),
+                unit: amount.currency.map(|it| it.code).or(amount.unit).map(|it| it.to_string()),
+            }),
+            Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::Between { start, end, precision, unit, currency }) => SlotValue::AmountOfMoneyInterval( AmountOfMoneyIntervalValue {
+                from: Some(start),
+                to: Some(end),
+                precision: precision.into(),
+                unit: currency.map(|it| it.code).or(unit).map(|it| it.to_string()),
             }),
             Output::Temperature(temperature) => SlotValue::Temperature( TemperatureValue {
                 value: temperature.value,
-                unit: temperature.unit.map(|it| it.to_string()),
+                unit: temperature.unit.map(|it| it.as_str().to_string()),
+            }),
+            Output::TemperatureInterval(TemperatureIntervalOutput::After(temperature)) => SlotValue::TemperatureInterval( TemperatureIntervalValue {
+                from: Some(temperature.value),
+                to: None,
+                unit: temperature.unit.map(|it| it.as_str().to_string()),
+            }),
+            Output::TemperatureInterval(TemperatureIntervalOutput::Before(temperature)) => SlotValue::TemperatureInterval( TemperatureIntervalValue {
+                from: None,
+                to: Some(temperature.value),
+                unit: temperature.unit.map(|it| it.as_str().to_string()),
+            }),
+            Output::TemperatureInterval(TemperatureIntervalOutput::Between { start, end, unit, .. }) => SlotValue::TemperatureInterval( TemperatureIntervalValue {
+                from: Some(start),
+                to: Some(end),
+                unit: unit.map(|it| it.as_str().to_string()),
+            }),
+            Output::Duration(duration) => SlotValue::Duration(DurationValue::from_period(&duration.period, duration.precision.into())),
+            Output::DurationInterval(interval) => {
+                let precision: Precision = interval.precision.into();
+                SlotValue::DurationInterval( DurationIntervalValue {
+                    min: interval.min.map(|period| DurationValue::from_period(&period, precision)),
+                    max: interval.max.map(|period| DurationValue::from_period(&period, precision)),
+                    precision: precision,
+                })
+            }
+            Output::Recurrence(recurrence) => SlotValue::Recurrence( RecurrenceValue {
+                rrule: recurrence.to_rrule(),
+                frequency: recurrence.frequency.into(),
+                interval: recurrence.interval,
+                from: recurrence.start,
+                to: recurrence.end,
+            }),
+            Output::Quantity(quantity) => SlotValue::Quantity( QuantityValue {
+                value: quantity.value,
+                unit: quantity.unit.as_str().to_string(),
+                normalized_value: quantity.normalized_value,
+                precision: quantity.precision.into(),
             }),
-            Output::Duration(duration) => SlotValue::Duration( DurationValue {
-                years: *duration.period.0.get(Grain::Year as usize).unwrap_or(&0),
-                quarters: *duration.period.0.get(Grain::Quarter as usize).unwrap_or(&0),
-                months: *duration.period.0.get(Grain::Month as usize).unwrap_or(&0),
-                weeks: *duration.period.0.get(Grain::Week as usize).unwrap_or(&0),
-                days: *duration.period.0.get(Grain::Day as usize).unwrap_or(&0),
-                hours: *duration.period.0.get(Grain::Hour as usize).unwrap_or(&0),
-                minutes: *duration.period.0.get(Grain::Minute as usize).unwrap_or(&0),
-                seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
-                precision: duration.precision.into(),
+            Output::Email(email) => SlotValue::Email(EmailValue { value: email.0 }),
+            Output::Url(url) => SlotValue::Url(UrlValue { value: url.value, domain: url.domain }),
+            Output::PhoneNumber(phone) => SlotValue::PhoneNumber(PhoneNumberValue { value: phone.0 }),
+            Output::NumberInterval(interval) => SlotValue::NumberInterval(NumberIntervalValue {
+                from: interval.from.map(|it| it.into()),
+                to: interval.to.map(|it| it.into()),
+                from_inclusive: interval.from_inclusive,
+                to_inclusive: interval.to_inclusive,
             }),
         }
     }
@@ -180,17 +246,21 @@ impl PartialEq for PercentageValue {
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct InstantTimeValue {
     #[serde(with = "moment_json")]
-    pub value: Moment<Local>,
+    pub value: Moment<Zone>,
     pub grain: Grain,
     pub precision: Precision,
+    #[serde(default)]
+    pub timezone: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct TimeIntervalValue {
     #[serde(with = "optional_moment_json")]
-    pub from: Option<Moment<Local>>,
+    pub from: Option<Moment<Zone>>,
     #[serde(with = "optional_moment_json")]
-    pub to: Option<Moment<Local>>,
+    pub to: Option<Moment<Zone>>,
+    #[serde(default)]
+    pub timezone: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -200,12 +270,27 @@ pub struct AmountOfMoneyValue {
     pub unit: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AmountOfMoneyIntervalValue {
+    pub from: Option<f32>,
+    pub to: Option<f32>,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct TemperatureValue {
     pub value: f32,
     pub unit: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemperatureIntervalValue {
+    pub from: Option<f32>,
+    pub to: Option<f32>,
+    pub unit: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct DurationValue {
     pub years: i64,
@@ -219,6 +304,72 @@ pub struct DurationValue {
     pub precision: Precision,
 }
 
+impl DurationValue {
+    fn from_period(period: &moment::Period, precision: Precision) -> DurationValue {
+        DurationValue {
+            years: *period.0.get(Grain::Year as usize).unwrap_or(&0),
+            quarters: *period.0.get(Grain::Quarter as usize).unwrap_or(&0),
+            months: *period.0.get(Grain::Month as usize).unwrap_or(&0),
+            weeks: *period.0.get(Grain::Week as usize).unwrap_or(&0),
+            days: *period.0.get(Grain::Day as usize).unwrap_or(&0),
+            hours: *period.0.get(Grain::Hour as usize).unwrap_or(&0),
+            minutes: *period.0.get(Grain::Minute as usize).unwrap_or(&0),
+            seconds: *period.0.get(Grain::Second as usize).unwrap_or(&0),
+            precision: precision,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DurationIntervalValue {
+    pub min: Option<DurationValue>,
+    pub max: Option<DurationValue>,
+    pub precision: Precision,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RecurrenceValue {
+    pub rrule: String,
+    pub frequency: Grain,
+    pub interval: i64,
+    #[serde(with = "optional_moment_json")]
+    pub from: Option<Moment<Zone>>,
+    #[serde(with = "optional_moment_json")]
+    pub to: Option<Moment<Zone>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct QuantityValue {
+    pub value: f32,
+    pub unit: String,
+    pub normalized_value: f32,
+    pub precision: Precision,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct EmailValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct UrlValue {
+    pub value: String,
+    pub domain: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PhoneNumberValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct NumberIntervalValue {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    pub from_inclusive: bool,
+    pub to_inclusive: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Hash)]
 pub enum Grain {
     Year = 0,
@@ -262,37 +413,60 @@ impl From<dimension::Precision> for Precision {
 }
 
 mod moment_json {
-    use moment::{Moment, Local, TimeZone};
+    use std::fmt::Display;
+    use moment::{Moment, DateTime, Local, TimeZone, Zone};
     use serde::{Serialize, Serializer, Deserialize, Deserializer, de::Error};
 
-    pub fn serialize<S: Serializer>(moment: &Moment<Local>, serializer: S) -> Result<S::Ok, S::Error> {
-        moment.0.format("%Y-%m-%d %T").to_string().serialize(serializer)
+    pub fn serialize<T, S>(moment: &Moment<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where T: TimeZone, T::Offset: Display, S: Serializer {
+        moment.0.format("%Y-%m-%d %T%:z").to_string().serialize(serializer)
     }
 
-    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Moment<Local>, D::Error> {
+    /// Reads a moment at the offset it was written with, or on the wall clock of the machine
+    /// when it has none
+    pub fn parse(time: &str) -> Result<Moment<Zone>, String> {
+        match DateTime::parse_from_str(time, "%Y-%m-%d %T%:z") {
+            Ok(datetime) => Ok(Moment(datetime.with_timezone(&Zone::Fixed(*datetime.offset())))),
+            Err(_) => Zone::Local.datetime_from_str(time, "%Y-%m-%d %T").map(Moment).map_err(|e| e.to_string()),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Moment<Zone>, D::Error> {
         let time: String = Deserialize::deserialize(deserializer)?;
-        let datetime = Local.datetime_from_str(time.as_ref(), "%Y-%m-%d %T").map_err(D::Error::custom)?;
-        Ok(Moment(datetime))
+        parse(time.as_ref()).map_err(D::Error::custom)
+    }
+
+    /// Moments of the machine, such as the reference time of a test
+    pub mod local {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(moment: &Moment<Local>, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(moment, serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Moment<Local>, D::Error> {
+            let time: String = Deserialize::deserialize(deserializer)?;
+            parse(time.as_ref()).map(|moment| Moment(moment.0.with_timezone(&Local))).map_err(D::Error::custom)
+        }
     }
 }
 
 mod optional_moment_json {
     use super::*;
-    use moment::{Moment, Local, TimeZone};
+    use moment::{Moment, Zone};
     use serde::{Serializer, Deserialize, Deserializer, de::Error};
 
-    pub fn serialize<S: Serializer>(moment: &Option<Moment<Local>>, serializer: S) -> Result<S::Ok, S::Error> {
+    pub fn serialize<S: Serializer>(moment: &Option<Moment<Zone>>, serializer: S) -> Result<S::Ok, S::Error> {
         match moment {
             &Some(ref moment) => moment_json::serialize(moment, serializer),
             &None => serializer.serialize_none(),
         }
     }
 
-    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Moment<Local>>, D::Error> {
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Moment<Zone>>, D::Error> {
         let time: Option<String> = Deserialize::deserialize(deserializer)?;
         if let Some(time) = time {
-            let datetime = Local.datetime_from_str(time.as_ref(), "%Y-%m-%d %T").map_err(D::Error::custom)?;
-            Ok(Some(Moment(datetime)))
+            moment_json::parse(time.as_ref()).map(Some).map_err(D::Error::custom)
         } else {
             Ok(None)
         }
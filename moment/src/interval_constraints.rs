@@ -9,18 +9,127 @@ use std::fmt;
 use chrono::offset::local::Local;
 use chrono::{Datelike, TimeZone, Timelike, Weekday};
 
+/// Days starting the week and making up the weekend
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Week {
+    pub start: Weekday,
+    pub weekend_start: Weekday,
+    pub weekend_days: u32,
+}
+
+impl Default for Week {
+    fn default() -> Week {
+        Week {
+            start: Weekday::Mon,
+            weekend_start: Weekday::Sat,
+            weekend_days: 2,
+        }
+    }
+}
+
+/// First month of the fiscal year, fiscal quarters starting from it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiscalCalendar {
+    /// Month from 1 to 12
+    pub start_month: u32,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> FiscalCalendar {
+        FiscalCalendar { start_month: 1 }
+    }
+}
+
+/// Order of the day and the month in dates written with numbers only, e.g. "3/4/2015"
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateOrder {
+    MonthDay,
+    DayMonth,
+}
+
+/// How dates written with numbers only are read
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DateFormat {
+    /// Order of the day and the month, the one of the grammar when `None`
+    pub order: Option<DateOrder>,
+    /// Two-digit years from the pivot are read in the 1900s, the ones below it in the 2000s
+    pub year_pivot: i32,
+}
+
+impl Default for DateFormat {
+    fn default() -> DateFormat {
+        DateFormat {
+            order: None,
+            year_pivot: 50,
+        }
+    }
+}
+
+impl DateFormat {
+    /// Year with its century, e.g. 1974 for 74
+    pub fn full_year(&self, year: i32) -> i32 {
+        if year < 0 || year > 99 {
+            year
+        } else if year >= self.year_pivot {
+            1900 + year
+        } else {
+            2000 + year
+        }
+    }
+}
+
+/// Named period of the day, such as the morning or lunch
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DayPeriod {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+    Breakfast,
+    Brunch,
+    Lunch,
+    Snack,
+    Dinner,
+}
+
+/// Hours of the periods of the day set in the context, as `[start_hour, end_hour)` windows
+/// replacing the ones of the grammars
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct DayPeriods([Option<(u32, u32)>; 9]);
+
+impl DayPeriods {
+    pub fn with_window(self, period: DayPeriod, start_hour: u32, end_hour: u32) -> DayPeriods {
+        let mut windows = self.0;
+        windows[period as usize] = Some((start_hour, end_hour));
+        DayPeriods(windows)
+    }
+
+    pub fn window(&self, period: DayPeriod) -> Option<(u32, u32)> {
+        self.0[period as usize]
+    }
+}
+
 #[derive(Clone, PartialEq, new)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    #[new(default)]
+    pub week: Week,
+    #[new(default)]
+    pub day_periods: DayPeriods,
+    #[new(default)]
+    pub fiscal_calendar: FiscalCalendar,
+    #[new(default)]
+    pub date_format: DateFormat,
 }
 
 impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
 
 impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?} }}", self.reference, self.min, self.max)
+        write!(f, "Context {{ reference: {:?}, min: {:?}, max: {:?}, week: {:?}, day_periods: {:?}, fiscal_calendar: {:?}, date_format: {:?} }}",
+               self.reference, self.min, self.max, self.week, self.day_periods, self.fiscal_calendar, self.date_format)
     }
 }
 
@@ -46,6 +155,22 @@ impl<T: TimeZone> Context<T> where <T as TimeZone>::Offset: Copy {
         let max_interval = Interval::starting_at(Moment(now.timezone().ymd(max_year, 1, 1).and_hms(0, 0, 0)), Grain::Second);
         Context::new(now, min_interval, max_interval)
     }
+
+    pub fn with_week(self, week: Week) -> Context<T> {
+        Context { week, ..self }
+    }
+
+    pub fn with_day_periods(self, day_periods: DayPeriods) -> Context<T> {
+        Context { day_periods, ..self }
+    }
+
+    pub fn with_fiscal_calendar(self, fiscal_calendar: FiscalCalendar) -> Context<T> {
+        Context { fiscal_calendar, ..self }
+    }
+
+    pub fn with_date_format(self, date_format: DateFormat) -> Context<T> {
+        Context { date_format, ..self }
+    }
 }
 
 pub type IntervalWalker<T> = BidirectionalWalker<Interval<T>>;
@@ -137,12 +262,8 @@ impl<T: TimeZone> IntervalConstraint<T> for Year where <T as TimeZone>::Offset:
         Grain::Year
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let normalized_year = if self.0 <= 99 {
-            (self.0 + 50) % 100 + 2000 - 50
-        } else {
-            self.0
-        };
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let normalized_year = context.date_format.full_year(self.0);
 
         if origin.start.year() <= normalized_year {
             let moment_year = Moment(origin.timezone().ymd(normalized_year, 1, 1).and_hms(0, 0, 0));
@@ -156,6 +277,51 @@ impl<T: TimeZone> IntervalConstraint<T> for Year where <T as TimeZone>::Offset:
     }
 }
 
+/// Fiscal year of the context, labelled by the year it ends in, or every fiscal year when `None`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiscalYear(pub Option<i32>);
+
+impl FiscalYear {
+    pub fn new<T: TimeZone>(y: Option<i32>) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(FiscalYear(y))
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for FiscalYear where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let start_month = context.fiscal_calendar.start_month;
+        match self.0 {
+            Some(year) => {
+                let normalized_year = context.date_format.full_year(year);
+                let start_year = if start_month == 1 { normalized_year } else { normalized_year - 1 };
+                let moment_year = Moment(origin.timezone().ymd(start_year, start_month, 1).and_hms(0, 0, 0));
+                let interval = Interval::starting_at(moment_year, Grain::Year);
+                if origin.start < interval.end_moment() {
+                    BidirectionalWalker::new().forward_values(vec![interval])
+                } else {
+                    BidirectionalWalker::new().backward_values(vec![interval])
+                }
+            }
+            None => {
+                let month_offset = (origin.start.month0() + 12 - (start_month - 1)) % 12;
+                let anchor = Interval::starting_at(origin.start.round_to(Grain::Month) - PeriodComp::months(month_offset as i64), Grain::Year);
+                BidirectionalWalker::new()
+                    .forward_with(anchor, |prev| prev + PeriodComp::years(1))
+                    .backward_with(anchor - PeriodComp::years(1),
+                                   |prev| prev - PeriodComp::years(1))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct YearMonthDay {
     pub year: i32, 
@@ -178,12 +344,8 @@ impl<T: TimeZone> IntervalConstraint<T> for YearMonthDay where <T as TimeZone>::
         Grain::Year
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let normalized_year = if self.year < 99 {
-            (self.year + 50) % 100 + 2000 - 50
-        } else {
-            self.year
-        };
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let normalized_year = context.date_format.full_year(self.year);
         if self.day > last_day_in_month(normalized_year, self.month, origin.timezone()) {
             BidirectionalWalker::new() 
         } else if origin.start.year() <= normalized_year {
@@ -250,6 +412,51 @@ impl<T: TimeZone + 'static> IntervalConstraint<T> for MonthDay where <T as TimeZ
     }
 }
 
+/// Date written with numbers only, e.g. "3/4" or "3/4/2015", whose day and month are read in the
+/// order of the context, or else in the order of the grammar
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NumericDate {
+    pub first: u32,
+    pub second: u32,
+    pub year: Option<i32>,
+    pub order: DateOrder,
+}
+
+impl NumericDate {
+    pub fn new<T: TimeZone + 'static>(first: u32, second: u32, year: Option<i32>, order: DateOrder) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(NumericDate { first, second, year, order })
+    }
+
+    /// Month and day of the date when read in the given order
+    pub fn month_day(&self, order: DateOrder) -> (u32, u32) {
+        match order {
+            DateOrder::MonthDay => (self.first, self.second),
+            DateOrder::DayMonth => (self.second, self.first),
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for NumericDate where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (month, day) = self.month_day(context.date_format.order.unwrap_or(self.order));
+        if month < 1 || month > 12 || day < 1 || day > 31 {
+            return BidirectionalWalker::new()
+        }
+        match self.year {
+            Some(year) => YearMonthDay { year, month, day }.to_walker(origin, context),
+            None => MonthDay(month, day).to_walker(origin, context),
+        }
+    }
+}
+
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Month(pub u32);
@@ -364,6 +571,139 @@ impl<T: TimeZone> IntervalConstraint<T> for DayOfWeek where <T as TimeZone>::Off
 }
 
 
+/// Period of the day, spanning the hours set in the context or else the default ones of the grammar
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DayPeriodWindow {
+    pub period: DayPeriod,
+    pub start_hour: u32,
+    pub end_hour: u32,
+}
+
+impl DayPeriodWindow {
+    pub fn new<T: TimeZone + 'static>(period: DayPeriod, start_hour: u32, end_hour: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(DayPeriodWindow { period: period, start_hour: start_hour, end_hour: end_hour })
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for DayPeriodWindow where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (start_hour, end_hour) = context.day_periods.window(self.period)
+            .unwrap_or((self.start_hour, self.end_hour));
+        Hour::clock_24(start_hour)
+            .span_to(&Hour::clock_24(end_hour))
+            .to_walker(origin, context)
+    }
+}
+
+/// Part of a period of the day, such as the early evening. `[start_hour, end_hour)` lies within
+/// the default window of the period, and is moved and stretched along with it when the context
+/// sets other hours for the period.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DayPeriodPart {
+    pub window: DayPeriodWindow,
+    pub start_hour: u32,
+    pub end_hour: u32,
+}
+
+impl DayPeriodPart {
+    pub fn new<T: TimeZone + 'static>(window: DayPeriodWindow, start_hour: u32, end_hour: u32) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(DayPeriodPart { window: window, start_hour: start_hour, end_hour: end_hour })
+    }
+
+    /// Hours of the part once its period spans `[start_hour, end_hour)`. The hours of the part are
+    /// taken as offsets from the start of the default window, which may fall a bit before or after
+    /// it, and are stretched by the ratio of the two window lengths.
+    fn hours_within(&self, start_hour: u32, end_hour: u32) -> (u32, u32) {
+        // windows may wrap around midnight, and an empty one stands for the whole day
+        let length = |start: u32, end: u32| ((end + 24 - start + 23) % 24 + 1) as f64;
+        let default_length = length(self.window.start_hour, self.window.end_hour);
+        let new_length = length(start_hour, end_hour);
+        // offsets are centered on the default window
+        let margin = (24.0 - default_length) / 2.0;
+        let offset = |hour: u32| {
+            let offset = ((hour + 24 - self.window.start_hour) % 24) as f64;
+            if offset >= default_length + margin { offset - 24.0 } else { offset }
+        };
+        let stretch = |offset: f64| {
+            let hour = start_hour as i64 + (offset * new_length / default_length).round() as i64;
+            ((hour % 24 + 24) % 24) as u32
+        };
+        (stretch(offset(self.start_hour)), stretch(offset(self.end_hour)))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for DayPeriodPart where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (start_hour, end_hour) = match context.day_periods.window(self.window.period) {
+            Some((start_hour, end_hour)) => self.hours_within(start_hour, end_hour),
+            None => (self.start_hour, self.end_hour),
+        };
+        Hour::clock_24(start_hour)
+            .span_to(&Hour::clock_24(end_hour))
+            .to_walker(origin, context)
+    }
+}
+
+/// Weekend of the context, starting at 6pm the day before its first day when `from_evening` is set
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Weekend {
+    pub from_evening: bool,
+}
+
+impl Weekend {
+    pub fn new<T: TimeZone>(from_evening: bool) -> RcConstraint<T> where <T as TimeZone>::Offset: Copy {
+        rc!(Weekend { from_evening: from_evening })
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for Weekend where <T as TimeZone>::Offset: Copy {
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let from_evening = self.from_evening;
+        let days = context.week.weekend_days as i64;
+        let weekend_of = move |first_day: Moment<T>| {
+            let start = if from_evening {
+                first_day - PeriodComp::days(1) + PeriodComp::hours(18)
+            } else {
+                first_day
+            };
+            Interval { start: start, end: Some(first_day + PeriodComp::days(days)), grain: Grain::Hour }
+        };
+        let offset = (context.week.weekend_start.number_from_monday() as i64 -
+                      origin.start.weekday().number_from_monday() as i64 + 7) % 7;
+        let anchor = weekend_of(origin.start.round_to(Grain::Day) + PeriodComp::days(offset));
+
+        BidirectionalWalker::new()
+            .forward_with(anchor, |prev| prev + PeriodComp::weeks(1))
+            .backward_with(anchor - PeriodComp::weeks(1),
+                           |prev| prev - PeriodComp::weeks(1))
+    }
+}
+
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct HourMinute {
     pub hour: u32,
@@ -554,8 +894,12 @@ impl<T: TimeZone> IntervalConstraint<T> for Cycle where <T as TimeZone>::Offset:
         self.0
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let anchor = origin.start_round_to(self.0);
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let anchor = match self.0 {
+            Grain::Week => origin.start_round_to_week(context.week.start),
+            Grain::Quarter => origin.start_round_to_quarter(context.fiscal_calendar.start_month),
+            grain => origin.start_round_to(grain),
+        };
         let grain = self.0;
         BidirectionalWalker::new()
             .forward_with(anchor, move |prev| prev + PeriodComp::new(grain, 1))
@@ -820,7 +1164,7 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for Intersection<T>  where <T as
                      constraint: RcConstraint<U>)
                      -> Walker<Interval<U>> where <U as TimeZone>::Offset: Copy 
         {
-            let context = Context::new(context.reference, *origin, *origin);
+            let context = Context { min: *origin, max: *origin, ..context };
             let max_moment = origin.end_moment();
             let origin_copied = origin.clone();
             constraint
@@ -1007,11 +1351,11 @@ impl<T: TimeZone+'static> IntervalConstraint<T> for ShiftBy<T>  where <T as Time
             let grain_after_shift = period_grain.after_shift();
             let translate = Translate {
                 generator: self.base.clone(),
-                offset: Rc::new(move |i: &Interval<T>, _: &Context<T>| -> Option<Interval<T>> {
+                offset: Rc::new(move |i: &Interval<T>, context: &Context<T>| -> Option<Interval<T>> {
                     if is_forward && i.is_span() {
-                        Some(i.after().interval_round_to(grain_after_shift) + &period)
+                        Some(i.after().interval_round_to(grain_after_shift, context.week.start) + &period)
                     } else {
-                        Some(i.interval_round_to(grain_after_shift) + &period)
+                        Some(i.interval_round_to(grain_after_shift, context.week.start) + &period)
                     }
                 }),
             };
@@ -1403,6 +1747,150 @@ mod tests {
     }
 
 
+    #[test]
+    fn test_weekend() {
+        // Day of week => Tuesday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = Weekend { from_evening: true }.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 28).and_hms(18, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 05, 01).and_hms(0, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+
+        let week = Week { weekend_start: Weekday::Fri, ..Week::default() };
+        let context = context.with_week(week);
+        let walker = Weekend { from_evening: false }.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 28).and_hms(0, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 30).and_hms(0, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_day_period_window() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let lunch = DayPeriodWindow { period: DayPeriod::Lunch, start_hour: 12, end_hour: 14 };
+        let walker = lunch.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 25).and_hms(12, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 25).and_hms(14, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+
+        let context = context.with_day_periods(DayPeriods::default().with_window(DayPeriod::Lunch, 13, 15));
+        let walker = lunch.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 25).and_hms(13, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 25).and_hms(15, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_day_period_part() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let evening = DayPeriodWindow { period: DayPeriod::Evening, start_hour: 18, end_hour: 0 };
+        let late_evening = DayPeriodPart { window: evening, start_hour: 21, end_hour: 0 };
+        let walker = late_evening.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 25).and_hms(21, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 26).and_hms(0, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+
+        let context = context.with_day_periods(DayPeriods::default().with_window(DayPeriod::Evening, 17, 23));
+        let walker = late_evening.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 25).and_hms(20, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 25).and_hms(23, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+
+        let night = DayPeriodWindow { period: DayPeriod::Night, start_hour: 0, end_hour: 4 };
+        let early_night = DayPeriodPart { window: night, start_hour: 21, end_hour: 0 };
+        let context = context.with_day_periods(DayPeriods::default().with_window(DayPeriod::Night, 1, 5));
+        let walker = early_night.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::new(Moment(Paris.ymd(2017, 04, 25).and_hms(22, 0, 0)),
+                                      Some(Moment(Paris.ymd(2017, 04, 26).and_hms(1, 0, 0))),
+                                      Grain::Hour)),
+                   walker.forward.clone().next());
+    }
+
+    #[test]
+    fn test_fiscal_year() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = FiscalYear(Some(2018)).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2018, 01, 1).and_hms(0, 0, 0)), Grain::Year)),
+                   walker.forward.clone().next());
+
+        let context = context.with_fiscal_calendar(FiscalCalendar { start_month: 10 });
+        let walker = FiscalYear(Some(2018)).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 10, 1).and_hms(0, 0, 0)), Grain::Year)),
+                   walker.forward.clone().next());
+        let walker = FiscalYear(None).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2016, 10, 1).and_hms(0, 0, 0)), Grain::Year)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2015, 10, 1).and_hms(0, 0, 0)), Grain::Year)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_numeric_date() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let date = NumericDate { first: 3, second: 4, year: Some(2015), order: DateOrder::MonthDay };
+        let walker = date.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2015, 03, 4).and_hms(0, 0, 0)), Grain::Day)),
+                   walker.backward.clone().next());
+
+        let context = context.with_date_format(DateFormat { order: Some(DateOrder::DayMonth), ..DateFormat::default() });
+        let walker = date.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2015, 04, 3).and_hms(0, 0, 0)), Grain::Day)),
+                   walker.backward.clone().next());
+
+        let date = NumericDate { first: 4, second: 31, year: None, order: DateOrder::MonthDay };
+        let walker = date.to_walker(&context.reference, &context);
+        assert_eq!(None, walker.forward.clone().next());
+        assert_eq!(None, walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_year_pivot() {
+        let date_format = DateFormat::default();
+        assert_eq!(1974, date_format.full_year(74));
+        assert_eq!(2049, date_format.full_year(49));
+        assert_eq!(1999, date_format.full_year(99));
+        assert_eq!(2015, date_format.full_year(2015));
+
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)))
+            .with_date_format(DateFormat { year_pivot: 30, ..DateFormat::default() });
+        let walker = YearMonthDay::new(45, 10, 31).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(1945, 10, 31).and_hms(0, 0, 0)), Grain::Day)),
+                   walker.backward.clone().next());
+    }
+
+    #[test]
+    fn test_quarter_cycle_with_fiscal_calendar() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)))
+            .with_fiscal_calendar(FiscalCalendar { start_month: 2 });
+        let walker = Cycle(Grain::Quarter).to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 02, 1).and_hms(0, 0, 0)), Grain::Quarter)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 05, 1).and_hms(0, 0, 0)), Grain::Quarter)),
+                   walker.forward.clone().skip(1).next());
+    }
+
+    #[test]
+    fn test_week_cycle_with_week_start() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let cycle = Cycle(Grain::Week);
+        let walker = cycle.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 24).and_hms(0, 0, 0)), Grain::Week)),
+                   walker.forward.clone().next());
+
+        let context = context.with_week(Week { start: Weekday::Sun, ..Week::default() });
+        let walker = cycle.to_walker(&context.reference, &context);
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)), Grain::Week)),
+                   walker.forward.clone().next());
+        assert_eq!(Some(Interval::starting_at(Moment(Paris.ymd(2017, 04, 16).and_hms(0, 0, 0)), Grain::Week)),
+                   walker.backward.clone().next());
+    }
+
     #[test]
     fn test_day_of_week_before() {
         // Day of week => Tuesday
@@ -9,16 +9,18 @@ mod period;
 pub mod interval_constraints;
 pub mod bidirectional_walker;
 pub mod walker;
+mod zone;
 
 use std::ops;
 use std::cmp::Ordering;
 use std::fmt;
 
-use chrono::{Duration, Datelike, Timelike};
-pub use chrono::{Weekday, Local, TimeZone};
-use chrono::datetime::DateTime;
+use chrono::{Duration, LocalResult, NaiveDate, NaiveDateTime};
+pub use chrono::{Weekday, Local, FixedOffset, TimeZone, Datelike, Timelike};
+pub use chrono::datetime::DateTime;
 pub use interval_constraints::*;
 pub use period::*;
+pub use zone::*;
 
 
 #[derive(Clone)]
@@ -58,6 +60,16 @@ impl<T: TimeZone> fmt::Display for Moment<T> where T::Offset: fmt::Display {
     }
 }
 
+/// Builds the moment at the given wall-clock time. Times skipped by a daylight saving transition
+/// are moved forward, and the earliest instant is taken for times repeated by a transition.
+fn at_wall_clock<T: TimeZone>(tz: &T, local: NaiveDateTime) -> DateTime<T> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(moment) => moment,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => at_wall_clock(tz, local + Duration::hours(1)),
+    }
+}
+
 fn last_day_in_month<T: TimeZone>(y: i32, m: u32, tz: T) -> u32 {
     assert!(m >= 1 && m <= 12);
     for d in 28..31 {
@@ -75,6 +87,12 @@ impl<T: TimeZone> ops::Deref for Moment<T> {
     }
 }
 
+impl<T: TimeZone> Moment<T> {
+    pub fn with_timezone<Tz: TimeZone>(&self, tz: &Tz) -> Moment<Tz> {
+        Moment(self.0.with_timezone(tz))
+    }
+}
+
 impl Moment<Local> {
     pub fn now() -> Moment<Local> {
         Moment(Local::now())
@@ -111,32 +129,57 @@ impl<T: TimeZone> Moment<T> where <T as TimeZone>::Offset: Copy {
         };
         let target_month_days = last_day_in_month(year, month0 + 1, self.timezone());
         let day = ::std::cmp::min(target_month_days, self.day());
-        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms(self.hour(), self.minute(), self.second()))
+        let local = NaiveDate::from_ymd(year, month0 + 1, day).and_hms(self.hour(), self.minute(), self.second());
+        Moment(at_wall_clock(&self.timezone(), local))
     }
 
-    fn round_to(self, g: Grain) -> Moment<T> {
+    pub fn round_to(self, g: Grain) -> Moment<T> {
+        let date = self.0.naive_local().date();
+        let tz = self.timezone();
         match g {
-            Grain::Year => Moment(self.timezone().ymd(self.year(), 1, 1).and_hms(0, 0, 0)),
-            Grain::Month => Moment(self.timezone().ymd(self.year(), self.month(), 1).and_hms(0, 0, 0)),
-            Grain::Day => Moment(self.date().and_hms(0, 0, 0)),
-            Grain::Hour => Moment(self.date().and_hms(self.hour(), 0, 0)),
-            Grain::Minute => Moment(self.date().and_hms(self.hour(), self.minute(), 0)),
+            Grain::Year => Moment(at_wall_clock(&tz, date.with_ordinal(1).unwrap().and_hms(0, 0, 0))),
+            Grain::Month => Moment(at_wall_clock(&tz, date.with_day(1).unwrap().and_hms(0, 0, 0))),
+            Grain::Day => Moment(at_wall_clock(&tz, date.and_hms(0, 0, 0))),
+            Grain::Hour => Moment(at_wall_clock(&tz, date.and_hms(self.hour(), 0, 0))),
+            Grain::Minute => Moment(at_wall_clock(&tz, date.and_hms(self.hour(), self.minute(), 0))),
             Grain::Second => self,
-            Grain::Week => {
-                // shift to monday morning
-                let day_offset = self.weekday().num_days_from_monday(); // monday is 0 here
-                self.round_to(Grain::Day) - PeriodComp::days(day_offset as i64)
-            }
-            Grain::Quarter => {
-                self.round_to(Grain::Month) - PeriodComp::months(self.month0() as i64 % 3)
-            }
+            Grain::Week => self.round_to_week(Weekday::Mon),
+            Grain::Quarter => self.round_to_quarter(1),
         }
     }
 
-    fn adjust_for_daylight_saving(self) -> Moment<T> {
-        Moment(self.timezone()
-                   .ymd(self.year(), self.month(), self.day())
-                   .and_hms(self.hour(), self.minute(), self.second()))
+    /// Rounds to the morning of the last `week_start`, weeks starting on monday for `round_to`
+    pub fn round_to_week(self, week_start: Weekday) -> Moment<T> {
+        let day_offset = (7 + self.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+        self.round_to(Grain::Day) - PeriodComp::days(day_offset as i64)
+    }
+
+    /// Rounds like `round_to`, weeks starting on `week_start`
+    pub fn round_to_with_week_start(self, g: Grain, week_start: Weekday) -> Moment<T> {
+        match g {
+            Grain::Week => self.round_to_week(week_start),
+            g => self.round_to(g),
+        }
+    }
+
+    /// Rounds to the first day of the quarter, quarters starting in `first_month` and every three
+    /// months from it
+    pub fn round_to_quarter(self, first_month: u32) -> Moment<T> {
+        let month_offset = (self.month0() + 12 - (first_month - 1) % 3) % 3;
+        self.round_to(Grain::Month) - PeriodComp::months(month_offset as i64)
+    }
+
+    /// Reads the wall-clock time of this moment as a time `utc_offset` minutes away from UTC,
+    /// and converts it back to the timezone of the moment.
+    pub fn reinterpret_at_utc_offset(self, utc_offset: i32) -> Moment<T> {
+        let utc = self.0.naive_local() - Duration::minutes(utc_offset as i64);
+        Moment(self.timezone().from_utc_datetime(&utc))
+    }
+
+    /// Shifts the wall-clock time of the moment, so that adding days keeps the time of the day
+    /// across daylight saving transitions.
+    fn shift_wall_clock(self, duration: Duration) -> Moment<T> {
+        Moment(at_wall_clock(&self.timezone(), self.0.naive_local() + duration))
     }
 }
 
@@ -177,10 +220,8 @@ impl<'a, T: TimeZone> ops::Add<&'a PeriodComp> for Moment<T>  where <T as TimeZo
             Grain::Year => self.add_months(12 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
-            Grain::Week => {
-                Moment(self.0 + Duration::weeks(p.quantity)).adjust_for_daylight_saving()
-            }
-            Grain::Day => Moment(self.0 + Duration::days(p.quantity)).adjust_for_daylight_saving(),
+            Grain::Week => self.shift_wall_clock(Duration::weeks(p.quantity)),
+            Grain::Day => self.shift_wall_clock(Duration::days(p.quantity)),
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
@@ -213,6 +254,14 @@ impl<T: TimeZone> Interval<T> {
     fn timezone(&self) -> T {
         self.start.0.timezone()
     }
+
+    pub fn with_timezone<Tz: TimeZone>(&self, tz: &Tz) -> Interval<Tz> {
+        Interval {
+            start: self.start.with_timezone(tz),
+            end: self.end.as_ref().map(|end| end.with_timezone(tz)),
+            grain: self.grain,
+        }
+    }
 }
 
 impl Interval<Local> {
@@ -244,11 +293,27 @@ impl<T: TimeZone> Interval<T> where <T as TimeZone>::Offset: Copy {
         }
     }
 
-    fn interval_round_to(self, g: Grain) -> Interval<T> {
+    fn start_round_to_week(self, week_start: Weekday) -> Interval<T> {
         Interval {
-            start: self.start.round_to(g),
+            start: self.start.round_to_week(week_start),
+            grain: Grain::Week,
+            end: None,
+        }
+    }
+
+    fn start_round_to_quarter(self, first_month: u32) -> Interval<T> {
+        Interval {
+            start: self.start.round_to_quarter(first_month),
+            grain: Grain::Quarter,
+            end: None,
+        }
+    }
+
+    fn interval_round_to(self, g: Grain, week_start: Weekday) -> Interval<T> {
+        Interval {
+            start: self.start.round_to_with_week_start(g, week_start),
             grain: g,
-            end: self.end.map(|it| it.round_to(g)),
+            end: self.end.map(|it| it.round_to_with_week_start(g, week_start)),
         }
     }
 
@@ -388,7 +453,7 @@ impl<T: TimeZone> ops::Sub<Period> for Interval<T> where <T as TimeZone>::Offset
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{TimeZone, FixedOffset, NaiveDate, NaiveDateTime, LocalResult};
+    use chrono::{Duration, TimeZone, FixedOffset, NaiveDate, NaiveDateTime, LocalResult};
 
     #[derive(Copy, Clone, PartialEq, Eq)]
     struct Paris;
@@ -441,6 +506,15 @@ mod tests {
                    Moment(FixedOffset::east(1*3600).ymd(2017, 02, 26).and_hms(2, 30, 00)).add_months(1));
     }
 
+    #[test]
+    fn reinterpret_moment_at_utc_offset() {
+        // 3pm EST is 8pm UTC, 10pm in Paris
+        assert_eq!(Moment(Paris.ymd(2017, 04, 25).and_hms(22, 0, 0)),
+                   Moment(Paris.ymd(2017, 04, 25).and_hms(15, 0, 0)).reinterpret_at_utc_offset(-300));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 25).and_hms(0, 30, 0)),
+                   Moment(Paris.ymd(2017, 04, 25).and_hms(7, 30, 0)).reinterpret_at_utc_offset(540));
+    }
+
     #[test]
     fn add_period_comp_to_moment() {
         let now = Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11));
@@ -495,6 +569,66 @@ mod tests {
                    Moment(FixedOffset::east(1*3600).ymd(2017, 04, 26).and_hms(2, 30, 00)).add_months(-1));
     }
 
+    /// Central European time in 2017, switching to summer time on March 26th at 2am.
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    struct ParisDst;
+
+    impl ParisDst {
+        fn transition() -> NaiveDateTime {
+            NaiveDate::from_ymd(2017, 3, 26).and_hms(1, 0, 0)
+        }
+    }
+
+    impl TimeZone for ParisDst {
+        type Offset = FixedOffset;
+        fn from_offset(_: &FixedOffset) -> ParisDst { ParisDst }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            if *local < ParisDst::transition() + Duration::hours(1) {
+                LocalResult::Single(FixedOffset::east(3600))
+            } else if *local < ParisDst::transition() + Duration::hours(2) {
+                LocalResult::None
+            } else {
+                LocalResult::Single(FixedOffset::east(2*3600))
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+        }
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            if *utc < ParisDst::transition() { FixedOffset::east(3600) } else { FixedOffset::east(2*3600) }
+        }
+    }
+
+    #[test]
+    fn add_days_across_daylight_saving_transition() {
+        assert_eq!(Moment(FixedOffset::east(2*3600).ymd(2017, 03, 26).and_hms(9, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 25).and_hms(9, 0, 0)) + PeriodComp::days(1));
+        assert_eq!(Moment(FixedOffset::east(2*3600).ymd(2017, 04, 1).and_hms(0, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 25).and_hms(0, 0, 0)) + PeriodComp::weeks(1));
+        // 2:30am does not exist on the day of the transition
+        assert_eq!(Moment(FixedOffset::east(2*3600).ymd(2017, 03, 26).and_hms(3, 30, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 25).and_hms(2, 30, 0)) + PeriodComp::days(1));
+        assert_eq!(Moment(FixedOffset::east(2*3600).ymd(2017, 03, 26).and_hms(3, 30, 0)),
+                   Moment(ParisDst.ymd(2017, 02, 26).and_hms(2, 30, 0)).add_months(1));
+        assert_eq!(Moment(FixedOffset::east(2*3600).ymd(2017, 03, 26).and_hms(3, 0, 0)),
+                   Moment(ParisDst.ymd(2017, 03, 26).and_hms(4, 0, 0)).round_to(Grain::Day) + PeriodComp::hours(2));
+    }
+
+    #[test]
+    fn moment_in_fixed_zone() {
+        let zone = Zone::Fixed(FixedOffset::west(5*3600));
+        let moment = Moment(Paris.ymd(2017, 04, 25).and_hms(22, 0, 0)).with_timezone(&zone);
+        assert_eq!(Moment(FixedOffset::west(5*3600).ymd(2017, 04, 25).and_hms(15, 0, 0)), moment);
+        assert_eq!(15, moment.hour());
+        assert_eq!(Moment(zone.ymd(2017, 04, 25).and_hms(0, 0, 0)), moment.round_to(Grain::Day));
+        assert_eq!(Moment(zone.ymd(2017, 05, 25).and_hms(15, 0, 0)), moment + PeriodComp::months(1));
+    }
+
     #[test]
     fn daylight_saving_aware() {
         // TODO Take a look at the offset shifting due to a period addition        // 1st March -> +1 and 31 Match -> +2        // 1st March + 30 days -> +1 instead of +2
@@ -519,8 +653,38 @@ mod tests {
                    now.round_to(Grain::Second));
         assert_eq!(Moment(Paris.ymd(2017, 04, 24).and_hms(0, 0, 0)),
                    now.round_to(Grain::Week));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)),
+                   now.round_to_week(Weekday::Sun));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 22).and_hms(0, 0, 0)),
+                   now.round_to_week(Weekday::Sat));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 25).and_hms(0, 0, 0)),
+                   now.round_to_week(Weekday::Tue));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)),
+                   now.round_to_with_week_start(Grain::Week, Weekday::Sun));
+        assert_eq!(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 0, 0)),
+                   now.round_to_with_week_start(Grain::Hour, Weekday::Sun));
         assert_eq!(Moment(Paris.ymd(2017, 04, 01).and_hms(0, 0, 0)),
                    now.round_to(Grain::Quarter));
+        assert_eq!(Moment(Paris.ymd(2017, 02, 01).and_hms(0, 0, 0)),
+                   now.round_to_quarter(2));
+        assert_eq!(Moment(Paris.ymd(2017, 03, 01).and_hms(0, 0, 0)),
+                   now.round_to_quarter(12));
+    }
+
+    #[test]
+    fn interval_round_to_week_start() {
+        let interval = Interval {
+            start: Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)),
+            grain: Grain::Second,
+            end: Some(Moment(Paris.ymd(2017, 05, 03).and_hms(9, 10, 11))),
+        };
+        let monday = interval.interval_round_to(Grain::Week, Weekday::Mon);
+        assert_eq!(Moment(Paris.ymd(2017, 04, 24).and_hms(0, 0, 0)), monday.start);
+        assert_eq!(Some(Moment(Paris.ymd(2017, 05, 01).and_hms(0, 0, 0))), monday.end);
+        let sunday = interval.interval_round_to(Grain::Week, Weekday::Sun);
+        assert_eq!(Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)), sunday.start);
+        assert_eq!(Some(Moment(Paris.ymd(2017, 04, 30).and_hms(0, 0, 0))), sunday.end);
+        assert_eq!(Grain::Week, sunday.grain);
     }
 
     #[test]
@@ -1,4 +1,6 @@
 use std::ops;
+use std::time;
+use chrono;
 use vec_map::VecMap;
 
 enum_from_primitive! {
@@ -118,6 +120,130 @@ impl Period {
     pub fn coarse_num_secs(&self) -> i64 {
         self.comps().iter().map(|it| it.coarse_num_secs()).sum()
     }
+
+    fn get(&self, grain: Grain) -> i64 {
+        *self.0.get(grain as usize).unwrap_or(&0)
+    }
+
+    /// Total number of seconds of the period. Days are 24 hours long, and years and months are
+    /// taken with their average length in the Gregorian calendar: 365.2425 days for a year and a
+    /// twelfth of it for a month, that is 30.436875 days.
+    pub fn total_seconds(&self) -> i64 {
+        const SECONDS_PER_YEAR: i64 = 31_556_952;
+        let months = 12 * self.get(Grain::Year) + 3 * self.get(Grain::Quarter) + self.get(Grain::Month);
+        let days = 7 * self.get(Grain::Week) + self.get(Grain::Day);
+        months * SECONDS_PER_YEAR / 12 + days * 86_400 + self.get(Grain::Hour) * 3600
+            + self.get(Grain::Minute) * 60 + self.get(Grain::Second)
+    }
+
+    /// Carries the seconds over into minutes, hours and days, and the months over into years, so
+    /// that "1 day 36 hours" becomes "2 days 12 hours". Days are never carried into months as their
+    /// number varies; quarters and weeks are expressed as months and days.
+    pub fn normalized(&self) -> Period {
+        let months = 12 * self.get(Grain::Year) + 3 * self.get(Grain::Quarter) + self.get(Grain::Month);
+        let seconds = (7 * self.get(Grain::Week) + self.get(Grain::Day)) * 86_400
+            + self.get(Grain::Hour) * 3600 + self.get(Grain::Minute) * 60 + self.get(Grain::Second);
+        let comps = [
+            PeriodComp::years(months / 12),
+            PeriodComp::months(months % 12),
+            PeriodComp::days(seconds / 86_400),
+            PeriodComp::hours(seconds % 86_400 / 3600),
+            PeriodComp::minutes(seconds % 3600 / 60),
+            PeriodComp::seconds(seconds % 60),
+        ];
+        comps.iter()
+            .filter(|comp| comp.quantity != 0)
+            .fold(Period::default(), |period, comp| period + comp)
+    }
+
+    /// Length of the period as defined by `total_seconds`, None if the period is negative
+    pub fn to_std_duration(&self) -> Option<time::Duration> {
+        let seconds = self.total_seconds();
+        if seconds >= 0 { Some(time::Duration::from_secs(seconds as u64)) } else { None }
+    }
+
+    /// Length of the period as defined by `total_seconds`
+    pub fn to_chrono_duration(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.total_seconds())
+    }
+
+    /// Formats the period as an ISO 8601 duration, e.g. "P1Y2M3DT4H5M6S". Quarters are written
+    /// as months, and weeks as days unless the period is only made of weeks ("P2W").
+    /// Negative periods are prefixed with a minus sign.
+    pub fn to_iso8601(&self) -> String {
+        if self.0.values().all(|q| *q <= 0) && self.0.values().any(|q| *q < 0) {
+            return format!("-{}", (-self).to_iso8601())
+        }
+        let weeks = self.get(Grain::Week);
+        if weeks != 0 && self.0.iter().all(|(g, q)| g == Grain::Week as usize || *q == 0) {
+            return format!("P{}W", weeks)
+        }
+        let months = 3 * self.get(Grain::Quarter) + self.get(Grain::Month);
+        let days = 7 * weeks + self.get(Grain::Day);
+        let mut result = "P".to_string();
+        for &(quantity, unit) in &[(self.get(Grain::Year), 'Y'), (months, 'M'), (days, 'D')] {
+            if quantity != 0 {
+                result.push_str(&format!("{}{}", quantity, unit));
+            }
+        }
+        let time = [(self.get(Grain::Hour), 'H'), (self.get(Grain::Minute), 'M'), (self.get(Grain::Second), 'S')];
+        if time.iter().any(|&(quantity, _)| quantity != 0) {
+            result.push('T');
+            for &(quantity, unit) in &time {
+                if quantity != 0 {
+                    result.push_str(&format!("{}{}", quantity, unit));
+                }
+            }
+        }
+        if result == "P" {
+            result.push_str("T0S");
+        }
+        result
+    }
+
+    /// Parses an ISO 8601 duration with integral values, e.g. "P3Y6M4DT12H30M5S" or "-P2W".
+    pub fn from_iso8601(text: &str) -> Option<Period> {
+        let (negative, text) = if text.starts_with('-') { (true, &text[1..]) } else { (false, text) };
+        if !text.starts_with('P') || text.len() == 1 || text.ends_with('T') {
+            return None
+        }
+        let mut period = Period::default();
+        let mut in_time = false;
+        let mut number = String::new();
+        let mut last_grain = None;
+        for c in text[1..].chars() {
+            let grain = match (c, in_time) {
+                ('T', false) if number.is_empty() => {
+                    in_time = true;
+                    continue
+                }
+                (c, _) if c.is_digit(10) => {
+                    number.push(c);
+                    continue
+                }
+                ('Y', false) => Grain::Year,
+                ('M', false) => Grain::Month,
+                ('W', false) => Grain::Week,
+                ('D', false) => Grain::Day,
+                ('H', true) => Grain::Hour,
+                ('M', true) => Grain::Minute,
+                ('S', true) => Grain::Second,
+                _ => return None,
+            };
+            // components must be given once, from the coarsest to the finest
+            if number.is_empty() || last_grain.map(|last| last >= grain).unwrap_or(false) {
+                return None
+            }
+            let quantity = match number.parse::<i64>() {
+                Ok(quantity) => quantity,
+                Err(_) => return None,
+            };
+            period += PeriodComp::new(grain, if negative { -quantity } else { quantity });
+            number.clear();
+            last_grain = Some(grain);
+        }
+        if number.is_empty() { Some(period) } else { None }
+    }
 }
 
 impl From<PeriodComp> for Period {
@@ -383,4 +509,54 @@ mod tests {
         a.0.insert(Grain::Hour as usize, 4);
         assert_eq!(a.finer_grain(), Some(Grain::Hour));
     }
+
+    #[test]
+    fn period_total_seconds() {
+        let period = Period::default() + PeriodComp::days(1) + PeriodComp::hours(36);
+        assert_eq!(216_000, period.total_seconds());
+        assert_eq!(2_629_746, Period::from(PeriodComp::months(1)).total_seconds());
+        assert_eq!(31_556_952, Period::from(PeriodComp::quarters(4)).total_seconds());
+        assert_eq!(Some(::std::time::Duration::from_secs(216_000)), period.to_std_duration());
+        assert_eq!(::chrono::Duration::hours(60), period.to_chrono_duration());
+        assert_eq!(None, (-period).to_std_duration());
+    }
+
+    #[test]
+    fn normalize_period() {
+        let period = Period::default() + PeriodComp::days(1) + PeriodComp::hours(36) + PeriodComp::seconds(90);
+        assert_eq!(Period::default() + PeriodComp::days(2) + PeriodComp::hours(12) + PeriodComp::minutes(1) + PeriodComp::seconds(30),
+                   period.normalized());
+        let period = Period::default() + PeriodComp::quarters(3) + PeriodComp::months(5) + PeriodComp::weeks(1);
+        assert_eq!(Period::default() + PeriodComp::years(1) + PeriodComp::months(2) + PeriodComp::days(7),
+                   period.normalized());
+        assert_eq!(-Period::from(PeriodComp::hours(25)).normalized(),
+                   Period::from(PeriodComp::hours(-25)).normalized());
+    }
+
+    #[test]
+    fn format_iso8601_period() {
+        let period = Period::default() + PeriodComp::years(3) + PeriodComp::months(6) + PeriodComp::days(4)
+            + PeriodComp::hours(12) + PeriodComp::minutes(30) + PeriodComp::seconds(5);
+        assert_eq!("P3Y6M4DT12H30M5S", period.to_iso8601());
+        assert_eq!("P2W", Period::from(PeriodComp::weeks(2)).to_iso8601());
+        assert_eq!("P1Y3M10D", (Period::default() + PeriodComp::years(1) + PeriodComp::quarters(1)
+            + PeriodComp::weeks(1) + PeriodComp::days(3)).to_iso8601());
+        assert_eq!("PT36H", Period::from(PeriodComp::hours(36)).to_iso8601());
+        assert_eq!("-P1DT2H", (-(Period::default() + PeriodComp::days(1) + PeriodComp::hours(2))).to_iso8601());
+        assert_eq!("PT0S", Period::default().to_iso8601());
+    }
+
+    #[test]
+    fn parse_iso8601_period() {
+        let period = Period::default() + PeriodComp::years(3) + PeriodComp::months(6) + PeriodComp::days(4)
+            + PeriodComp::hours(12) + PeriodComp::minutes(30) + PeriodComp::seconds(5);
+        assert_eq!(Some(period.clone()), Period::from_iso8601("P3Y6M4DT12H30M5S"));
+        assert_eq!(Some(period.clone()), Period::from_iso8601(&period.to_iso8601()));
+        assert_eq!(Some(Period::from(PeriodComp::minutes(1))), Period::from_iso8601("PT1M"));
+        assert_eq!(Some(Period::from(PeriodComp::months(1))), Period::from_iso8601("P1M"));
+        assert_eq!(Some(Period::from(PeriodComp::weeks(-2))), Period::from_iso8601("-P2W"));
+        for invalid in &["", "P", "PT", "1D", "P1H", "PT1D", "P1D2Y", "PD", "P1.5D", "P1DT"] {
+            assert_eq!(None, Period::from_iso8601(invalid));
+        }
+    }
 }
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use chrono::{FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+
+/// Timezone chosen at runtime: either the timezone of the machine, or a fixed offset from UTC.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Zone {
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl Default for Zone {
+    fn default() -> Zone {
+        Zone::Local
+    }
+}
+
+/// Offset of a `Zone` at a given time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ZoneOffset {
+    zone: Zone,
+    offset: FixedOffset,
+}
+
+impl ZoneOffset {
+    fn with_zone(zone: Zone, result: LocalResult<FixedOffset>) -> LocalResult<ZoneOffset> {
+        match result {
+            LocalResult::None => LocalResult::None,
+            LocalResult::Single(offset) => LocalResult::Single(ZoneOffset { zone, offset }),
+            LocalResult::Ambiguous(earliest, latest) => {
+                LocalResult::Ambiguous(ZoneOffset { zone, offset: earliest },
+                                       ZoneOffset { zone, offset: latest })
+            }
+        }
+    }
+}
+
+impl Offset for ZoneOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Display for ZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.offset)
+    }
+}
+
+impl TimeZone for Zone {
+    type Offset = ZoneOffset;
+
+    fn from_offset(offset: &ZoneOffset) -> Zone {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<ZoneOffset> {
+        match *self {
+            Zone::Local => ZoneOffset::with_zone(*self, Local.offset_from_local_date(local)),
+            Zone::Fixed(offset) => LocalResult::Single(ZoneOffset { zone: *self, offset }),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<ZoneOffset> {
+        match *self {
+            Zone::Local => ZoneOffset::with_zone(*self, Local.offset_from_local_datetime(local)),
+            Zone::Fixed(offset) => LocalResult::Single(ZoneOffset { zone: *self, offset }),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> ZoneOffset {
+        let offset = match *self {
+            Zone::Local => Local.offset_from_utc_date(utc),
+            Zone::Fixed(offset) => offset,
+        };
+        ZoneOffset { zone: *self, offset }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        let offset = match *self {
+            Zone::Local => Local.offset_from_utc_datetime(utc),
+            Zone::Fixed(offset) => offset,
+        };
+        ZoneOffset { zone: *self, offset }
+    }
+}
@@ -30,13 +30,16 @@ extern crate rustling_ontology_values;
 
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, Range, Value, Sym, ParsingAnalysis};
 pub use rustling::errors::*;
-pub use grammar::{Lang, dims};
+pub use grammar::{Lang, Locale, dims};
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
-pub use rustling_ontology_values::{ResolverContext, IdentityContext, ParsingContext};
+pub use rustling_ontology_values::{ResolverContext, ResolutionPolicy, ClockPolicy, IdentityContext, ParsingContext};
+pub use rustling_ontology_values::currency::Currency;
+pub use rustling_ontology_values::locale::Region;
 pub use rustling_ontology_moment::Interval;
 pub use rustling_ontology_moment::Grain;
+pub use rustling_ontology_moment::{Zone, FixedOffset, Weekday, DayPeriod, DateOrder};
 
 mod parser;
 mod tagger;
@@ -48,7 +51,11 @@ pub use tagger::CandidateTagger;
 pub type RawParser = rustling::Parser<dimension::Dimension, parser::Feat, parser::FeatureExtractor>;
 
 /// Main class to be use at runtime.
-pub struct Parser(RawParser);
+pub struct Parser {
+    raw: RawParser,
+    /// Region of the locale of the parser, for the contexts having none
+    default_region: Option<Region>,
+}
 
 impl Parser {
     pub fn parse_with_kind_order(&self,
@@ -56,12 +63,13 @@ impl Parser {
                                  context: &ResolverContext,
                                  order: &[OutputKind])
                                  -> RustlingResult<Vec<ParserMatch<Output>>> {
+        let context = self.context(context);
         let tagger = CandidateTagger {
             order: order,
-            context: context,
+            context: &context,
             resolve_all_candidates: false,
         };
-        Ok(self.0.parse(input, &tagger)?
+        Ok(self.raw.parse(input, &tagger)?
             .into_iter()
             .filter_map(|m| {
                 if let Some(v) = m.value {
@@ -93,12 +101,13 @@ impl Parser {
                                     examples: Vec<&str>,
                                     context: &ResolverContext,
                                     order:  &[OutputKind]) -> RustlingResult<ParsingAnalysis> {
+        let context = self.context(context);
         let tagger = CandidateTagger {
             order: order,
-            context: context,
+            context: &context,
             resolve_all_candidates: false,
         };
-        self.0.analyse(examples, &tagger)
+        self.raw.analyse(examples, &tagger)
     }
 
     pub fn analyse(&self, examples: Vec<&str>, context: &ResolverContext) -> RustlingResult<ParsingAnalysis> {
@@ -107,42 +116,59 @@ impl Parser {
     }
 
     pub fn num_rules(&self) -> usize {
-        self.0.num_rules()
+        self.raw.num_rules()
     }
 
     pub fn num_text_patterns(&self) -> usize {
-        self.0.num_text_patterns()
+        self.raw.num_text_patterns()
+    }
+
+    fn context(&self, context: &ResolverContext) -> ResolverContext {
+        match self.default_region {
+            Some(region) => context.with_default_region(region),
+            None => *context,
+        }
     }
 }
 
 /// Obtain a parser for a given language.
 pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
-    build_raw_parser(lang).map(::Parser)
+    build_raw_parser(lang).map(|raw| Parser { raw, default_region: None })
 }
 
 
 
 /// Obtain a parser for a given language.
 pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
-    let rules = grammar::rules(lang)?;
-    let model = match lang {
-        Lang::DE => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..]) },
-        Lang::EN => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..]) },
-        Lang::ES => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp"))[..]) },
-        Lang::FR => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp"))[..]) },
-        Lang::JA => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp"))[..]) },
-        Lang::KO => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp"))[..]) },
-        Lang::ZH => { ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp"))[..]) },
-    }.map_err(|e| format!("{:?}", e))?;
+    build_raw_parser_for_locale(Locale::from(lang))
+}
+
+
+/// Obtain a parser for a given locale, with the rules of its region if it has some. The default
+/// region of the locale is used to resolve with the contexts having no region of their own.
+pub fn build_parser_for_locale(locale: Locale) -> RustlingResult<Parser> {
+    let default_region = locale.default_region();
+    build_raw_parser_for_locale(locale).map(|raw| Parser { raw, default_region: Some(default_region) })
+}
+
+/// Obtain a parser for a given locale, with the rules of its region if it has some.
+pub fn build_raw_parser_for_locale(locale: Locale) -> RustlingResult<RawParser> {
+    let locale = locale.grammar_locale();
+    let rules = grammar::rules_for_locale(locale)?;
+    let model = model_bytes(&locale.to_string().to_lowercase())
+        .ok_or_else(|| format!("No model for the locale {}", locale))?;
+    let model = ::rmp_serde::decode::from_read(model).map_err(|e| format!("{:?}", e))?;
     Ok(::RawParser::new(rules, model, ::parser::FeatureExtractor()))
 }
 
+// Models of the locales, generated by the build script
+include!(concat!(env!("OUT_DIR"), "/models.rs"));
 
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
     let rules = grammar::rules(lang)?;
     let examples = grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, ::parser::FeatureExtractor())?;
-    Ok(Parser(::rustling::Parser::new(rules, model, ::parser::FeatureExtractor())))
+    Ok(Parser { raw: ::rustling::Parser::new(rules, model, ::parser::FeatureExtractor()), default_region: None })
 }
 
 #[cfg(test)]
@@ -159,6 +185,338 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    fn test_phone_number_hides_its_digits() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse("call +33 6 12 34 56 78", &ctx).unwrap();
+        assert_eq!(1, result.len());
+        assert_eq!(Output::PhoneNumber(output::PhoneNumberOutput("+33612345678".to_string())), result[0].value);
+        assert_eq!((5, 22), (result[0].byte_range.0, result[0].byte_range.1));
+        for sentence in &["2013-02-12", "1.200.000", "1 200 000"] {
+            let result = parser.parse(sentence, &ctx).unwrap();
+            assert!(result.iter().all(|m| m.value.kind() != OutputKind::PhoneNumber), "{}", sentence);
+        }
+    }
+
+    #[test]
+    fn test_number_interval_dash() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        for sentence in &["555-1234", "1-800", "2017-2018"] {
+            let result = parser.parse(sentence, &ctx).unwrap();
+            assert!(result.iter().all(|m| m.value.kind() != OutputKind::NumberInterval), "{}", sentence);
+        }
+        let result = parser.parse_with_kind_order("between 3-5", &ctx, &[OutputKind::NumberInterval]).unwrap();
+        let interval: output::NumberIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((Some(3.0), Some(5.0)), (interval.from, interval.to));
+    }
+
+    #[test]
+    fn test_resolve_currency_with_region() {
+        let parser = build_parser(Lang::EN).unwrap();
+        for &(region, code) in &[(None, "USD"), (Region::from_code("CA"), "CAD"), (Region::from_code("AU"), "AUD")] {
+            let ctx = match region {
+                Some(region) => ResolverContext::default().with_region(region),
+                None => ResolverContext::default(),
+            };
+            let result = parser.parse_with_kind_order("$20", &ctx, &[OutputKind::AmountOfMoney]).unwrap();
+            let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!(Some(code), money.currency.map(|it| it.code));
+        }
+    }
+
+    #[test]
+    fn test_resolve_temperature_unit() {
+        use dimension::TemperatureUnit;
+        let parser = build_parser(Lang::EN).unwrap();
+        let temperature = |sentence: &str, ctx: &ResolverContext| -> output::TemperatureOutput {
+            let result = parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Temperature]).unwrap();
+            result[0].value.clone().attempt_into().unwrap()
+        };
+        let ctx = ResolverContext::default();
+        assert_eq!((20.0, Some(TemperatureUnit::Celsius)), { let t = temperature("20 degrees", &ctx); (t.value, t.unit) });
+        let us = ctx.with_region(Region::from_code("US").unwrap());
+        assert_eq!((68.0, Some(TemperatureUnit::Fahrenheit)), { let t = temperature("68 degrees", &us); (t.value, t.unit) });
+        let to_celsius = us.with_temperature_unit(TemperatureUnit::Celsius);
+        assert_eq!((20.0, Some(TemperatureUnit::Celsius)), { let t = temperature("68 degrees", &to_celsius); (t.value, t.unit) });
+        assert_eq!(293.15, TemperatureUnit::Celsius.convert(20.0, TemperatureUnit::Kelvin));
+    }
+
+    #[test]
+    fn test_resolve_time_form() {
+        use output::{TimeForm, TimeOutput};
+        use rustling_ontology_moment::Weekday;
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let form = |sentence: &str| {
+            let result = parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::Time]).unwrap();
+            let time: TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time.form
+        };
+        assert_eq!(Some(TimeForm::Celebration("easter_monday")), form("easter monday"));
+        assert_eq!(Some(TimeForm::DayOfWeek(Weekday::Mon)), form("monday"));
+        assert_eq!(None, form("tomorrow"));
+    }
+
+    #[test]
+    fn test_resolution_policy() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        // tuesday
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let day = |sentence: &str, policy: ResolutionPolicy| {
+            let ctx = ResolverContext::new(now).with_resolution_policy(policy);
+            let result = parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time.moment
+        };
+        assert_eq!(Moment(Local.ymd(2013, 2, 15).and_hms(0, 0, 0)), day("friday", ResolutionPolicy::PreferFuture));
+        assert_eq!(Moment(Local.ymd(2013, 2, 8).and_hms(0, 0, 0)), day("friday", ResolutionPolicy::PreferPast));
+        assert_eq!(Moment(Local.ymd(2013, 2, 11).and_hms(0, 0, 0)), day("monday", ResolutionPolicy::Nearest));
+        assert_eq!(Moment(Local.ymd(2013, 2, 19).and_hms(0, 0, 0)), day("tuesday", ResolutionPolicy::PreferFuture));
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(0, 0, 0)), day("tuesday", ResolutionPolicy::IncludeCurrent));
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(0, 0, 0)), day("tuesday", ResolutionPolicy::PreferPast));
+        assert_eq!(Moment(Local.ymd(2012, 12, 1).and_hms(0, 0, 0)), day("december", ResolutionPolicy::PreferPast));
+    }
+
+    #[test]
+    fn test_clock_policy() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let time = |sentence: &str, policy: ClockPolicy| {
+            let ctx = ResolverContext::new(now).with_clock_policy(policy);
+            let result = parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time
+        };
+        let at_three = time("at 3", ClockPolicy::NextOccurrence);
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(15, 0, 0)), at_three.moment);
+        assert!(at_three.ambiguous_clock);
+        assert_eq!(Moment(Local.ymd(2013, 2, 13).and_hms(3, 0, 0)), time("at 3", ClockPolicy::AlwaysAm).moment);
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(15, 0, 0)), time("at 3", ClockPolicy::AlwaysPm).moment);
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(15, 0, 0)), time("at 3", ClockPolicy::BusinessHours).moment);
+        assert_eq!(Moment(Local.ymd(2013, 2, 12).and_hms(8, 0, 0)), time("at 8", ClockPolicy::BusinessHours).moment);
+        let night = ClockPolicy::Window { start_hour: 22, end_hour: 6 };
+        assert_eq!(Moment(Local.ymd(2013, 2, 13).and_hms(3, 0, 0)), time("at 3", night).moment);
+        assert!(!time("at 15:00", ClockPolicy::AlwaysAm).ambiguous_clock);
+    }
+
+    #[test]
+    fn test_week_start_and_weekend() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        // tuesday
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let this_week = |ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order("this week", ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time.moment
+        };
+        assert_eq!(Moment(Local.ymd(2013, 2, 11).and_hms(0, 0, 0)), this_week(&ResolverContext::new(now)));
+        assert_eq!(Moment(Local.ymd(2013, 2, 10).and_hms(0, 0, 0)),
+                   this_week(&ResolverContext::new(now).with_week_start(Weekday::Sun)));
+        assert_eq!(Moment(Local.ymd(2013, 2, 10).and_hms(0, 0, 0)),
+                   this_week(&ResolverContext::new(now).with_region(Region::from_code("US").unwrap())));
+
+        let weekend = |ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order("the weekend", ctx, &[OutputKind::Time]).unwrap();
+            match result[0].value {
+                Output::TimeInterval(output::TimeIntervalOutput::Between { start, end, .. }) => (start, end),
+                ref other => panic!("{:?} is not an interval", other),
+            }
+        };
+        assert_eq!((Moment(Local.ymd(2013, 2, 15).and_hms(18, 0, 0)), Moment(Local.ymd(2013, 2, 18).and_hms(0, 0, 0))),
+                   weekend(&ResolverContext::new(now)));
+        assert_eq!((Moment(Local.ymd(2013, 2, 14).and_hms(18, 0, 0)), Moment(Local.ymd(2013, 2, 17).and_hms(0, 0, 0))),
+                   weekend(&ResolverContext::new(now).with_region(Region::from_code("EG").unwrap())));
+        assert_eq!((Moment(Local.ymd(2013, 2, 14).and_hms(18, 0, 0)), Moment(Local.ymd(2013, 2, 16).and_hms(0, 0, 0))),
+                   weekend(&ResolverContext::new(now).with_weekend(Weekday::Fri, 1)));
+    }
+
+    #[test]
+    fn test_week_format_with_week_start() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let ctx = ResolverContext::new(now).with_week_start(Weekday::Sun);
+        let result = parser.parse_with_kind_order("this week", &ctx, &[OutputKind::Time]).unwrap();
+        let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Grain::Week, time.grain);
+        assert_eq!("2013-02-10", time.format(output::TimeFormat::Grain));
+    }
+
+    #[test]
+    fn test_day_period_windows() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone, Timelike};
+        let parser = build_parser(Lang::EN).unwrap();
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let between = |sentence: &str, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap();
+            match result[0].value {
+                Output::TimeInterval(output::TimeIntervalOutput::Between { start, end, .. }) => (start.hour(), end.hour()),
+                ref other => panic!("{:?} is not an interval", other),
+            }
+        };
+        assert_eq!((12, 14), between("at lunch", &ResolverContext::new(now)));
+        assert_eq!((13, 15), between("at lunch", &ResolverContext::new(now).with_day_period(DayPeriod::Lunch, 13, 15)));
+        assert_eq!((12, 19), between("this afternoon", &ResolverContext::new(now)));
+        assert_eq!((14, 21), between("this afternoon", &ResolverContext::new(now).with_day_period(DayPeriod::Afternoon, 14, 21)));
+        assert_eq!((4, 9), between("tomorrow early morning", &ResolverContext::new(now)));
+        assert_eq!((6, 10), between("tomorrow early morning", &ResolverContext::new(now).with_day_period(DayPeriod::Morning, 6, 12)));
+    }
+
+    #[test]
+    fn test_fiscal_year_start() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let time = |sentence: &str, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time.moment
+        };
+        let october = ResolverContext::new(now).with_fiscal_year_start(10).unwrap();
+        assert_eq!(Moment(Local.ymd(2013, 10, 1).and_hms(0, 0, 0)), time("FY2014", &october));
+        assert_eq!(Moment(Local.ymd(2012, 10, 1).and_hms(0, 0, 0)), time("this fiscal year", &october));
+        assert_eq!(Moment(Local.ymd(2013, 10, 1).and_hms(0, 0, 0)), time("Q1 2014", &october));
+        assert_eq!(Moment(Local.ymd(2013, 4, 1).and_hms(0, 0, 0)), time("third quarter", &october));
+        let result = parser.parse_with_kind_order("Q1 2014", &october, &[OutputKind::Time]).unwrap();
+        let quarter: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!("FY2014-Q1", quarter.format(output::TimeFormat::Grain));
+        let february = ResolverContext::new(now).with_fiscal_year_start(2).unwrap();
+        assert_eq!(Moment(Local.ymd(2013, 5, 1).and_hms(0, 0, 0)), time("next quarter", &february));
+    }
+
+    #[test]
+    fn test_fiscal_year_start_out_of_range() {
+        assert!(ResolverContext::default().with_fiscal_year_start(0).is_none());
+        assert!(ResolverContext::default().with_fiscal_year_start(13).is_none());
+        assert!(ResolverContext::default().with_fiscal_year_start(12).is_some());
+    }
+
+    #[test]
+    fn test_numeric_date_order() {
+        use rustling_ontology_moment::{Moment, Local, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        let now = Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        let time = |sentence: &str, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order(sentence, ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time
+        };
+        let us = time("3/4/2015", &ResolverContext::new(now));
+        assert_eq!(Moment(Local.ymd(2015, 3, 4).and_hms(0, 0, 0)), us.moment);
+        assert_eq!(Some(Moment(Local.ymd(2015, 4, 3).and_hms(0, 0, 0))), us.alternative);
+        let gb = time("3/4/2015", &ResolverContext::new(now).with_region(Region::from_code("GB").unwrap()));
+        assert_eq!(Moment(Local.ymd(2015, 4, 3).and_hms(0, 0, 0)), gb.moment);
+        assert_eq!(Some(Moment(Local.ymd(2015, 3, 4).and_hms(0, 0, 0))), gb.alternative);
+        let au = time("25/12/2015", &ResolverContext::new(now).with_date_order(DateOrder::DayMonth));
+        assert_eq!(Moment(Local.ymd(2015, 12, 25).and_hms(0, 0, 0)), au.moment);
+        assert_eq!(None, au.alternative);
+        assert_eq!(None, time("3/3/2015", &ResolverContext::new(now)).alternative);
+
+        assert_eq!(Moment(Local.ymd(1974, 10, 31).and_hms(0, 0, 0)), time("10/31/74", &ResolverContext::new(now)).moment);
+        assert_eq!(Moment(Local.ymd(2074, 10, 31).and_hms(0, 0, 0)),
+                   time("10/31/74", &ResolverContext::new(now).with_two_digit_year_pivot(80)).moment);
+    }
+
+    #[test]
+    fn test_locale() {
+        assert_eq!(Ok(Locale::new(Lang::FR, Region::from_code("CH"))), "fr-CH".parse());
+        assert_eq!(Ok(Locale::new(Lang::ZH, Region::from_code("TW"))), "zh_Hant_TW".parse());
+        assert_eq!(Ok(Locale::new(Lang::ES, None)), "es-419".parse());
+        assert_eq!(Ok(Locale::new(Lang::EN, None)), "en".parse::<Locale>());
+        assert!("xx-CH".parse::<Locale>().is_err());
+        assert_eq!("fr-CH", Locale::new(Lang::FR, Region::from_code("ch")).to_string());
+        assert_eq!(Locale::new(Lang::FR, None), Locale::new(Lang::FR, Region::from_code("CA")).grammar_locale());
+        assert_eq!(Region::from_code("US"), Some("en".parse::<Locale>().unwrap().default_region()));
+        assert_eq!(Region::from_code("GB"), Some("en-GB".parse::<Locale>().unwrap().default_region()));
+    }
+
+    #[test]
+    fn test_regional_rules() {
+        let ctx = ResolverContext::default();
+        let number = |parser: &Parser, sentence: &str| {
+            parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::Number]).unwrap()
+                .into_iter()
+                .filter_map(|m| match m.value {
+                    Output::Integer(output::IntegerOutput(value))
+                        if m.byte_range.0 == 0 && m.byte_range.1 == sentence.len() => Some(value),
+                    _ => None,
+                })
+                .next()
+        };
+        let swiss = build_parser_for_locale("fr-CH".parse().unwrap()).unwrap();
+        assert_eq!(Some(92), number(&swiss, "nonante-deux"));
+        assert_eq!(Some(80), number(&swiss, "huitante"));
+        let french = build_parser_for_locale("fr-FR".parse().unwrap()).unwrap();
+        assert_eq!(None, number(&french, "nonante-deux"));
+        assert_eq!(Some(92), number(&french, "quatre-vingt-douze"));
+    }
+
+    #[test]
+    fn test_locale_default_region() {
+        let currency = |parser: &Parser, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order("$20", ctx, &[OutputKind::AmountOfMoney]).unwrap();
+            let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+            money.currency.map(|it| it.code)
+        };
+        let canadian = build_parser_for_locale("en-CA".parse().unwrap()).unwrap();
+        assert_eq!(Some("CAD"), currency(&canadian, &ResolverContext::default()));
+        assert_eq!(Some("AUD"), currency(&canadian, &ResolverContext::default().with_region(Region::from_code("AU").unwrap())));
+        assert_eq!(Some("USD"), currency(&build_parser(Lang::EN).unwrap(), &ResolverContext::default()));
+    }
+
+    #[test]
+    fn test_minor_unit_currency() {
+        let money = |locale: &str, sentence: &str| {
+            let parser = build_parser_for_locale(locale.parse().unwrap()).unwrap();
+            let result = parser.parse_with_kind_order(sentence, &ResolverContext::default(), &[OutputKind::AmountOfMoney]).unwrap();
+            let money: output::AmountOfMoneyOutput = result[0].value.clone().attempt_into().unwrap();
+            (money.value, money.currency.map(|it| it.code))
+        };
+        assert_eq!((0.5, Some("BRL")), money("pt-BR", "50 centavos"));
+        assert_eq!((0.5, Some("JPY")), money("ja", "50銭"));
+        assert_eq!((0.5, Some("EUR")), money("fr", "50 centimes"));
+    }
+
+    #[test]
+    fn test_resolve_in_fixed_offset() {
+        use rustling_ontology_moment::{Moment, TimeZone, Timelike};
+        let parser = build_parser(Lang::EN).unwrap();
+        // 4:30am UTC is already the afternoon in Tokyo, but still the day before in Los Angeles
+        let now = Interval::starting_at(Moment(FixedOffset::east(0).ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second);
+        for &(offset, day) in &[(FixedOffset::east(9 * 3600), 13), (FixedOffset::west(8 * 3600), 12)] {
+            let ctx = ResolverContext::new_with_tz(now, offset);
+            let result = parser.parse_with_kind_order("tomorrow at 3pm", &ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!(Moment(offset.ymd(2013, 2, day).and_hms(15, 0, 0)), time.moment);
+            assert_eq!(15, time.moment.hour());
+        }
+    }
+
+    #[test]
+    fn test_resolve_in_stated_timezone() {
+        use rustling_ontology_moment::{Moment, TimeZone};
+        let parser = build_parser(Lang::EN).unwrap();
+        let time = |sentence: &str, now: Moment<FixedOffset>, offset: FixedOffset| {
+            let ctx = ResolverContext::new_with_tz(Interval::starting_at(now, Grain::Second), offset);
+            let result = parser.parse_with_kind_order(sentence, &ctx, &[OutputKind::Time]).unwrap();
+            let time: output::TimeOutput = result[0].value.clone().attempt_into().unwrap();
+            time.moment
+        };
+        // 4pm in Paris is 10am in New York, 3pm there is still to come today
+        let paris = FixedOffset::east(3600);
+        assert_eq!(Moment(paris.ymd(2013, 2, 12).and_hms(21, 0, 0)),
+                   time("3pm EST", Moment(paris.ymd(2013, 2, 12).and_hms(16, 0, 0)), paris));
+        // 8am in Tokyo is still the evening before in New York
+        let tokyo = FixedOffset::east(9 * 3600);
+        assert_eq!(Moment(tokyo.ymd(2013, 2, 13).and_hms(13, 0, 0)),
+                   time("11pm EST", Moment(tokyo.ymd(2013, 2, 13).and_hms(8, 0, 0)), tokyo));
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
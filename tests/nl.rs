@@ -0,0 +1,50 @@
+extern crate rustling_ontology;
+extern crate rustling_ontology_moment as moment;
+extern crate rustling_ontology_json_utils as json_utils;
+extern crate serde_json;
+
+mod utils;
+
+use rustling_ontology::Lang;
+
+#[test]
+#[ignore]
+fn test_nl_numbers() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "number.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_ordinal() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "ordinal.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_percentage() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "percentage.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_duration() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "duration.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_temperature() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "temperature.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_amount_of_money() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "amount_of_money.json"));
+}
+
+#[test]
+#[ignore]
+fn test_nl_datetime() {
+    utils::run_json_test(Lang::NL, utils::build_resources_path("nl", "datetime.json"));
+}
\ No newline at end of file
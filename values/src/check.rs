@@ -1,5 +1,5 @@
 use rustling::{AttemptFrom, Check, ParsedNode};
-use moment::{Grain, Interval, Moment, Local, Period};
+use moment::{Grain, Interval, Moment, Local, Period, Weekday};
 use dimension::*;
 use output::*;
 use context::{ParsingContext, ResolverContext};
@@ -55,6 +55,25 @@ pub fn check_float(v: f32) -> CheckFloat {
     CheckFloat { value: v }
 }
 
+#[derive(Debug)]
+pub struct CheckFraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Check<Dimension> for CheckFraction {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        FloatValue::attempt_from(pn.value.clone())
+            .and_then(|v| v.fraction)
+            .map(|f| f.numerator == self.numerator && f.denominator == self.denominator)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_fraction(numerator: i64, denominator: i64) -> CheckFraction {
+    CheckFraction { numerator, denominator }
+}
+
 #[derive(Debug)]
 pub struct CheckDuration {
     pub period: Period,
@@ -73,6 +92,62 @@ pub fn check_duration(period: Period, precision: Precision) -> CheckDuration {
     CheckDuration { period, precision }
 }
 
+#[derive(Debug)]
+pub struct CheckDurationInterval {
+    pub min: Option<Period>,
+    pub max: Option<Period>,
+}
+
+impl Check<Dimension> for CheckDurationInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DurationIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.min == self.min && v.max == self.max)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_duration_interval(min: Option<Period>, max: Option<Period>) -> CheckDurationInterval {
+    CheckDurationInterval { min, max }
+}
+
+#[derive(Debug)]
+pub struct CheckTimezone {
+    pub name: &'static str,
+}
+
+impl Check<Dimension> for CheckTimezone {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TimeValue::attempt_from(pn.value.clone())
+            .map(|v| v.timezone.map(|tz| tz.name) == Some(self.name))
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_timezone(name: &'static str) -> CheckTimezone {
+    CheckTimezone { name }
+}
+
+#[derive(Debug)]
+pub struct CheckRecurrence {
+    pub frequency: Grain,
+    pub interval: i64,
+    pub by_day: Vec<Weekday>,
+    pub by_hour: Option<u32>,
+}
+
+impl Check<Dimension> for CheckRecurrence {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        RecurrenceValue::attempt_from(pn.value.clone())
+            .map(|v| v.frequency == self.frequency && v.interval == self.interval
+                && v.by_day == self.by_day && v.by_hour == self.by_hour)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_recurrence(frequency: Grain, interval: i64, by_day: Vec<Weekday>, by_hour: Option<u32>) -> CheckRecurrence {
+    CheckRecurrence { frequency, interval, by_day, by_hour }
+}
+
 
 #[derive(Debug)]
 pub struct CheckMoment {
@@ -150,7 +225,7 @@ impl Check<Dimension> for CheckMomentSpan {
             .and_then(|v| TimeIntervalOutput::attempt_from(v))
             .map(|v| {
                 if let TimeIntervalOutput::Between { start, end, precision, .. } = v {
-                    start == self.interval.start && Some(end) == self.interval.end && precision == self.precision
+                    start == self.interval.start && self.interval.end.map_or(false, |it| end == it) && precision == self.precision
                 } else {
                     false
                 }
@@ -174,7 +249,7 @@ pub struct CheckFinance {
 impl Check<Dimension> for CheckFinance {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         AmountOfMoneyValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit && !v.is_interval())
             .unwrap_or(false)
     }
 }
@@ -187,23 +262,186 @@ pub fn check_finance(value: f32, unit: Option<&'static str>, precision: Precisio
     }
 }
 
+#[derive(Debug)]
+pub struct CheckFinanceInterval {
+    pub start: Option<f32>,
+    pub end: Option<f32>,
+    pub unit: Option<&'static str>,
+}
+
+impl Check<Dimension> for CheckFinanceInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AmountOfMoneyValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                let bounds = match (v.direction, v.end) {
+                    (_, Some(end)) => (Some(v.value), Some(end)),
+                    (Some(Direction::After), None) => (Some(v.value), None),
+                    (Some(Direction::Before), None) => (None, Some(v.value)),
+                    (None, None) => return false,
+                };
+                bounds == (self.start, self.end) && v.unit == self.unit
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_finance_interval(start: Option<f32>, end: Option<f32>, unit: Option<&'static str>) -> CheckFinanceInterval {
+    CheckFinanceInterval { start, end, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckPercentage {
+    pub value: f32,
+}
+
+impl Check<Dimension> for CheckPercentage {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        PercentageValue::attempt_from(pn.value.clone())
+            .map(|v| v.0 == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_percentage(value: f32) -> CheckPercentage {
+    CheckPercentage { value }
+}
+
 #[derive(Debug)]
 pub struct CheckTemperature {
     pub value: f32,
-    pub unit: Option<&'static str>,
+    pub unit: Option<TemperatureUnit>,
 }
 
 impl Check<Dimension> for CheckTemperature {
     fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
         TemperatureValue::attempt_from(pn.value.clone())
-            .map(|v| v.value == self.value && v.unit == self.unit)
+            .map(|v| v.value == self.value && v.unit == self.unit && !v.is_interval())
             .unwrap_or(false)
     }
 }
 
-pub fn check_temperature(value: f32, unit: Option<&'static str>) -> CheckTemperature {
+pub fn check_temperature(value: f32, unit: Option<TemperatureUnit>) -> CheckTemperature {
     CheckTemperature {
         value: value,
         unit: unit,
     }
 }
+
+#[derive(Debug)]
+pub struct CheckTemperatureInterval {
+    pub start: Option<f32>,
+    pub end: Option<f32>,
+    pub unit: Option<TemperatureUnit>,
+}
+
+impl Check<Dimension> for CheckTemperatureInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        TemperatureValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                let bounds = match (v.direction, v.end) {
+                    (_, Some(end)) => (Some(v.value), Some(end)),
+                    (Some(Direction::After), None) => (Some(v.value), None),
+                    (Some(Direction::Before), None) => (None, Some(v.value)),
+                    (None, None) => return false,
+                };
+                bounds == (self.start, self.end) && v.unit == self.unit
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_temperature_interval(start: Option<f32>, end: Option<f32>, unit: Option<TemperatureUnit>) -> CheckTemperatureInterval {
+    CheckTemperatureInterval { start, end, unit }
+}
+
+#[derive(Debug)]
+pub struct CheckQuantity {
+    pub value: f32,
+    pub unit: QuantityUnit,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckQuantity {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        QuantityValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit && v.precision == self.precision)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_quantity(value: f32, unit: QuantityUnit, precision: Precision) -> CheckQuantity {
+    CheckQuantity {
+        value: value,
+        unit: unit,
+        precision: precision,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckEmail(pub String);
+
+impl Check<Dimension> for CheckEmail {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        EmailValue::attempt_from(pn.value.clone())
+            .map(|v| v.0 == self.0)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_email(value: &str) -> CheckEmail {
+    CheckEmail(value.to_string())
+}
+
+#[derive(Debug)]
+pub struct CheckUrl {
+    pub value: String,
+    pub domain: String,
+}
+
+impl Check<Dimension> for CheckUrl {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        UrlValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.domain == self.domain)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_url(value: &str, domain: &str) -> CheckUrl {
+    CheckUrl {
+        value: value.to_string(),
+        domain: domain.to_string(),
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckPhoneNumber(pub String);
+
+impl Check<Dimension> for CheckPhoneNumber {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        PhoneNumberValue::attempt_from(pn.value.clone())
+            .map(|v| v.0 == self.0)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_phone_number(value: &str) -> CheckPhoneNumber {
+    CheckPhoneNumber(value.to_string())
+}
+
+#[derive(Debug)]
+pub struct CheckNumberInterval {
+    pub from: Option<f32>,
+    pub to: Option<f32>,
+}
+
+impl Check<Dimension> for CheckNumberInterval {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        NumberIntervalValue::attempt_from(pn.value.clone())
+            .map(|v| v.from == self.from && v.to == self.to)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_number_interval(from: Option<f32>, to: Option<f32>) -> CheckNumberInterval {
+    CheckNumberInterval { from, to }
+}
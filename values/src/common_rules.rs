@@ -0,0 +1,46 @@
+//! Rules whose patterns do not depend on the language: emails, urls and written phone numbers.
+//! Spoken forms of phone numbers are handled by each grammar.
+
+use rustling::*;
+use dimension::*;
+use helpers;
+
+pub fn rules_email(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("email",
+                      b.reg(r#"[\w.%+-]+@[\w-]+(?:\.[\w-]+)*\.[a-z]{2,}"#)?,
+                      |text_match| Ok(EmailValue(text_match.group(0).to_string()))
+    );
+    Ok(())
+}
+
+pub fn rules_url(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("url (with scheme or www)",
+                      b.reg(r#"(?:[a-z][a-z0-9+.-]*://(?:www\d*\.)?|www\d*\.)[\w-]+(?:\.[\w-]+)*\.[a-z]{2,}(?::\d+)?(?:[/?#][^\s]*)?"#)?,
+                      |text_match| helpers::url(text_match.group(0))
+    );
+    b.rule_1_terminal("url (bare domain)",
+                      b.reg(r#"[\w-]+(?:\.[\w-]+)*\.(?:com|org|net|edu|gov|info|biz|io|ai|co|me|fr|de|es|it|uk|ch|be|nl|eu|us|ca|jp|kr|cn)(?::\d+)?(?:[/?#][^\s]*)?"#)?,
+                      |text_match| helpers::url(text_match.group(0))
+    );
+    Ok(())
+}
+
+pub fn rules_phone_number(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("phone number (international)",
+                      b.reg(r#"\+\d{1,3}(?:[\s.-]?\(\d{1,4}\))?(?:[\s.-]?\d{1,5}){1,7}"#)?,
+                      |text_match| helpers::phone_number(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (area code)",
+                      b.reg(r#"\(\d{1,4}\)[\s.-]?\d{1,5}(?:[\s.-]?\d{1,5}){1,6}"#)?,
+                      |text_match| helpers::phone_number(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (grouped digits)",
+                      b.reg(r#"\d{2,5}(?:[\s.-]\d{2,5}){2,6}"#)?,
+                      |text_match| helpers::phone_number(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (trunk prefix)",
+                      b.reg(r#"0\d{6,14}"#)?,
+                      |text_match| helpers::phone_number(text_match.group(0))
+    );
+    Ok(())
+}
@@ -2,6 +2,9 @@ use output::*;
 use dimension::*;
 use rustling::Value;
 use moment::*;
+use moment::bidirectional_walker::BidirectionalWalker;
+use currency::Currency;
+use locale::Region;
 
 pub trait ParsingContext<V: Value> {
     type O;
@@ -27,16 +30,317 @@ impl<V: Value+Clone> ParsingContext<V> for IdentityContext<V> {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+/// Which occurrence of a time is picked when the expression does not pin it down, e.g. "friday"
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ResolutionPolicy {
+    /// The current or next occurrence, "monday" said on a monday being the next one
+    PreferFuture,
+    /// The current occurrence if the reference time falls in it, or else the last one
+    PreferPast,
+    /// The current occurrence, or else the closest one to the reference time
+    Nearest,
+    /// The current or next occurrence, "monday" said on a monday being the current one
+    IncludeCurrent,
+}
+
+impl Default for ResolutionPolicy {
+    fn default() -> ResolutionPolicy {
+        ResolutionPolicy::PreferFuture
+    }
+}
+
+/// Which half of the day is picked for a time on the 12-hour clock, e.g. "at 3"
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ClockPolicy {
+    /// The next occurrence, whether in the morning or in the afternoon
+    NextOccurrence,
+    /// The occurrence between 7am and 7pm
+    BusinessHours,
+    AlwaysAm,
+    AlwaysPm,
+    /// The occurrence whose hour falls in `[start_hour, end_hour)`, the window may span midnight
+    Window { start_hour: u32, end_hour: u32 },
+}
+
+impl Default for ClockPolicy {
+    fn default() -> ClockPolicy {
+        ClockPolicy::NextOccurrence
+    }
+}
+
+impl ClockPolicy {
+    /// Hour of the day to keep for an ambiguous `hour` on the 12-hour clock, if the policy picks one
+    fn pick_hour(&self, hour: u32) -> Option<u32> {
+        let am = hour % 12;
+        let pm = am + 12;
+        let in_window = |h: u32, start: u32, end: u32| if start <= end {
+            start <= h && h < end
+        } else {
+            start <= h || h < end
+        };
+        match *self {
+            ClockPolicy::NextOccurrence => None,
+            ClockPolicy::AlwaysAm => Some(am),
+            ClockPolicy::AlwaysPm => Some(pm),
+            ClockPolicy::BusinessHours => ClockPolicy::Window { start_hour: 7, end_hour: 19 }.pick_hour(hour),
+            ClockPolicy::Window { start_hour, end_hour } => {
+                match (in_window(am, start_hour, end_hour), in_window(pm, start_hour, end_hour)) {
+                    (true, false) => Some(am),
+                    (false, true) => Some(pm),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
 pub struct ResolverContext {
-    ctx: Context<Local>,
+    ctx: Context<Zone>,
+    region: Option<Region>,
+    temperature_unit: Option<TemperatureUnit>,
+    policy: ResolutionPolicy,
+    clock_policy: ClockPolicy,
+    week: Option<Week>,
+    date_order: Option<DateOrder>,
+}
+
+impl Default for ResolverContext {
+    fn default() -> ResolverContext {
+        ResolverContext::new(Interval::starting_at(Moment::now(), Grain::Second))
+    }
 }
 
 impl ResolverContext {
+    /// Resolves times in the timezone of the machine
     pub fn new(now: Interval<Local>) -> ResolverContext {
+        ResolverContext::new_with_zone(now, Zone::Local)
+    }
+
+    /// Resolves times at a fixed offset from UTC, whatever the timezone of the machine
+    pub fn new_with_tz<T: TimeZone>(now: Interval<T>, offset: FixedOffset) -> ResolverContext {
+        ResolverContext::new_with_zone(now, Zone::Fixed(offset))
+    }
+
+    /// Resolves times in the given zone, the reference time is converted to this zone
+    pub fn new_with_zone<T: TimeZone>(now: Interval<T>, zone: Zone) -> ResolverContext {
         ResolverContext {
-           ctx: Context::for_reference(now) 
+           ctx: Context::for_reference(now.with_timezone(&zone)),
+           region: None,
+           temperature_unit: None,
+           policy: ResolutionPolicy::default(),
+           clock_policy: ClockPolicy::default(),
+           week: None,
+           date_order: None,
+        }
+    }
+
+    /// Region of the user, used to tell apart the currencies sharing a symbol such as "$", to
+    /// read temperatures given in degrees without a scale, for the days of the week and weekend,
+    /// and for the order of the day and month in dates such as "3/4/2015"
+    pub fn with_region(self, region: Region) -> ResolverContext {
+        ResolverContext { region: Some(region), ..self }
+    }
+
+    /// Region used when none was set, e.g. the one of the locale of the parser
+    pub fn with_default_region(self, region: Region) -> ResolverContext {
+        ResolverContext { region: Some(self.region.unwrap_or(region)), ..self }
+    }
+
+    /// Converts the temperatures with a known scale to the given unit
+    pub fn with_temperature_unit(self, unit: TemperatureUnit) -> ResolverContext {
+        ResolverContext { temperature_unit: Some(unit), ..self }
+    }
+
+    pub fn with_resolution_policy(self, policy: ResolutionPolicy) -> ResolverContext {
+        ResolverContext { policy, ..self }
+    }
+
+    pub fn with_clock_policy(self, clock_policy: ClockPolicy) -> ResolverContext {
+        ResolverContext { clock_policy, ..self }
+    }
+
+    /// First day of the week, for "this week" and the other weeks
+    pub fn with_week_start(self, start: Weekday) -> ResolverContext {
+        ResolverContext { week: Some(Week { start, ..self.week() }), ..self }
+    }
+
+    /// Days making up "the weekend", e.g. friday for two days in most of the Middle East
+    pub fn with_weekend(self, weekend_start: Weekday, weekend_days: u32) -> ResolverContext {
+        ResolverContext { week: Some(Week { weekend_start, weekend_days, ..self.week() }), ..self }
+    }
+
+    /// Hours of a period of the day, replacing the default window of the grammar, e.g. a
+    /// lunch from 1pm to 3pm
+    pub fn with_day_period(self, period: DayPeriod, start_hour: u32, end_hour: u32) -> ResolverContext {
+        let day_periods = self.ctx.day_periods.with_window(period, start_hour, end_hour);
+        ResolverContext { ctx: self.ctx.with_day_periods(day_periods), ..self }
+    }
+
+    /// First month of the fiscal year, from 1 to 12, used for "FY2019" and quarters. Returns
+    /// `None` for a month out of this range.
+    pub fn with_fiscal_year_start(self, start_month: u32) -> Option<ResolverContext> {
+        if start_month < 1 || start_month > 12 {
+            return None
+        }
+        Some(ResolverContext { ctx: self.ctx.with_fiscal_calendar(FiscalCalendar { start_month }), ..self })
+    }
+
+    /// Order of the day and the month in dates written with numbers only, e.g. "3/4/2015"
+    pub fn with_date_order(self, date_order: DateOrder) -> ResolverContext {
+        ResolverContext { date_order: Some(date_order), ..self }
+    }
+
+    /// Two-digit years from the pivot are read in the 1900s and the ones below it in the 2000s,
+    /// e.g. 1974 for "10/31/74" with the default pivot of 50
+    pub fn with_two_digit_year_pivot(self, year_pivot: i32) -> ResolverContext {
+        let date_format = DateFormat { year_pivot, ..self.ctx.date_format };
+        ResolverContext { ctx: self.ctx.with_date_format(date_format), ..self }
+    }
+
+    /// Date order set explicitly, or else the one of the region, or else the one of the grammar
+    fn date_order(&self) -> Option<DateOrder> {
+        self.date_order.or(self.region.map(|region| region.date_order()))
+    }
+
+    /// Week set explicitly, or else the one of the region, or else monday to sunday
+    fn week(&self) -> Week {
+        match (self.week, self.region) {
+            (Some(week), _) => week,
+            (None, Some(region)) => region.week(),
+            (None, None) => Week::default(),
+        }
+    }
+
+    pub fn zone(&self) -> Zone {
+        self.ctx.reference.start.timezone()
+    }
+
+    /// Expands the next `n` occurrences of a recurrence, starting from the reference time
+    pub fn next_occurrences(&self, recurrence: &RecurrenceOutput, n: usize) -> Vec<Moment<Zone>> {
+        recurrence.occurrences_after(self.ctx.reference.start, n)
+    }
+
+    /// Scale of temperatures in degrees without unit: Fahrenheit in the United States and Celsius elsewhere
+    fn default_temperature_unit(&self) -> TemperatureUnit {
+        match self.region {
+            Some(region) if region.as_str() == "US" => TemperatureUnit::Fahrenheit,
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+
+    fn normalize_temperature(&self, value: f32, unit: Option<TemperatureUnit>) -> (f32, Option<TemperatureUnit>) {
+        let unit = match unit {
+            Some(TemperatureUnit::Degree) => Some(self.default_temperature_unit()),
+            unit => unit,
+        };
+        match (unit, self.temperature_unit) {
+            (Some(unit), Some(preferred)) => (unit.convert(value, preferred), Some(preferred)),
+            _ => (value, unit),
+        }
+    }
+
+    /// Walks the occurrences of the time, keeping only the half of the day picked by the clock
+    /// policy when the time is ambiguous on the 12-hour clock
+    fn walker(&self, tv: &TimeValue) -> IntervalWalker<Zone> {
+        let ctx = self.ctx
+            .with_week(self.week())
+            .with_date_format(DateFormat { order: self.date_order(), ..self.ctx.date_format });
+        let walker = tv.constraint.to_walker(&ctx.reference, &ctx);
+        let hour = match tv.form {
+            Form::TimeOfDay(ref tod) if tod.is_ambiguous_clock() => self.clock_policy.pick_hour(tod.full_hour()),
+            _ => None,
+        };
+        match hour {
+            Some(hour) => BidirectionalWalker::new()
+                .forward(walker.forward.filter(move |interval| interval.start.hour() == hour))
+                .backward(walker.backward.filter(move |interval| interval.start.hour() == hour)),
+            None => walker,
+        }
+    }
+
+    /// Picks the occurrence of the time according to the resolution policy
+    fn resolve_time(&self, tv: &TimeValue) -> Option<Interval<Zone>> {
+        let reference = self.ctx.reference;
+        let mut walker = self.walker(tv);
+        let first = walker.forward.next();
+        let current = match first {
+            Some(h) if h.intersect(reference).is_some() => Some(h),
+            _ => None,
+        };
+        match self.policy {
+            ResolutionPolicy::PreferFuture if current.is_some() && tv.form.not_immediate().unwrap_or(false) => {
+                walker.forward.next().or_else(|| walker.backward.next())
+            }
+            ResolutionPolicy::PreferFuture | ResolutionPolicy::IncludeCurrent => {
+                first.or_else(|| walker.backward.next())
+            }
+            ResolutionPolicy::PreferPast => {
+                current.or_else(|| walker.backward.next()).or(first)
+            }
+            ResolutionPolicy::Nearest => {
+                if current.is_some() {
+                    return current
+                }
+                match (first, walker.backward.next()) {
+                    (Some(future), Some(past)) => {
+                        let to_future = future.start.0.signed_duration_since(reference.start.0);
+                        let to_past = reference.start.0.signed_duration_since(past.end_moment().0);
+                        if to_future <= to_past { Some(future) } else { Some(past) }
+                    }
+                    (future, past) => future.or(past),
+                }
+            }
+        }
+    }
+
+    /// Reading of a date written with numbers only with its day and month swapped, when it
+    /// differs from the resolved one
+    fn resolve_alternative(&self, tv: &TimeValue, resolved: Interval<Zone>) -> Option<Interval<Zone>> {
+        if !tv.numeric_date {
+            return None
         }
+        [DateOrder::MonthDay, DateOrder::DayMonth].iter()
+            .filter_map(|order| self.with_date_order(*order).resolve_time(tv))
+            .find(|interval| *interval != resolved)
+    }
+
+    /// Same context with the reference time on the wall clock of a zone stated in the sentence,
+    /// e.g. "3pm EST", so that the occurrences already past there are skipped
+    fn in_stated_zone(&self, timezone: TimezoneValue) -> ResolverContext {
+        let standard = Zone::Fixed(FixedOffset::east(timezone.utc_offset * 60));
+        let utc_offset = timezone.utc_offset_at(&self.ctx.reference.start.with_timezone(&standard));
+        let zone = Zone::Fixed(FixedOffset::east(utc_offset * 60));
+        let ctx = Context {
+            reference: self.ctx.reference.with_timezone(&zone),
+            min: self.ctx.min.with_timezone(&zone),
+            max: self.ctx.max.with_timezone(&zone),
+            ..self.ctx
+        };
+        ResolverContext { ctx, ..*self }
+    }
+
+    fn resolve_interval(&self, tv: &TimeValue) -> Option<Interval<Zone>> {
+        let mut walker = self.walker(tv);
+        walker.forward.next().or_else(|| walker.backward.next())
+    }
+}
+
+/// Converts an interval resolved on the wall clock of the stated zone to the zone of the caller
+fn in_timezone(interval: Interval<Zone>, timezone: Option<TimezoneValue>, zone: Zone) -> Interval<Zone> {
+    match timezone {
+        Some(timezone) => {
+            // the offset of the zone may have changed between the reference and the interval
+            let convert = |moment: Moment<Zone>| moment
+                .reinterpret_at_utc_offset(timezone.utc_offset_at(&moment))
+                .with_timezone(&zone);
+            Interval {
+                start: convert(interval.start),
+                end: interval.end.map(convert),
+                grain: interval.grain,
+            }
+        }
+        None => interval,
     }
 }
 
@@ -45,31 +349,31 @@ impl ParsingContext<Dimension> for ResolverContext {
     fn resolve(&self, dim: &Dimension) -> Option<Output> {
         match dim {
             &Dimension::Time(ref tv) => {
-                let mut walker = tv.constraint
-                    .to_walker(&self.ctx.reference, &self.ctx);
-                walker.forward
-                    .next()
-                    .and_then(|h| {
-                        if tv.form.not_immediate().unwrap_or(false) && h.intersect(self.ctx.reference).is_some() {
-                            walker.forward.next()
-                        } else {
-                            Some(h)
-                        }
-                    })
-                    .or_else(|| walker.backward.next())
+                let context = tv.timezone.map_or(*self, |timezone| self.in_stated_zone(timezone));
+                context.resolve_time(tv)
                     .map(|interval| {
+                        let alternative = context.resolve_alternative(tv, interval)
+                            .map(|alternative| in_timezone(alternative, tv.timezone, self.zone()));
+                        (in_timezone(interval, tv.timezone, self.zone()), alternative)
+                    })
+                    .map(|(interval, alternative)| {
                         if let Some(bounded_direction) = tv.direction {
-                            let anchor = match bounded_direction.bound {
+                            let anchor = |interval: Interval<Zone>| match bounded_direction.bound {
                                 Bound::Start => interval.start,
                                 Bound::End { only_interval } if only_interval => interval.end.unwrap_or(interval.start),
                                 Bound::End { .. } => interval.end_moment(),
                             };
                             
                             let output = TimeOutput {
-                                moment: anchor,
+                                moment: anchor(interval),
                                 grain: interval.grain,
                                 precision: tv.precision,
                                 latent: tv.latent,
+                                timezone: tv.timezone,
+                                form: TimeForm::from_form(&tv.form),
+                                ambiguous_clock: tv.form.is_ambiguous_clock(),
+                                alternative: alternative.map(&anchor),
+                                fiscal_year_start: self.ctx.fiscal_calendar.start_month,
                             };
                             
                             match bounded_direction.direction {
@@ -83,6 +387,9 @@ impl ParsingContext<Dimension> for ResolverContext {
                                         end: end, 
                                         precision: tv.precision,
                                         latent: tv.latent,
+                                        timezone: tv.timezone,
+                                        form: TimeForm::from_form(&tv.form),
+                                        ambiguous_clock: tv.form.is_ambiguous_clock(),
                                     }
                                 )
                         } else {
@@ -91,6 +398,11 @@ impl ParsingContext<Dimension> for ResolverContext {
                                     grain: interval.grain,
                                     precision: tv.precision,
                                     latent: tv.latent,
+                                    timezone: tv.timezone,
+                                    form: TimeForm::from_form(&tv.form),
+                                    ambiguous_clock: tv.form.is_ambiguous_clock(),
+                                    alternative: alternative.map(|alternative| alternative.start),
+                                    fiscal_year_start: self.ctx.fiscal_calendar.start_month,
                             };
                             Output::Time(output)
                         }
@@ -103,21 +415,108 @@ impl ParsingContext<Dimension> for ResolverContext {
                 }
             }
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
-            &Dimension::AmountOfMoney(ref aom) => Some(Output::AmountOfMoney(AmountOfMoneyOutput {
-                value: aom.value,
-                precision: aom.precision,
-                unit: aom.unit,
-            })),
-            &Dimension::Temperature(ref temp) => Some(Output::Temperature(TemperatureOutput {
-                value: temp.value,
-                unit: temp.unit,
-                latent: temp.latent,
-            })),
+            &Dimension::AmountOfMoney(ref aom) => {
+                let currency = aom.unit.and_then(|unit| Currency::resolve(unit, self.region));
+                let amount = |value: f32| match (aom.unit, currency) {
+                    (Some(unit), Some(currency)) => currency.amount(unit, value),
+                    _ => value,
+                };
+                let output = AmountOfMoneyOutput {
+                    value: amount(aom.value),
+                    precision: aom.precision,
+                    unit: aom.unit,
+                    currency,
+                };
+                match (aom.direction, aom.end) {
+                    (_, Some(end)) => Some(Output::AmountOfMoneyInterval(
+                        AmountOfMoneyIntervalOutput::Between {
+                            start: amount(aom.value),
+                            end: amount(end),
+                            precision: aom.precision,
+                            unit: aom.unit,
+                            currency,
+                        }
+                    )),
+                    (Some(Direction::After), None) => Some(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::After(output))),
+                    (Some(Direction::Before), None) => Some(Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput::Before(output))),
+                    (None, None) => Some(Output::AmountOfMoney(output)),
+                }
+            }
+            &Dimension::Temperature(ref temp) => {
+                let (value, unit) = self.normalize_temperature(temp.value, temp.unit);
+                let output = TemperatureOutput {
+                    value: value,
+                    unit: unit,
+                    latent: temp.latent,
+                };
+                match (temp.direction, temp.end) {
+                    (_, Some(end)) => Some(Output::TemperatureInterval(
+                        TemperatureIntervalOutput::Between {
+                            start: value,
+                            end: self.normalize_temperature(end, temp.unit).0,
+                            unit: unit,
+                            latent: temp.latent,
+                        }
+                    )),
+                    (Some(Direction::After), None) => Some(Output::TemperatureInterval(TemperatureIntervalOutput::After(output))),
+                    (Some(Direction::Before), None) => Some(Output::TemperatureInterval(TemperatureIntervalOutput::Before(output))),
+                    (None, None) => Some(Output::Temperature(output)),
+                }
+            }
             &Dimension::Duration(ref duration) => Some(Output::Duration(DurationOutput {
                 period: duration.period.clone(),
                 precision: duration.precision,
             })),
             &Dimension::Percentage(ref percentage) => Some(Output::Percentage(PercentageOutput(percentage.0))),
+            &Dimension::Quantity(ref quantity) => Some(Output::Quantity(QuantityOutput {
+                value: quantity.value,
+                unit: quantity.unit,
+                normalized_value: quantity.unit.to_si(quantity.value),
+                precision: quantity.precision,
+            })),
+            &Dimension::Email(ref email) => Some(Output::Email(EmailOutput(email.0.clone()))),
+            &Dimension::Url(ref url) => Some(Output::Url(UrlOutput {
+                value: url.value.clone(),
+                domain: url.domain.clone(),
+            })),
+            &Dimension::PhoneNumber(ref phone) => Some(Output::PhoneNumber(PhoneNumberOutput(phone.0.clone()))),
+            &Dimension::NumberInterval(ref interval) => Some(Output::NumberInterval(NumberIntervalOutput {
+                from: interval.from,
+                to: interval.to,
+                from_inclusive: interval.from_inclusive,
+                to_inclusive: interval.to_inclusive,
+            })),
+            &Dimension::DurationInterval(ref interval) => Some(Output::DurationInterval(DurationIntervalOutput {
+                min: interval.min.clone(),
+                max: interval.max.clone(),
+                precision: interval.precision,
+            })),
+            &Dimension::Recurrence(ref recurrence) => {
+                let start = match recurrence.start {
+                    Some(ref tv) => match self.resolve_interval(tv) {
+                        Some(interval) => Some(interval.start),
+                        None => return None,
+                    },
+                    None => None,
+                };
+                let end = match recurrence.end {
+                    Some(ref tv) => match self.resolve_interval(tv) {
+                        Some(interval) => Some(interval.end_moment()),
+                        None => return None,
+                    },
+                    None => None,
+                };
+                Some(Output::Recurrence(RecurrenceOutput {
+                    frequency: recurrence.frequency,
+                    interval: recurrence.interval,
+                    by_day: recurrence.by_day.clone(),
+                    by_hour: recurrence.by_hour,
+                    by_minute: recurrence.by_minute,
+                    start: start,
+                    end: end,
+                    reference: self.ctx.reference.start,
+                }))
+            }
             _ => None,
         }
     }
@@ -0,0 +1,355 @@
+use locale::Region;
+
+/// Currency of the ISO 4217 registry
+#[derive(Debug, PartialEq)]
+pub struct Currency {
+    /// ISO 4217 alphabetic code, e.g. "USD"
+    pub code: &'static str,
+    /// Number of digits of the minor unit, e.g. 2 for cents of dollar and 0 for the yen
+    pub minor_digits: u8,
+    /// Symbols and abbreviations used by the grammars to tag this currency
+    pub symbols: &'static [&'static str],
+}
+
+/// Known currencies. When several currencies share a symbol, the first one listed is used
+/// unless the region of the user points to another one in `REGIONAL_SYMBOLS`.
+pub static CURRENCIES: &'static [Currency] = &[
+    Currency { code: "USD", minor_digits: 2, symbols: &["$", "us$"] },
+    Currency { code: "EUR", minor_digits: 2, symbols: &["€"] },
+    Currency { code: "GBP", minor_digits: 2, symbols: &["£"] },
+    Currency { code: "JPY", minor_digits: 0, symbols: &["¥", "円"] },
+    Currency { code: "CNY", minor_digits: 2, symbols: &["¥", "元", "rmb", "cnh"] },
+    Currency { code: "KRW", minor_digits: 0, symbols: &["₩", "원"] },
+    Currency { code: "INR", minor_digits: 2, symbols: &["₹", "rs"] },
+    Currency { code: "CAD", minor_digits: 2, symbols: &["$", "ca$"] },
+    Currency { code: "AUD", minor_digits: 2, symbols: &["$", "a$"] },
+    Currency { code: "NZD", minor_digits: 2, symbols: &["$", "nz$"] },
+    Currency { code: "HKD", minor_digits: 2, symbols: &["$", "hk$"] },
+    Currency { code: "SGD", minor_digits: 2, symbols: &["$", "s$"] },
+    Currency { code: "MXN", minor_digits: 2, symbols: &["$", "mx$"] },
+    Currency { code: "CHF", minor_digits: 2, symbols: &["fr"] },
+    Currency { code: "SEK", minor_digits: 2, symbols: &["kr"] },
+    Currency { code: "NOK", minor_digits: 2, symbols: &["kr"] },
+    Currency { code: "DKK", minor_digits: 2, symbols: &["kr"] },
+    Currency { code: "ISK", minor_digits: 0, symbols: &["kr"] },
+    Currency { code: "EGP", minor_digits: 2, symbols: &["£"] },
+    Currency { code: "RUB", minor_digits: 2, symbols: &["₽"] },
+    Currency { code: "AED", minor_digits: 2, symbols: &["د.إ"] },
+    Currency { code: "BRL", minor_digits: 2, symbols: &["r$"] },
+    // peseta, replaced by the euro
+    Currency { code: "ESP", minor_digits: 0, symbols: &["pts"] },
+    // bitcoin has no ISO 4217 code, XBT follows the convention for supranational currencies
+    Currency { code: "XBT", minor_digits: 8, symbols: &["฿"] },
+];
+
+/// Unit tagged by the grammars for the hundredths of the currency of the region, e.g. "50 cents"
+pub const MINOR_UNIT: &'static str = "cent";
+
+/// Suffix of the units tagged for the hundredths of a given currency, e.g. "JPY-cent" for "50銭"
+const MINOR_UNIT_SUFFIX: &'static str = "-cent";
+
+/// Whether a unit of money stands for the hundredths of a currency, either `MINOR_UNIT` or the
+/// unit of a given currency such as "JPY-cent"
+pub fn is_minor_unit(unit: &str) -> bool {
+    unit == MINOR_UNIT || unit.ends_with(MINOR_UNIT_SUFFIX)
+}
+
+/// Regions using the euro, whose cents are the ones of the euro rather than of a dollar
+static EURO_REGIONS: &'static [&'static str] = &[
+    "AT", "BE", "CY", "DE", "EE", "ES", "FI", "FR", "GR", "HR", "IE", "IT", "LT", "LU", "LV", "MT",
+    "NL", "PT", "SI", "SK",
+];
+
+/// Symbols shared by several currencies, with the region where each alternative currency is used
+static REGIONAL_SYMBOLS: &'static [(&'static str, &'static str, &'static str)] = &[
+    ("$", "CA", "CAD"),
+    ("$", "AU", "AUD"),
+    ("$", "NZ", "NZD"),
+    ("$", "HK", "HKD"),
+    ("$", "SG", "SGD"),
+    ("$", "MX", "MXN"),
+    ("¥", "CN", "CNY"),
+    ("kr", "NO", "NOK"),
+    ("kr", "DK", "DKK"),
+    ("kr", "IS", "ISK"),
+    ("£", "EG", "EGP"),
+];
+
+impl Currency {
+    pub fn from_code(code: &str) -> Option<&'static Currency> {
+        CURRENCIES.iter().find(|currency| currency.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Resolves a unit of money tagged by a grammar, either an ISO code, a symbol, the minor unit
+    /// of an ISO code such as "JPY-cent", or `MINOR_UNIT`, using the region of the user to pick
+    /// among the currencies sharing a symbol. `MINOR_UNIT` stands for the cents of the euro in
+    /// the euro area, and of the dollar of the region elsewhere.
+    pub fn resolve(unit: &str, region: Option<Region>) -> Option<&'static Currency> {
+        if unit == MINOR_UNIT {
+            let euro = region.map_or(false, |region| EURO_REGIONS.iter().any(|&code| code == region.as_str()));
+            return if euro { Currency::from_code("EUR") } else { Currency::resolve("$", region) };
+        }
+        if unit.ends_with(MINOR_UNIT_SUFFIX) {
+            return Currency::from_code(&unit[..unit.len() - MINOR_UNIT_SUFFIX.len()]);
+        }
+        if let Some(currency) = Currency::from_code(unit) {
+            return Some(currency);
+        }
+        let symbol = unit.to_lowercase();
+        let regional = region.and_then(|region| {
+            REGIONAL_SYMBOLS.iter()
+                .find(|&&(s, r, _)| s == symbol && r == region.as_str())
+                .and_then(|&(_, _, code)| Currency::from_code(code))
+        });
+        regional.or_else(|| CURRENCIES.iter().find(|currency| currency.symbols.iter().any(|s| *s == symbol)))
+    }
+
+    /// Rounds an amount to the minor unit of the currency
+    pub fn round(&self, value: f32) -> f32 {
+        let factor = 10f32.powi(self.minor_digits as i32);
+        (value * factor).round() / factor
+    }
+
+    /// Amount in this currency of a value tagged with `unit`, converting cents to the main unit
+    pub fn amount(&self, unit: &str, value: f32) -> f32 {
+        if is_minor_unit(unit) {
+            // hundredths are kept for the currencies whose minor unit is out of use, e.g. the sen
+            let factor = 10f32.powi(::std::cmp::max(self.minor_digits, 2) as i32);
+            (value / 100.0 * factor).round() / factor
+        } else {
+            self.round(value)
+        }
+    }
+}
+
+/// Names and symbols of currencies in each language, with the unit tagged for each of them: an
+/// ISO code, a symbol shared by several currencies, the minor unit of an ISO code, or
+/// `MINOR_UNIT` for the names of cents used with several currencies, such as "cent" or "centavo"
+pub mod names {
+    /// German
+    pub static DE: &'static [(&'static str, &'static str)] = &[
+        ("cent", "cent"), ("cents", "cent"), ("penny", "cent"), ("pennies", "cent"), ("ct", "cent"),
+        ("cts", "cent"), ("c", "cent"), ("¢", "cent"),
+        ("₩", "KRW"), ("krw", "KRW"), ("won", "KRW"), ("südkoreanische won", "KRW"),
+        ("südkoreanischer won", "KRW"), ("südkoreanischen won", "KRW"), ("südkoreanischem won", "KRW"),
+        ("südkoreanisches won", "KRW"), ("sudkoreanische won", "KRW"), ("sudkoreanischer won", "KRW"),
+        ("sudkoreanischen won", "KRW"), ("sudkoreanischem won", "KRW"), ("sudkoreanisches won", "KRW"),
+        ("$", "$"), ("dollar", "$"), ("dollars", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("eur", "EUR"),
+        ("£", "£"), ("pfund", "£"), ("pfunds", "£"), ("pfd.", "£"), ("pfd", "£"),
+        ("gbp", "GBP"), ("pfund sterling", "GBP"), ("pfunds sterling", "GBP"),
+        ("aud", "AUD"), ("australische dollar", "AUD"), ("australischer dollar", "AUD"),
+        ("australischen dollar", "AUD"), ("australischem dollar", "AUD"), ("australisches dollar", "AUD"),
+        ("usd", "USD"), ("us$", "USD"), ("us dollar", "USD"), ("us-dollar", "USD"),
+        ("pt", "PTS"), ("pts", "PTS"), ("pta", "PTS"), ("ptas", "PTS"),
+        ("inr", "INR"), ("₹", "INR"), ("rupie", "INR"), ("rupien", "INR"), ("indische rupie", "INR"),
+        ("indische rupien", "INR"), ("indischer rupie", "INR"), ("indischer rupien", "INR"),
+        ("indischen rupie", "INR"), ("indischen rupien", "INR"),
+        ("chf", "CHF"), ("franken", "CHF"), ("frankens", "CHF"), ("schweizer franken", "CHF"),
+        ("schweizer frankens", "CHF"),
+    ];
+
+    /// English
+    pub static EN: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dollar", "$"), ("dollars", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("euros", "EUR"), ("eur", "EUR"), ("eurs", "EUR"), ("€uro", "EUR"),
+        ("€uros", "EUR"),
+        ("£", "£"), ("pound", "£"), ("pounds", "£"),
+        ("usd", "USD"), ("us$", "USD"),
+        ("gbp", "GBP"),
+        ("¥", "JPY"), ("yen", "JPY"), ("yens", "JPY"), ("jpy", "JPY"),
+        ("pt", "PTS"), ("pts", "PTS"), ("pta", "PTS"), ("ptas", "PTS"),
+        ("cent", "cent"), ("cents", "cent"), ("penny", "cent"), ("pennies", "cent"), ("c", "cent"),
+        ("¢", "cent"),
+        ("inr", "INR"), ("rs", "INR"), ("rs.", "INR"), ("rupee", "INR"), ("rupees", "INR"),
+    ];
+
+    /// Spanish
+    pub static ES: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dólar", "$"), ("dolar", "$"), ("dólares", "$"), ("dolares", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("euros", "EUR"), ("eur", "EUR"), ("eurs", "EUR"), ("€uro", "EUR"),
+        ("€uros", "EUR"),
+        ("pound", "£"), ("pounds", "£"), ("libra", "£"), ("libras", "£"), ("£", "£"),
+        ("usd", "USD"), ("us$", "USD"), ("dólar estadounidense", "USD"), ("dólar americano", "USD"),
+        ("dolar estadounidense", "USD"), ("dolar americano", "USD"), ("dólares estadounidense", "USD"),
+        ("dólares americano", "USD"), ("dolares estadounidense", "USD"), ("dolares americano", "USD"),
+        ("bitcóin", "฿"), ("bitcóines", "฿"),
+        ("gbp", "GBP"), ("libra esterlina", "GBP"), ("libras esterlina", "GBP"),
+        ("centavo", "cent"), ("centavos", "cent"),
+    ];
+
+    /// Mexican Spanish, on top of the Spanish ones
+    pub static ES_MX: &'static [(&'static str, &'static str)] = &[
+        ("mxn", "MXN"), ("peso", "MXN"), ("pesos", "MXN"), ("peso mexicano", "MXN"),
+        ("peso mexicanos", "MXN"), ("pesos mexicano", "MXN"), ("pesos mexicanos", "MXN"),
+    ];
+
+    /// French
+    pub static FR: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dollar", "$"), ("dollars", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("euros", "EUR"), ("eur", "EUR"), ("eurs", "EUR"), ("€uro", "EUR"),
+        ("€uros", "EUR"),
+        ("£", "£"), ("livre", "£"), ("livres", "£"),
+        ("usd", "USD"), ("us$", "USD"), ("dollar américain", "USD"), ("dollar américains", "USD"),
+        ("dollar americain", "USD"), ("dollar americains", "USD"), ("dollars américain", "USD"),
+        ("dollars américains", "USD"), ("dollars americain", "USD"), ("dollars americains", "USD"),
+        ("chf", "CHF"), ("franc suisse", "CHF"), ("franc suisses", "CHF"), ("francs suisse", "CHF"),
+        ("francs suisses", "CHF"),
+        ("jpy", "JPY"), ("yen", "JPY"), ("yens", "JPY"),
+        ("cny", "CNY"), ("cnh", "CNY"), ("rmb", "CNY"), ("yuan", "CNY"), ("yuans", "CNY"),
+        ("renmimbi", "CNY"), ("renmimbis", "CNY"),
+        ("bitcoin", "฿"), ("bitcoins", "฿"),
+        ("gbp", "GBP"), ("livre sterling", "GBP"), ("livre sterlings", "GBP"), ("livres sterling", "GBP"),
+        ("livres sterlings", "GBP"),
+        ("centime", "EUR-cent"), ("centimes", "EUR-cent"), ("cent", "cent"), ("cents", "cent"), ("penny", "cent"),
+        ("pennies", "cent"), ("fen", "CNY-cent"), ("fens", "CNY-cent"),
+    ];
+
+    /// Italian
+    pub static IT: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dollaro", "$"), ("dollari", "$"),
+        ("€", "EUR"), ("euro", "EUR"),
+        ("sterlina", "£"), ("sterline", "£"), ("£", "£"),
+        ("usd", "USD"), ("us$", "USD"), ("dollaro americani", "USD"), ("dollaro americano", "USD"),
+        ("dollaro statunitensi", "USD"), ("dollaro statunitense", "USD"), ("dollari americani", "USD"),
+        ("dollari americano", "USD"), ("dollari statunitensi", "USD"), ("dollari statunitense", "USD"),
+        ("gbp", "GBP"), ("sterlina inglesi", "GBP"), ("sterline inglesi", "GBP"), ("libbre inglesi", "GBP"),
+        ("lira sterlina", "GBP"), ("lira sterline", "GBP"), ("lire sterlina", "GBP"),
+        ("lire sterline", "GBP"),
+        ("chf", "CHF"), ("franchi svizzeri", "CHF"), ("franco svizzero", "CHF"),
+        ("bitcoin", "฿"),
+        ("centesimo", "EUR-cent"), ("centesimi", "EUR-cent"),
+    ];
+
+    /// Japanese
+    pub static JA: &'static [(&'static str, &'static str)] = &[
+        ("アメリカドル", "USD"),
+        ("ドル", "$"), ("$", "$"), ("＄", "$"),
+        ("ユーロ", "EUR"), ("€", "EUR"),
+        ("ポンド", "£"), ("£", "£"),
+        ("GBP", "GBP"), ("イギリスポンド", "GBP"),
+        ("JPY", "JPY"), ("円", "JPY"),
+        ("CNY", "CNY"), ("人民元", "CNY"), ("元", "CNY"),
+        ("¥", "¥"),
+        ("KR", "KR"), ("クローネ", "KR"),
+        ("DKK", "DKK"), ("デンマーククローネ", "DKK"),
+        ("SEK", "SEK"), ("スウェーデンクローナ", "SEK"),
+        ("NOK", "NOK"), ("ノルウェークローネ", "NOK"),
+        ("CHF", "CHF"), ("スイスフラン", "CHF"),
+        ("ウォン", "KRW"), ("₩", "KRW"),
+        ("ルピー", "INR"),
+        ("ルーブル", "RUB"),
+        ("オーストラリアドル", "AUD"),
+        ("香港ドル", "HKD"),
+        ("カナダドル", "CAD"),
+        ("ビットコイン", "฿"), ("฿", "฿"),
+        ("銭", "JPY-cent"),
+    ];
+
+    /// Korean
+    pub static KO: &'static [(&'static str, &'static str)] = &[
+        ("₩", "KRW"), ("원", "KRW"), ("krw", "KRW"),
+        ("$", "$"), ("달러", "$"), ("불", "$"),
+        ("cent", "cent"), ("cents", "cent"), ("센트", "cent"), ("센츠", "cent"), ("c", "cent"), ("¢", "cent"),
+        ("€", "EUR"), ("유로", "EUR"), ("euro", "EUR"), ("eur", "EUR"),
+        ("£", "£"), ("파운드", "£"), ("영국파운드", "£"),
+        ("gbp", "GBP"),
+        ("aud", "AUD"), ("호주달러", "AUD"),
+        ("usd", "USD"), ("us$", "USD"),
+        ("pt", "PTS"), ("pts", "PTS"), ("pta", "PTS"), ("ptas", "PTS"),
+        ("inr", "INR"), ("rs", "INR"), ("rs.", "INR"), ("rupee", "INR"), ("rupees", "INR"), ("Rupee", "INR"),
+        ("Rupees", "INR"), ("루피", "INR"), ("인도루피", "INR"),
+        ("디르함", "AED"), ("aed", "AED"), ("dirham", "AED"), ("dirhams", "AED"),
+    ];
+
+    /// Dutch
+    pub static NL: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dollar", "$"), ("dollars", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("euros", "EUR"), ("eur", "EUR"),
+        ("ponden", "£"), ("pond", "£"), ("£", "£"),
+        ("usd", "USD"), ("us$", "USD"), ("amerikaanse dollar", "USD"), ("amerikaanse dollars", "USD"),
+        ("gbp", "GBP"), ("britse ponden", "GBP"), ("britse pond", "GBP"), ("pond sterling", "GBP"),
+        ("chf", "CHF"), ("zwitserse frank", "CHF"), ("zwitserse franks", "CHF"),
+        ("bitcoin", "฿"), ("bitcoins", "฿"),
+        ("cent", "cent"), ("centen", "cent"), ("cents", "cent"), ("eurocent", "EUR-cent"),
+        ("eurocenten", "EUR-cent"), ("eurocents", "EUR-cent"),
+    ];
+
+    /// Portuguese
+    pub static PT: &'static [(&'static str, &'static str)] = &[
+        ("$", "$"), ("dólar", "$"), ("dolar", "$"), ("dólares", "$"), ("dolares", "$"),
+        ("€", "EUR"), ("euro", "EUR"), ("euros", "EUR"), ("€uro", "EUR"), ("€uros", "EUR"),
+        ("libra", "£"), ("libras", "£"), ("£", "£"),
+        ("usd", "USD"), ("us$", "USD"), ("dólar americano", "USD"), ("dólar americanos", "USD"),
+        ("dólar estadunidense", "USD"), ("dólar estadunidenses", "USD"), ("dólar norte-americano", "USD"),
+        ("dólar norte-americanos", "USD"), ("dolar americano", "USD"), ("dolar americanos", "USD"),
+        ("dolar estadunidense", "USD"), ("dolar estadunidenses", "USD"), ("dolar norte-americano", "USD"),
+        ("dolar norte-americanos", "USD"), ("dólares americano", "USD"), ("dólares americanos", "USD"),
+        ("dólares estadunidense", "USD"), ("dólares estadunidenses", "USD"),
+        ("dólares norte-americano", "USD"), ("dólares norte-americanos", "USD"),
+        ("dolares americano", "USD"), ("dolares americanos", "USD"), ("dolares estadunidense", "USD"),
+        ("dolares estadunidenses", "USD"), ("dolares norte-americano", "USD"),
+        ("dolares norte-americanos", "USD"),
+        ("gbp", "GBP"), ("libra esterlina", "GBP"), ("libra esterlinas", "GBP"), ("libras esterlina", "GBP"),
+        ("libras esterlinas", "GBP"),
+        ("r$", "BRL"), ("brl", "BRL"), ("reais", "BRL"), ("real", "BRL"),
+        ("bitcoin", "฿"), ("bitcoins", "฿"),
+        ("centavo", "BRL-cent"), ("centavos", "BRL-cent"), ("cêntimo", "EUR-cent"),
+        ("cêntimos", "EUR-cent"), ("centimo", "EUR-cent"), ("centimos", "EUR-cent"),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_currency() {
+        assert_eq!(Some("USD"), Currency::resolve("$", None).map(|c| c.code));
+        assert_eq!(Some("USD"), Currency::resolve("$", Region::from_code("fr")).map(|c| c.code));
+        assert_eq!(Some("CAD"), Currency::resolve("$", Region::from_code("ca")).map(|c| c.code));
+        assert_eq!(Some("AUD"), Currency::resolve("$", Region::from_code("AU")).map(|c| c.code));
+        assert_eq!(Some("JPY"), Currency::resolve("¥", None).map(|c| c.code));
+        assert_eq!(Some("CNY"), Currency::resolve("¥", Region::from_code("CN")).map(|c| c.code));
+        assert_eq!(Some("SEK"), Currency::resolve("KR", None).map(|c| c.code));
+        assert_eq!(Some("NOK"), Currency::resolve("kr", Region::from_code("NO")).map(|c| c.code));
+        // ISO codes are not ambiguous
+        assert_eq!(Some("USD"), Currency::resolve("USD", Region::from_code("CA")).map(|c| c.code));
+        assert_eq!(Some("ESP"), Currency::resolve("PTS", None).map(|c| c.code));
+        assert_eq!(Some("USD"), Currency::resolve("cent", None).map(|c| c.code));
+        assert_eq!(Some("CAD"), Currency::resolve("cent", Region::from_code("CA")).map(|c| c.code));
+        assert_eq!(Some("EUR"), Currency::resolve("cent", Region::from_code("FR")).map(|c| c.code));
+        assert_eq!(Some("JPY"), Currency::resolve("JPY-cent", Region::from_code("US")).map(|c| c.code));
+        assert!(Currency::resolve("XYZ-cent", None).is_none());
+        assert!(is_minor_unit("BRL-cent"));
+        assert!(!is_minor_unit("BRL"));
+        assert_eq!(0, Currency::from_code("JPY").unwrap().minor_digits);
+        assert_eq!(12.35, Currency::from_code("EUR").unwrap().round(12.346));
+        assert_eq!(0.5, Currency::from_code("EUR").unwrap().amount(MINOR_UNIT, 50.0));
+        assert_eq!(3.0, Currency::from_code("JPY").unwrap().amount("JPY", 2.6));
+        assert_eq!(0.5, Currency::from_code("JPY").unwrap().amount("JPY-cent", 50.0));
+    }
+
+    #[test]
+    fn test_names_resolve() {
+        for table in &[names::DE, names::EN, names::ES, names::ES_MX, names::FR, names::IT, names::JA,
+                       names::KO, names::NL, names::PT] {
+            for &(name, unit) in table.iter() {
+                assert!(Currency::resolve(unit, None).is_some(), "{} tags the unknown unit {}", name, unit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_minor_units_of_names() {
+        fn unit(table: &[(&str, &'static str)], name: &str) -> &'static str {
+            table.iter().find(|&&(n, _)| n == name).map(|&(_, unit)| unit).unwrap()
+        }
+        let brazil = Region::from_code("BR");
+        assert_eq!(Some("JPY"), Currency::resolve(unit(names::JA, "銭"), None).map(|c| c.code));
+        assert_eq!(Some("BRL"), Currency::resolve(unit(names::PT, "centavos"), brazil).map(|c| c.code));
+        assert_eq!(Some("BRL"), Currency::resolve(unit(names::PT, "centavos"), None).map(|c| c.code));
+        assert_eq!(Some("EUR"), Currency::resolve(unit(names::FR, "centimes"), None).map(|c| c.code));
+        assert_eq!(Some("CNY"), Currency::resolve(unit(names::FR, "fen"), None).map(|c| c.code));
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt, result};
 
 use rustling::*;
-use moment::{RcConstraint, Period, Grain, Local};
+use moment::{RcConstraint, Period, Grain, Weekday, Zone};
 
 /// Union of all possible values parsed by the ontology.
 rustling_value! {
@@ -16,9 +16,17 @@ rustling_value! {
         Time(TimeValue),
         Duration(DurationValue),
         Percentage(PercentageValue),
+        Quantity(QuantityValue),
+        Email(EmailValue),
+        Url(UrlValue),
+        PhoneNumber(PhoneNumberValue),
+        NumberInterval(NumberIntervalValue),
+        DurationInterval(DurationIntervalValue),
+        Recurrence(RecurrenceValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
+        Timezone(TimezoneValue),
     }
 
     fn latent(v: &Dimension) -> bool {
@@ -31,9 +39,17 @@ rustling_value! {
             &Dimension::MoneyUnit(_) => true,
             &Dimension::Time(ref tv) => tv.latent,
             &Dimension::Duration(_) => false,
+            &Dimension::Quantity(_) => false,
+            &Dimension::Email(_) => false,
+            &Dimension::Url(_) => false,
+            &Dimension::PhoneNumber(_) => false,
+            &Dimension::NumberInterval(_) => false,
+            &Dimension::DurationInterval(_) => false,
+            &Dimension::Recurrence(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
+            &Dimension::Timezone(_) => true,
         }
     }
 
@@ -47,9 +63,17 @@ rustling_value! {
             &Dimension::MoneyUnit(_) => None,
             &Dimension::Time(ref tv) => Some(Payload(tv.constraint.grain())),
             &Dimension::Duration(_) => None,
+            &Dimension::Quantity(_) => None,
+            &Dimension::Email(_) => None,
+            &Dimension::Url(_) => None,
+            &Dimension::PhoneNumber(_) => None,
+            &Dimension::NumberInterval(_) => None,
+            &Dimension::DurationInterval(_) => None,
+            &Dimension::Recurrence(_) => None,
             &Dimension::Cycle(_) => None,
             &Dimension::UnitOfDuration(_) => None,
             &Dimension::RelativeMinute(_) => None,
+            &Dimension::Timezone(_) => None,
         }
     }
 }
@@ -66,9 +90,17 @@ impl Dimension {
             &Dimension::MoneyUnit(_) => false,
             &Dimension::Time(ref tv) => tv.is_too_ambiguous(),
             &Dimension::Duration(_) => false,
+            &Dimension::Quantity(_) => false,
+            &Dimension::Email(_) => false,
+            &Dimension::Url(_) => false,
+            &Dimension::PhoneNumber(_) => false,
+            &Dimension::NumberInterval(_) => false,
+            &Dimension::DurationInterval(_) => false,
+            &Dimension::Recurrence(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
+            &Dimension::Timezone(_) => true,
         }
     }
 }
@@ -92,9 +124,17 @@ impl fmt::Display for Dimension {
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
             &Dimension::Time(_) => write!(fmt, "Time"),
             &Dimension::Duration(_) => write!(fmt, "Duration"),
+            &Dimension::Quantity(_) => write!(fmt, "Quantity"),
+            &Dimension::Email(ref v) => write!(fmt, "Email: {}", v.0),
+            &Dimension::Url(ref v) => write!(fmt, "Url: {}", v.value),
+            &Dimension::PhoneNumber(ref v) => write!(fmt, "PhoneNumber: {}", v.0),
+            &Dimension::NumberInterval(_) => write!(fmt, "NumberInterval"),
+            &Dimension::DurationInterval(_) => write!(fmt, "DurationInterval"),
+            &Dimension::Recurrence(_) => write!(fmt, "Recurrence"),
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
             &Dimension::UnitOfDuration(_) => write!(fmt, "UnitOfDuration"),
             &Dimension::RelativeMinute(_) => write!(fmt, "RelativeMinute"),
+            &Dimension::Timezone(_) => write!(fmt, "Timezone"),
         }
     }
 }
@@ -151,6 +191,21 @@ pub struct AmountOfMoneyValue {
     pub value: f32,
     pub precision: Precision,
     pub unit: Option<&'static str>,
+    /// Set for open bounds, e.g. After for "more than 100 €" and Before for "under $50"
+    pub direction: Option<Direction>,
+    /// Upper end of a range such as "between 10 and 20 euros", `value` holding the lower end
+    pub end: Option<f32>,
+}
+
+impl AmountOfMoneyValue {
+    pub fn is_interval(&self) -> bool {
+        self.direction.is_some() || self.end.is_some()
+    }
+
+    /// Whether the amount is given in the hundredths of a currency, e.g. "50 cents"
+    pub fn is_minor_unit(&self) -> bool {
+        self.unit.map_or(false, ::currency::is_minor_unit)
+    }
 }
 
 /// Payload for the unit of money of Dimension
@@ -325,6 +380,8 @@ pub struct FloatValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// Exact numerator and denominator when the value was expressed as a fraction
+    pub fraction: Option<Fraction>,
 }
 
 impl FloatValue {
@@ -335,6 +392,17 @@ impl FloatValue {
         })
     }
 
+    pub fn new_fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+        if denominator <= 0 {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(FloatValue {
+            value: numerator as f32 / denominator as f32,
+            fraction: Some(Fraction { numerator, denominator }),
+            ..FloatValue::default()
+        })
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<FloatValue> {
         Ok(FloatValue {
@@ -362,6 +430,22 @@ impl FloatValue {
     }
 }
 
+/// Numerator and denominator of a FloatValue, e.g. 5/2 for "two and a half"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Fraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Fraction {
+    pub fn negated(self) -> Fraction {
+        Fraction {
+            numerator: -self.numerator,
+            ..self
+        }
+    }
+}
+
 impl From<FloatValue> for NumberValue {
     fn from(v: FloatValue) -> NumberValue {
         NumberValue::Float(v)
@@ -439,18 +523,259 @@ impl NumberValue {
             &NumberValue::Integer(ref v) => v.grain,
         }
     }
+
+    pub fn fraction(&self) -> Option<Fraction> {
+        match self {
+            &NumberValue::Float(ref v) => v.fraction,
+            &NumberValue::Integer(_) => None,
+        }
+    }
+}
+
+/// Payload for the number intervals of Dimension, e.g. "between 3 and 5" or "at least 3"
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct NumberIntervalValue {
+    /// Lower bound, None for "up to 10"
+    pub from: Option<f32>,
+    /// Upper bound, None for "at least 3"
+    pub to: Option<f32>,
+    /// false when the lower bound itself is excluded, e.g. "more than 100"
+    pub from_inclusive: bool,
+    /// false when the upper bound itself is excluded, e.g. "less than 10"
+    pub to_inclusive: bool,
+}
+
+impl NumberIntervalValue {
+    pub fn between(from: f32, to: f32) -> RuleResult<NumberIntervalValue> {
+        if from >= to {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(NumberIntervalValue {
+            from: Some(from),
+            to: Some(to),
+            from_inclusive: true,
+            to_inclusive: true,
+        })
+    }
+
+    pub fn at_least(from: f32) -> NumberIntervalValue {
+        NumberIntervalValue {
+            from: Some(from),
+            from_inclusive: true,
+            ..NumberIntervalValue::default()
+        }
+    }
+
+    pub fn more_than(from: f32) -> NumberIntervalValue {
+        NumberIntervalValue {
+            from: Some(from),
+            from_inclusive: false,
+            ..NumberIntervalValue::default()
+        }
+    }
+
+    pub fn up_to(to: f32) -> NumberIntervalValue {
+        NumberIntervalValue {
+            to: Some(to),
+            to_inclusive: true,
+            ..NumberIntervalValue::default()
+        }
+    }
+
+    pub fn less_than(to: f32) -> NumberIntervalValue {
+        NumberIntervalValue {
+            to: Some(to),
+            to_inclusive: false,
+            ..NumberIntervalValue::default()
+        }
+    }
 }
 
 /// Payload for the temperatures of Dimension
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct TemperatureValue {
     pub value: f32,
-    /// Celsius, Fahrenheit, ...
-    pub unit: Option<&'static str>,
+    pub unit: Option<TemperatureUnit>,
     /// true if it can not be confirmed that the value is actually a temperature
     pub latent: bool,
+    /// Set for open bounds, e.g. After for "above 30 degrees" and Before for "below freezing"
+    pub direction: Option<Direction>,
+    /// Upper end of a range such as "between 18 and 22 degrees", `value` holding the lower end
+    pub end: Option<f32>,
+}
+
+impl TemperatureValue {
+    pub fn is_interval(&self) -> bool {
+        self.direction.is_some() || self.end.is_some()
+    }
+}
+
+/// Unit of a temperature. `Degree` is a temperature in degrees of an unstated scale.
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub enum TemperatureUnit {
+    Degree,
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+impl TemperatureUnit {
+    pub fn to_celsius(&self, value: f32) -> f32 {
+        match self {
+            &TemperatureUnit::Degree => value,
+            &TemperatureUnit::Celsius => value,
+            &TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            &TemperatureUnit::Kelvin => value - 273.15,
+        }
+    }
+
+    pub fn from_celsius(&self, value: f32) -> f32 {
+        match self {
+            &TemperatureUnit::Degree => value,
+            &TemperatureUnit::Celsius => value,
+            &TemperatureUnit::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+            &TemperatureUnit::Kelvin => value + 273.15,
+        }
+    }
+
+    /// Converts a temperature in this unit to the `target` unit, degrees being left as they are
+    pub fn convert(&self, value: f32, target: TemperatureUnit) -> f32 {
+        if *self == TemperatureUnit::Degree || target == TemperatureUnit::Degree {
+            value
+        } else {
+            target.from_celsius(self.to_celsius(value))
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &TemperatureUnit::Degree => "degree",
+            &TemperatureUnit::Celsius => "celsius",
+            &TemperatureUnit::Fahrenheit => "fahrenheit",
+            &TemperatureUnit::Kelvin => "kelvin",
+        }
+    }
+}
+
+/// Kind of physical quantity measured by a `QuantityUnit`
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub enum QuantityKind {
+    Distance,
+    Volume,
+    Mass,
+}
+
+/// Units of the physical quantities of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+pub enum QuantityUnit {
+    Kilometre,
+    Metre,
+    Mile,
+    Foot,
+    Litre,
+    Millilitre,
+    Cup,
+    Gallon,
+    Kilogram,
+    Gram,
+    Pound,
+    Ounce,
+}
+
+impl QuantityUnit {
+    pub fn kind(&self) -> QuantityKind {
+        match self {
+            &QuantityUnit::Kilometre => QuantityKind::Distance,
+            &QuantityUnit::Metre => QuantityKind::Distance,
+            &QuantityUnit::Mile => QuantityKind::Distance,
+            &QuantityUnit::Foot => QuantityKind::Distance,
+            &QuantityUnit::Litre => QuantityKind::Volume,
+            &QuantityUnit::Millilitre => QuantityKind::Volume,
+            &QuantityUnit::Cup => QuantityKind::Volume,
+            &QuantityUnit::Gallon => QuantityKind::Volume,
+            &QuantityUnit::Kilogram => QuantityKind::Mass,
+            &QuantityUnit::Gram => QuantityKind::Mass,
+            &QuantityUnit::Pound => QuantityKind::Mass,
+            &QuantityUnit::Ounce => QuantityKind::Mass,
+        }
+    }
+
+    /// Factor converting a value in this unit into the reference unit of its kind:
+    /// metre for distances, litre for volumes and kilogram for masses.
+    /// Cups and gallons are US customary units.
+    pub fn si_factor(&self) -> f32 {
+        match self {
+            &QuantityUnit::Kilometre => 1000.0,
+            &QuantityUnit::Metre => 1.0,
+            &QuantityUnit::Mile => 1609.344,
+            &QuantityUnit::Foot => 0.3048,
+            &QuantityUnit::Litre => 1.0,
+            &QuantityUnit::Millilitre => 0.001,
+            &QuantityUnit::Cup => 0.236_588,
+            &QuantityUnit::Gallon => 3.785_411,
+            &QuantityUnit::Kilogram => 1.0,
+            &QuantityUnit::Gram => 0.001,
+            &QuantityUnit::Pound => 0.453_592_37,
+            &QuantityUnit::Ounce => 0.028_349_523,
+        }
+    }
+
+    pub fn to_si(&self, value: f32) -> f32 {
+        value * self.si_factor()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            &QuantityUnit::Kilometre => "km",
+            &QuantityUnit::Metre => "m",
+            &QuantityUnit::Mile => "mi",
+            &QuantityUnit::Foot => "ft",
+            &QuantityUnit::Litre => "l",
+            &QuantityUnit::Millilitre => "ml",
+            &QuantityUnit::Cup => "cup",
+            &QuantityUnit::Gallon => "gal",
+            &QuantityUnit::Kilogram => "kg",
+            &QuantityUnit::Gram => "g",
+            &QuantityUnit::Pound => "lb",
+            &QuantityUnit::Ounce => "oz",
+        }
+    }
 }
 
+/// Payload for the physical quantities (distance, volume, mass) of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct QuantityValue {
+    pub value: f32,
+    pub unit: QuantityUnit,
+    pub precision: Precision,
+}
+
+impl QuantityValue {
+    pub fn new(value: f32, unit: QuantityUnit) -> QuantityValue {
+        QuantityValue { value, unit, precision: Precision::Exact }
+    }
+
+    pub fn precision(self, precision: Precision) -> QuantityValue {
+        QuantityValue { precision, ..self }
+    }
+}
+
+/// Payload for the email addresses of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct EmailValue(pub String);
+
+/// Payload for the urls of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct UrlValue {
+    pub value: String,
+    /// Host part of the url, without scheme, `www.` prefix, port or path
+    pub domain: String,
+}
+
+/// Payload for the phone numbers of Dimension, normalized to digits with an optional leading `+`
+#[derive(Debug, PartialEq, Clone)]
+pub struct PhoneNumberValue(pub String);
+
 /// Payload for the cycle of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct CycleValue {
@@ -493,12 +818,16 @@ pub enum Ambiguity {
 /// Payload for the time of Dimension
 #[derive(Clone)]
 pub struct TimeValue {
-    pub constraint: RcConstraint<Local>,
+    pub constraint: RcConstraint<Zone>,
     pub form: Form,
     pub direction: Option<BoundedDirection>,
     pub precision: Precision,
     pub latent: bool,
     pub ambiguity: Ambiguity,
+    /// Zone stated in the expression, e.g. "EST" in "3pm EST"
+    pub timezone: Option<TimezoneValue>,
+    /// The day and month are written with numbers only, e.g. "3/4", and may be read either way
+    pub numeric_date: bool,
 }
 
 impl TimeValue {
@@ -540,12 +869,13 @@ pub enum Form {
     MonthDay(Option<MonthDayForm>),
     YearMonthDay(Option<YearMonthDayForm>),
     TimeOfDay(TimeOfDayForm),
-    DayOfWeek { not_immediate: bool },
+    DayOfWeek { weekday: Weekday, not_immediate: bool },
     PartOfDay(PartOfDayForm),
     PartOfMonth,
     PartOfYear,
-    Meal,
-    Celebration,
+    Meal(MealForm),
+    /// Holiday, with a stable identifier such as "easter_monday"
+    Celebration(&'static str),
     Empty,
 }
 
@@ -558,11 +888,11 @@ impl Form {
             &Form::MonthDay(_) => None,
             &Form::YearMonthDay(_) => None,
             &Form::TimeOfDay(_) => None,
-            &Form::DayOfWeek { not_immediate } => Some(not_immediate),
+            &Form::DayOfWeek { not_immediate, .. } => Some(not_immediate),
             &Form::Empty => None,
             &Form::PartOfDay { .. } => None,
-            &Form::Meal => None,
-            &Form::Celebration => None,
+            &Form::Meal(_) => None,
+            &Form::Celebration(_) => None,
             &Form::PartOfMonth => None,
             &Form::PartOfYear => None,
             &Form::DayOfMonth => None,
@@ -632,6 +962,16 @@ impl BoundedDirection {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MealForm {
+    Breakfast,
+    Brunch,
+    Lunch,
+    /// Coffee break, tea time or any other snack
+    Snack,
+    Dinner,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PartOfDayForm {
     Morning,
@@ -756,5 +1096,73 @@ impl DurationValue {
     }
 }
 
+/// Payload for recurring time expressions of Dimension, e.g. "every monday" or "each day at 8am"
+#[derive(Debug, Clone)]
+pub struct RecurrenceValue {
+    /// Grain of the repeated period, e.g. Week for "every monday"
+    pub frequency: Grain,
+    /// Number of `frequency` periods between two occurrences, 2 for "every other week"
+    pub interval: i64,
+    pub by_day: Vec<Weekday>,
+    pub by_hour: Option<u32>,
+    pub by_minute: Option<u32>,
+    pub start: Option<TimeValue>,
+    pub end: Option<TimeValue>,
+}
+
+impl RecurrenceValue {
+    pub fn new(frequency: Grain) -> RecurrenceValue {
+        RecurrenceValue {
+            frequency: frequency,
+            interval: 1,
+            by_day: vec![],
+            by_hour: None,
+            by_minute: None,
+            start: None,
+            end: None,
+        }
+    }
+}
+
+// Start and end are left out as TimeValue can not be compared, see TimeValue.
+impl PartialEq for RecurrenceValue {
+    fn eq(&self, other: &RecurrenceValue) -> bool {
+        self.frequency == other.frequency
+            && self.interval == other.interval
+            && self.by_day == other.by_day
+            && self.by_hour == other.by_hour
+            && self.by_minute == other.by_minute
+    }
+}
+
+/// Payload for the duration ranges of Dimension, e.g. "2 to 3 hours" or "at least 20 minutes"
+#[derive(Debug, PartialEq, Clone)]
+pub struct DurationIntervalValue {
+    /// Shortest duration, None for "less than a week"
+    pub min: Option<Period>,
+    /// Longest duration, None for "at least 20 minutes"
+    pub max: Option<Period>,
+    pub precision: Precision,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct RelativeMinuteValue(pub i32);
+
+/// Payload for the time zones of Dimension, e.g. "UTC" or "heure de Paris"
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct TimezoneValue {
+    /// Abbreviation or IANA name of the zone
+    pub name: &'static str,
+    /// Standard offset from UTC, in minutes
+    pub utc_offset: i32,
+    pub daylight_saving: Option<DaylightSaving>,
+}
+
+/// Daylight saving rules of the zones known by the ontology
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum DaylightSaving {
+    /// From the second Sunday of March to the first Sunday of November
+    NorthAmerica,
+    /// From the last Sunday of March to the last Sunday of October
+    Europe,
+}
@@ -2,7 +2,8 @@ use rustling::*;
 use dimension::*;
 use moment::*;
 use std::ops;
-use regex::Regex;
+use regex::{self, Regex};
+use currency::Currency;
 
 pub fn compose_numbers(a: &NumberValue, b: &NumberValue) -> RuleResult<NumberValue> {
     let grain = a.grain().unwrap_or(0) as u32;
@@ -69,9 +70,69 @@ pub fn decimal_hour_in_minute(a: &str, b: &str) -> RuleResult<i64> {
     Ok((b_value * 6) / 10i64.pow(b.len() as u32 - 1) + a_value * 60)
 }
 
+pub fn compose_mixed_fraction(integer: i64, fraction: &FloatValue) -> RuleResult<FloatValue> {
+    match fraction.fraction {
+        Some(f) if integer >= 0 && 0 < f.numerator && f.numerator < f.denominator => {
+            FloatValue::new_fraction(integer * f.denominator + f.numerator, f.denominator)
+        }
+        _ => Err(RuleErrorKind::Invalid.into()),
+    }
+}
+
+/// Spreads a fractional amount of `grain` over finer grains, e.g. 2.5 days is 2 days and 12 hours.
+pub fn period_from_fraction(value: f32, grain: Grain) -> RuleResult<Period> {
+    if value < 0.0 {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    let mut period = Period::default();
+    let mut grain = grain;
+    let mut remainder = value;
+    loop {
+        let whole = remainder.trunc();
+        if whole > 0.0 {
+            period = period + PeriodComp::new(grain, whole as i64);
+        }
+        remainder -= whole;
+        if remainder < 1e-3 || grain == Grain::Second {
+            break;
+        }
+        let finer = grain.next();
+        remainder *= (grain.coarse_num_secs() / finer.coarse_num_secs()) as f32;
+        if (remainder - remainder.round()).abs() < 1e-3 {
+            remainder = remainder.round();
+        }
+        grain = finer;
+    }
+    Ok(period)
+}
+
+/// Alternation of the names of currencies of a language, the longest first so that they are
+/// preferred over their prefixes
+pub fn money_unit_regex(names: &[(&str, &str)]) -> String {
+    let mut names: Vec<&str> = names.iter().map(|&(name, _)| name).collect();
+    names.sort_by(|a, b| b.chars().count().cmp(&a.chars().count()));
+    names.iter().map(|name| regex::escape(name)).collect::<Vec<_>>().join("|")
+}
+
+/// Unit of money tagged for a name of currency, known to the currency registry
+pub fn money_unit(names: &[(&'static str, &'static str)], text: &str) -> RuleResult<MoneyUnitValue> {
+    let text = text.to_lowercase();
+    let unit = names.iter()
+        .find(|&&(name, _)| name.to_lowercase() == text)
+        .map(|&(_, unit)| unit)
+        .ok_or_else(|| -> RuleError { RuleErrorKind::Invalid.into() })?;
+    match Currency::resolve(unit, None) {
+        Some(_) => Ok(MoneyUnitValue { unit: Some(unit) }),
+        None => Err(RuleErrorKind::Invalid.into()),
+    }
+}
+
 pub fn compose_money(a: &AmountOfMoneyValue,
                      b: &AmountOfMoneyValue)
                      -> RuleResult<AmountOfMoneyValue> {
+    if a.is_interval() || b.is_interval() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
     let amount = a.value + b.value / 100.0;
     Ok(AmountOfMoneyValue {
            value: amount,
@@ -83,6 +144,9 @@ pub fn compose_money(a: &AmountOfMoneyValue,
 pub fn compose_money_number(a: &AmountOfMoneyValue,
                             b: &NumberValue)
                             -> RuleResult<AmountOfMoneyValue> {
+    if a.is_interval() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
     let amount = a.value + b.value() / 100.0;
     Ok(AmountOfMoneyValue {
            value: amount,
@@ -91,6 +155,185 @@ pub fn compose_money_number(a: &AmountOfMoneyValue,
        })
 }
 
+/// Builds the range between two amounts, the start taking the unit of the end when it has none
+/// as in "between 10 and 20 euros".
+pub fn money_interval(start: &AmountOfMoneyValue, end: &AmountOfMoneyValue) -> RuleResult<AmountOfMoneyValue> {
+    if start.is_interval() || end.is_interval() || start.value >= end.value {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    if start.unit.is_some() && end.unit.is_some() && start.unit != end.unit {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    let precision = if start.precision == Precision::Approximate || end.precision == Precision::Approximate {
+        Precision::Approximate
+    } else {
+        Precision::Exact
+    };
+    Ok(AmountOfMoneyValue {
+        value: start.value,
+        end: Some(end.value),
+        unit: end.unit.or(start.unit),
+        precision: precision,
+        direction: None,
+    })
+}
+
+pub fn money_number_interval(start: &NumberValue, end: &AmountOfMoneyValue) -> RuleResult<AmountOfMoneyValue> {
+    money_interval(&AmountOfMoneyValue { value: start.value(), ..AmountOfMoneyValue::default() }, end)
+}
+
+pub fn money_bound(amount: &AmountOfMoneyValue, direction: Direction) -> RuleResult<AmountOfMoneyValue> {
+    if amount.is_interval() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    Ok(AmountOfMoneyValue {
+        direction: Some(direction),
+        ..*amount
+    })
+}
+
+pub fn temperature_interval(start: &TemperatureValue, end: &TemperatureValue) -> RuleResult<TemperatureValue> {
+    if start.is_interval() || end.is_interval() || start.value >= end.value {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    if start.unit.is_some() && end.unit.is_some() && start.unit != end.unit {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    Ok(TemperatureValue {
+        value: start.value,
+        end: Some(end.value),
+        unit: end.unit.or(start.unit),
+        latent: start.latent && end.latent,
+        direction: None,
+    })
+}
+
+pub fn temperature_bound(temp: &TemperatureValue, direction: Direction) -> RuleResult<TemperatureValue> {
+    if temp.is_interval() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    Ok(TemperatureValue {
+        direction: Some(direction),
+        ..temp.clone()
+    })
+}
+
+pub fn duration_interval(min: &DurationValue, max: &DurationValue) -> RuleResult<DurationIntervalValue> {
+    if min.period.coarse_num_secs() >= max.period.coarse_num_secs() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    Ok(DurationIntervalValue {
+        min: Some(min.period.clone()),
+        max: Some(max.period.clone()),
+        precision: precision_resolution(min.precision, max.precision),
+    })
+}
+
+/// Builds "2 to 3 hours" where the lower end borrows the unit of the upper one
+pub fn duration_number_interval(min: &NumberValue, max: &DurationValue) -> RuleResult<DurationIntervalValue> {
+    let comps = max.period.comps();
+    if comps.len() != 1 {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    let min = DurationValue::new(period_from_fraction(min.value(), comps[0].grain)?);
+    duration_interval(&min, max)
+}
+
+pub fn duration_bound(duration: &DurationValue, direction: Direction) -> RuleResult<DurationIntervalValue> {
+    let (min, max) = match direction {
+        Direction::After => (Some(duration.period.clone()), None),
+        Direction::Before => (None, Some(duration.period.clone())),
+    };
+    Ok(DurationIntervalValue { min, max, precision: duration.precision })
+}
+
+pub fn phone_number(text: &str) -> RuleResult<PhoneNumberValue> {
+    let digits: String = text.chars().filter(|c| c.is_digit(10)).collect();
+    let international = text.trim_left().starts_with('+');
+    if !international && !text.contains('(') {
+        let groups: Vec<&str> = text.split(|c: char| !c.is_digit(10)).filter(|g| !g.is_empty()).collect();
+        // A bare run of digits is only a phone number when dialed with a trunk prefix,
+        // otherwise it is just a big integer
+        if groups.len() < 3 && !digits.starts_with('0') {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        if is_thousands_grouping(&groups) || is_date_grouping(&groups) {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+    }
+    checked_phone_number(digits, international)
+}
+
+/// Groups of three digits after a shorter first group, like "1 200 000" or "1.200.000"
+fn is_thousands_grouping(groups: &[&str]) -> bool {
+    groups.len() > 1
+        && !groups[0].starts_with('0')
+        && groups[0].len() <= 3
+        && groups[1..].iter().all(|g| g.len() == 3)
+}
+
+/// A year with a month and a day, like "2013-02-12" or "12.02.2013"
+fn is_date_grouping(groups: &[&str]) -> bool {
+    groups.len() == 3 && {
+        let lens: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+        (lens[0] == 4 && lens[1] <= 2 && lens[2] <= 2) || (lens[0] <= 2 && lens[1] <= 2 && lens[2] == 4)
+    }
+}
+
+/// Builds a phone number from spoken digits like "oh six double one". `symbol` maps a word to
+/// the digit (or `+`) it stands for, `repeat` maps words like "double" to the number of times
+/// the next digit is repeated.
+pub fn spoken_phone_number<S, R>(text: &str, symbol: S, repeat: R) -> RuleResult<PhoneNumberValue>
+    where S: Fn(&str) -> Option<&'static str>, R: Fn(&str) -> Option<usize>
+{
+    let mut symbols = String::new();
+    let mut times = 1;
+    for word in text.split(|c: char| c.is_whitespace() || c == '-').filter(|w| !w.is_empty()) {
+        if let Some(n) = repeat(word) {
+            times = n;
+            continue;
+        }
+        let s = symbol(word).ok_or_else(|| -> RuleError { RuleErrorKind::Invalid.into() })?;
+        for _ in 0..times {
+            symbols.push_str(s);
+        }
+        times = 1;
+    }
+    let international = symbols.starts_with('+');
+    let digits: String = symbols.chars().filter(|c| c.is_digit(10)).collect();
+    if digits.len() + international as usize != symbols.len() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    checked_phone_number(digits, international)
+}
+
+fn checked_phone_number(digits: String, international: bool) -> RuleResult<PhoneNumberValue> {
+    if digits.len() < 7 || digits.len() > 15 {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    if international {
+        Ok(PhoneNumberValue(format!("+{}", digits)))
+    } else {
+        Ok(PhoneNumberValue(digits))
+    }
+}
+
+pub fn url(text: &str) -> RuleResult<UrlValue> {
+    let without_scheme = text.splitn(2, "://").last().unwrap_or(text);
+    let host = without_scheme
+        .split(|c: char| c == '/' || c == ':' || c == '?' || c == '#')
+        .next()
+        .unwrap_or(without_scheme);
+    let domain = if host.starts_with("www.") { &host[4..] } else { host };
+    if domain.is_empty() {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    Ok(UrlValue {
+        value: text.to_string(),
+        domain: domain.to_string(),
+    })
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -105,6 +348,13 @@ impl Form {
     }
     
 
+    pub fn is_ambiguous_clock(&self) -> bool {
+        match self {
+            &Form::TimeOfDay(ref tod) => tod.is_ambiguous_clock(),
+            _ => false,
+        }
+    }
+
     fn is_time_of_day(&self) -> bool {
          if let &Form::TimeOfDay(_) = self {
             true
@@ -139,6 +389,11 @@ impl TimeOfDayForm {
             &TimeOfDayForm::HourMinuteSecond { full_hour, .. } => full_hour,
         }
     }
+
+    /// Whether the hour could be in the morning or in the afternoon, as in "at 3"
+    pub fn is_ambiguous_clock(&self) -> bool {
+        self.is_12_clock() && 1 <= self.full_hour() && self.full_hour() <= 12
+    }
 }
 
 
@@ -158,7 +413,7 @@ fn from_addition_resolution(lhs: Option<FromAddition>, rhs: Option<FromAddition>
     }
 }
 impl TimeValue {
-    pub fn constraint(constraint: RcConstraint<Local>) -> TimeValue {
+    pub fn constraint(constraint: RcConstraint<Zone>) -> TimeValue {
         TimeValue {
             constraint: constraint,
             form: Form::Empty,
@@ -166,6 +421,8 @@ impl TimeValue {
             precision: Precision::Exact,
             latent: false,
             ambiguity: Ambiguity::No,
+            timezone: None,
+            numeric_date: false,
         }
     }
 
@@ -255,9 +512,24 @@ impl TimeValue {
     }
 
     pub fn intersect(&self, other: &TimeValue) -> RuleResult<TimeValue> {
-        Ok(TimeValue::constraint(self.constraint.intersect(&other.constraint))
+        if self.timezone.is_some() && other.timezone.is_some() {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(TimeValue {
+            timezone: self.timezone.or(other.timezone),
+            numeric_date: self.numeric_date || other.numeric_date,
+            ..TimeValue::constraint(self.constraint.intersect(&other.constraint))
                .direction(self.direction.or(other.direction))
-               .precision(precision_resolution(self.precision, other.precision)))
+               .precision(precision_resolution(self.precision, other.precision))
+        })
+    }
+
+    /// Attaches the zone the time is expressed in, as in "3pm EST"
+    pub fn in_timezone(&self, timezone: &TimezoneValue) -> RuleResult<TimeValue> {
+        if self.timezone.is_some() || self.constraint.grain() < Grain::Hour {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(TimeValue { timezone: Some(*timezone), ..self.clone() })
     }
 
     pub fn last_of(&self, other: &TimeValue) -> RuleResult<TimeValue> {
@@ -364,6 +636,28 @@ pub fn year(y: i32) -> RuleResult<TimeValue> {
     Ok(TimeValue::constraint(Year::new(y)).form(Form::Year(y)))
 }
 
+/// Fiscal year of the resolution context ending in year `y`, e.g. "FY2019"
+pub fn fiscal_year(y: i32) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(FiscalYear::new(Some(y))))
+}
+
+/// The `n`th fiscal year from the current one, e.g. "next fiscal year"
+pub fn fiscal_year_nth(n: i64) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(FiscalYear::new(None).take_the_nth(n)))
+}
+
+/// The `n`th quarter, counted from 1, of the given fiscal year, a calendar year being read as
+/// the fiscal year ending in it
+pub fn fiscal_quarter(n: i64, fiscal_year: &TimeValue) -> RuleResult<TimeValue> {
+    if !(1 <= n && n <= 4) {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    match fiscal_year.form {
+        Form::Year(y) => cycle_nth_after(Grain::Quarter, n - 1, &self::fiscal_year(y)?),
+        _ => cycle_nth_after(Grain::Quarter, n - 1, fiscal_year),
+    }
+}
+
 pub fn month(m: u32) -> RuleResult<TimeValue> {
     if !(1 <= m && m <= 12) {
         return Err(RuleErrorKind::Invalid.into())
@@ -379,7 +673,53 @@ pub fn day_of_month(dom: u32) -> RuleResult<TimeValue> {
 }
 
 pub fn day_of_week(weekday: Weekday) -> RuleResult<TimeValue> {
-    Ok(TimeValue::constraint(DayOfWeek::new(weekday)).form(Form::DayOfWeek { not_immediate: true }))
+    Ok(TimeValue::constraint(DayOfWeek::new(weekday)).form(Form::DayOfWeek { weekday: weekday, not_immediate: true }))
+}
+
+/// Part of the day, spanning the hours set in the resolution context or else `[start_hour, end_hour)`
+pub fn part_of_day(part_of_day: PartOfDayForm, start_hour: u32, end_hour: u32) -> RuleResult<TimeValue> {
+    let period = part_of_day_period(part_of_day)?;
+    Ok(TimeValue::constraint(DayPeriodWindow::new(period, start_hour, end_hour))
+        .form(Form::PartOfDay(part_of_day)))
+}
+
+/// Portion `[start_hour, end_hour)` of a part of the day whose default window is
+/// `[period_start_hour, period_end_hour)`, such as the early evening. It follows the hours set for
+/// the part of the day in the resolution context.
+pub fn part_of_day_portion(part_of_day: PartOfDayForm, period_start_hour: u32, period_end_hour: u32,
+                           start_hour: u32, end_hour: u32) -> RuleResult<TimeValue> {
+    let period = part_of_day_period(part_of_day)?;
+    let window = DayPeriodWindow { period: period, start_hour: period_start_hour, end_hour: period_end_hour };
+    Ok(TimeValue::constraint(DayPeriodPart::new(window, start_hour, end_hour))
+        .form(Form::PartOfDay(part_of_day)))
+}
+
+fn part_of_day_period(part_of_day: PartOfDayForm) -> RuleResult<DayPeriod> {
+    match part_of_day {
+        PartOfDayForm::Morning => Ok(DayPeriod::Morning),
+        PartOfDayForm::Afternoon => Ok(DayPeriod::Afternoon),
+        PartOfDayForm::Evening => Ok(DayPeriod::Evening),
+        PartOfDayForm::Night => Ok(DayPeriod::Night),
+        PartOfDayForm::None => Err(RuleErrorKind::Invalid.into()),
+    }
+}
+
+/// Meal time, spanning the hours set in the resolution context or else `[start_hour, end_hour)`
+pub fn meal(meal: MealForm, start_hour: u32, end_hour: u32) -> RuleResult<TimeValue> {
+    let period = match meal {
+        MealForm::Breakfast => DayPeriod::Breakfast,
+        MealForm::Brunch => DayPeriod::Brunch,
+        MealForm::Lunch => DayPeriod::Lunch,
+        MealForm::Snack => DayPeriod::Snack,
+        MealForm::Dinner => DayPeriod::Dinner,
+    };
+    Ok(TimeValue::constraint(DayPeriodWindow::new(period, start_hour, end_hour))
+        .form(Form::Meal(meal)))
+}
+
+/// Weekend as configured in the resolution context, from 6pm the day before when `from_evening` is set
+pub fn weekend(from_evening: bool) -> RuleResult<TimeValue> {
+    Ok(TimeValue::constraint(Weekend::new(from_evening)))
 }
 
 pub fn month_day(m: u32, d: u32) -> RuleResult<TimeValue> {
@@ -482,10 +822,24 @@ pub fn ymd(y: i32, m: u32, d: u32) -> RuleResult<TimeValue> {
      Ok(TimeValue::constraint(YearMonthDay::new(y, m, d)))
 }
 
+/// Date written with numbers only, such as "3/4/2015", read in the given order unless the
+/// resolver sets another one
+pub fn numeric_date(first: u32, second: u32, year: Option<i32>, order: DateOrder) -> RuleResult<TimeValue> {
+    if first < 1 || second < 1 || (first > 12 && second > 12) {
+        return Err(RuleErrorKind::Invalid.into())
+    }
+    // the month is only known once the resolver picks the order
+    let form = if year.is_some() { Form::Empty } else { Form::MonthDay(None) };
+    Ok(TimeValue {
+        numeric_date: first != second && first <= 12 && second <= 12,
+        ..TimeValue::constraint(NumericDate::new(first, second, year, order)).form(form)
+    })
+}
+
 pub fn easter() -> RuleResult<TimeValue> {
-    fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
+    fn offset(i: &Interval<Zone>, _: &Context<Zone>) -> Option<Interval<Zone>> {
         let (year, month, day) = computer_easter(i.start.year());
-        Some(Interval::ymd(year, month, day))
+        Some(Interval::starting_at(Moment(i.start.timezone().ymd(year, month, day).and_hms(0, 0, 0)), Grain::Day))
     }
     Ok(TimeValue::constraint(Month::new(3).translate_with(offset)))
 }
@@ -547,6 +901,119 @@ impl DurationValue {
         Ok(TimeValue::constraint(time.constraint.shift_by(-self.period.clone())).precision(self.precision))
     }
 }
+
+/// Looks up a zone abbreviation or IANA name in the zones known by the ontology.
+///
+/// Abbreviations shared by several zones resolve to a single one: "cst" is US Central Standard
+/// Time rather than China Standard Time, "bst" is British Summer Time rather than Bangladesh
+/// Standard Time, and "ist" is India Standard Time rather than Irish or Israel Standard Time.
+pub fn timezone(name: &str) -> RuleResult<TimezoneValue> {
+    let (name, utc_offset, daylight_saving) = match name {
+        "utc" | "gmt" | "z" => ("UTC", 0, None),
+        "est" => ("EST", -300, None),
+        "edt" => ("EDT", -240, None),
+        "cst" => ("CST", -360, None),
+        "cdt" => ("CDT", -300, None),
+        "mst" => ("MST", -420, None),
+        "mdt" => ("MDT", -360, None),
+        "pst" => ("PST", -480, None),
+        "pdt" => ("PDT", -420, None),
+        "bst" => ("BST", 60, None),
+        "cet" => ("CET", 60, None),
+        "cest" => ("CEST", 120, None),
+        "jst" => ("JST", 540, None),
+        "kst" => ("KST", 540, None),
+        "ist" => ("IST", 330, None),
+        "america/new_york" => ("America/New_York", -300, Some(DaylightSaving::NorthAmerica)),
+        "america/chicago" => ("America/Chicago", -360, Some(DaylightSaving::NorthAmerica)),
+        "america/denver" => ("America/Denver", -420, Some(DaylightSaving::NorthAmerica)),
+        "america/los_angeles" => ("America/Los_Angeles", -480, Some(DaylightSaving::NorthAmerica)),
+        "america/mexico_city" => ("America/Mexico_City", -360, None),
+        "europe/london" => ("Europe/London", 0, Some(DaylightSaving::Europe)),
+        "europe/paris" => ("Europe/Paris", 60, Some(DaylightSaving::Europe)),
+        "europe/berlin" => ("Europe/Berlin", 60, Some(DaylightSaving::Europe)),
+        "europe/madrid" => ("Europe/Madrid", 60, Some(DaylightSaving::Europe)),
+        "asia/tokyo" => ("Asia/Tokyo", 540, None),
+        "asia/seoul" => ("Asia/Seoul", 540, None),
+        "asia/shanghai" => ("Asia/Shanghai", 480, None),
+        _ => return Err(RuleErrorKind::Invalid.into()),
+    };
+    Ok(TimezoneValue { name, utc_offset, daylight_saving })
+}
+
+fn nth_sunday(year: i32, month: u32, n: u32) -> u32 {
+    let first = Local.ymd(year, month, 1).weekday().num_days_from_sunday();
+    1 + (7 - first) % 7 + 7 * (n - 1)
+}
+
+// Only used for March and October
+fn last_sunday(year: i32, month: u32) -> u32 {
+    31 - Local.ymd(year, month, 31).weekday().num_days_from_sunday()
+}
+
+impl TimezoneValue {
+    /// Offset from UTC in minutes in effect on the day of the given wall-clock time.
+    /// Transition days are considered entirely on the new offset.
+    pub fn utc_offset_at<T: TimeZone>(&self, moment: &Moment<T>) -> i32 {
+        let (year, day) = (moment.0.year(), (moment.0.month(), moment.0.day()));
+        let in_daylight_saving = match self.daylight_saving {
+            Some(DaylightSaving::NorthAmerica) => (3, nth_sunday(year, 3, 2)) <= day && day < (11, nth_sunday(year, 11, 1)),
+            Some(DaylightSaving::Europe) => (3, last_sunday(year, 3)) <= day && day < (10, last_sunday(year, 10)),
+            None => false,
+        };
+        if in_daylight_saving { self.utc_offset + 60 } else { self.utc_offset }
+    }
+}
+
+impl RecurrenceValue {
+    pub fn every(self, interval: i64) -> RuleResult<RecurrenceValue> {
+        if interval <= 0 {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(RecurrenceValue { interval: interval, ..self })
+    }
+
+    /// Narrows the recurrence with a day of week or a time of day, as in "every monday at 8am"
+    pub fn at(&self, time: &TimeValue) -> RuleResult<RecurrenceValue> {
+        match time.form {
+            Form::DayOfWeek { weekday, .. } => {
+                if self.frequency > Grain::Day || self.by_day.contains(&weekday) {
+                    return Err(RuleErrorKind::Invalid.into())
+                }
+                let mut by_day = self.by_day.clone();
+                by_day.push(weekday);
+                Ok(RecurrenceValue { by_day: by_day, ..self.clone() })
+            }
+            Form::TimeOfDay(ref tod) => {
+                if self.frequency > Grain::Day || self.by_hour.is_some() {
+                    return Err(RuleErrorKind::Invalid.into())
+                }
+                let (hour, minute) = match tod {
+                    &TimeOfDayForm::Hour { full_hour, .. } => (full_hour, 0),
+                    &TimeOfDayForm::HourMinute { full_hour, minute, .. } => (full_hour, minute),
+                    &TimeOfDayForm::HourMinuteSecond { full_hour, minute, .. } => (full_hour, minute),
+                };
+                Ok(RecurrenceValue { by_hour: Some(hour), by_minute: Some(minute), ..self.clone() })
+            }
+            _ => Err(RuleErrorKind::Invalid.into()),
+        }
+    }
+
+    pub fn starting(&self, time: &TimeValue) -> RuleResult<RecurrenceValue> {
+        if self.start.is_some() {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(RecurrenceValue { start: Some(time.clone()), ..self.clone() })
+    }
+
+    pub fn until(&self, time: &TimeValue) -> RuleResult<RecurrenceValue> {
+        if self.end.is_some() {
+            return Err(RuleErrorKind::Invalid.into())
+        }
+        Ok(RecurrenceValue { end: Some(time.clone()), ..self.clone() })
+    }
+}
+
 impl ops::Add<DurationValue> for DurationValue {
     type Output = DurationValue;
     fn add(self, duration: DurationValue) -> DurationValue {
@@ -609,6 +1076,152 @@ mod tests {
         assert_eq!(93, decimal_hour_in_minute("1", "55").unwrap());
     }
 
+    #[test]
+    fn test_phone_number() {
+        assert_eq!(PhoneNumberValue("+33612345678".to_string()), phone_number("+33 6 12 34 56 78").unwrap());
+        assert_eq!(PhoneNumberValue("0612345678".to_string()), phone_number("06.12.34.56.78").unwrap());
+        assert_eq!(PhoneNumberValue("0612345678".to_string()), phone_number("0612345678").unwrap());
+        assert_eq!(PhoneNumberValue("4155550123".to_string()), phone_number("(415) 555-0123").unwrap());
+        assert!(phone_number("1200000").is_err());
+        assert!(phone_number("12 34").is_err());
+        assert_eq!(PhoneNumberValue("5551234567".to_string()), phone_number("555-123-4567").unwrap());
+        assert!(phone_number("2013-02-12").is_err());
+        assert!(phone_number("12.02.2013").is_err());
+        assert!(phone_number("1.200.000").is_err());
+        assert!(phone_number("1 200 000").is_err());
+        assert!(phone_number("12 200 000").is_err());
+    }
+
+    #[test]
+    fn test_money_unit() {
+        let names: &[(&'static str, &'static str)] = &[("$", "$"), ("dollars", "$"), ("dollar", "$"), ("eur", "EUR"), ("euro", "EUR")];
+        assert_eq!(r#"dollars|dollar|euro|eur|\$"#, money_unit_regex(names));
+        assert_eq!(Some("EUR"), money_unit(names, "Euro").unwrap().unit);
+        assert!(money_unit(names, "pound").is_err());
+        assert!(money_unit(&[("bucks", "XXX")], "bucks").is_err());
+    }
+
+    #[test]
+    fn test_money_interval() {
+        let ten = AmountOfMoneyValue { value: 10.0, unit: Some("EUR"), ..AmountOfMoneyValue::default() };
+        let twenty = AmountOfMoneyValue { value: 20.0, unit: Some("EUR"), ..AmountOfMoneyValue::default() };
+        let twenty_dollars = AmountOfMoneyValue { value: 20.0, unit: Some("$"), ..AmountOfMoneyValue::default() };
+        let range = money_interval(&ten, &twenty).unwrap();
+        assert_eq!((10.0, Some(20.0), Some("EUR")), (range.value, range.end, range.unit));
+        let range = money_number_interval(&NumberValue::Integer(IntegerValue::new(10).unwrap()), &twenty).unwrap();
+        assert_eq!((10.0, Some(20.0), Some("EUR")), (range.value, range.end, range.unit));
+        assert!(money_interval(&twenty, &ten).is_err());
+        assert!(money_interval(&ten, &twenty_dollars).is_err());
+        assert!(money_interval(&range, &twenty).is_err());
+        assert_eq!(Some(Direction::Before), money_bound(&ten, Direction::Before).unwrap().direction);
+    }
+
+    #[test]
+    fn test_temperature_interval() {
+        let eighteen = TemperatureValue { value: 18.0, latent: true, ..TemperatureValue::default() };
+        let twenty_two = TemperatureValue { value: 22.0, unit: Some(TemperatureUnit::Degree), ..TemperatureValue::default() };
+        let twenty_two_f = TemperatureValue { value: 22.0, unit: Some(TemperatureUnit::Fahrenheit), ..TemperatureValue::default() };
+        let range = temperature_interval(&eighteen, &twenty_two).unwrap();
+        assert_eq!((18.0, Some(22.0), Some(TemperatureUnit::Degree), false), (range.value, range.end, range.unit, range.latent));
+        assert!(temperature_interval(&twenty_two, &eighteen).is_err());
+        assert!(temperature_interval(&TemperatureValue { unit: Some(TemperatureUnit::Celsius), ..eighteen.clone() }, &twenty_two_f).is_err());
+        assert!(temperature_bound(&range, Direction::After).is_err());
+        assert_eq!(Some(Direction::After), temperature_bound(&eighteen, Direction::After).unwrap().direction);
+    }
+
+    #[test]
+    fn test_duration_interval() {
+        let two_hours = DurationValue::new(PeriodComp::hours(2).into());
+        let three_hours = DurationValue::new(PeriodComp::hours(3).into());
+        let range = duration_interval(&two_hours, &three_hours).unwrap();
+        assert_eq!((Some(two_hours.period.clone()), Some(three_hours.period.clone())), (range.min, range.max));
+        assert!(duration_interval(&three_hours, &two_hours).is_err());
+        let range = duration_number_interval(&NumberValue::Integer(IntegerValue::new(2).unwrap()), &three_hours).unwrap();
+        assert_eq!(Some(two_hours.period.clone()), range.min);
+        let mixed = DurationValue::new(Period::default() + PeriodComp::hours(3) + PeriodComp::minutes(30));
+        assert!(duration_number_interval(&NumberValue::Integer(IntegerValue::new(2).unwrap()), &mixed).is_err());
+        let bound = duration_bound(&two_hours, Direction::Before).unwrap();
+        assert_eq!((None, Some(two_hours.period.clone())), (bound.min, bound.max));
+    }
+
+    #[test]
+    fn test_recurrence() {
+        let monday = day_of_week(Weekday::Mon).unwrap();
+        let eight = hour(8, false).unwrap();
+        let weekly = RecurrenceValue::new(Grain::Week).every(2).unwrap().at(&monday).unwrap().at(&eight).unwrap();
+        assert_eq!((2, vec![Weekday::Mon], Some(8), Some(0)), (weekly.interval, weekly.by_day.clone(), weekly.by_hour, weekly.by_minute));
+        assert!(weekly.at(&monday).is_err());
+        assert!(weekly.at(&eight).is_err());
+        assert!(RecurrenceValue::new(Grain::Hour).at(&monday).is_err());
+        assert!(RecurrenceValue::new(Grain::Day).every(0).is_err());
+        assert!(RecurrenceValue::new(Grain::Day).at(&month(3).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_timezone() {
+        let eastern = timezone("america/new_york").unwrap();
+        assert_eq!(-300, eastern.utc_offset_at(&Moment::ymd(2013, 2, 12)));
+        assert_eq!(-240, eastern.utc_offset_at(&Moment::ymd(2013, 3, 10)));
+        assert_eq!(-300, eastern.utc_offset_at(&Moment::ymd(2013, 11, 3)));
+        let paris = timezone("europe/paris").unwrap();
+        assert_eq!(60, paris.utc_offset_at(&Moment::ymd(2013, 3, 30)));
+        assert_eq!(120, paris.utc_offset_at(&Moment::ymd(2013, 3, 31)));
+        assert_eq!(60, paris.utc_offset_at(&Moment::ymd(2013, 10, 27)));
+        assert_eq!(-300, timezone("est").unwrap().utc_offset_at(&Moment::ymd(2013, 7, 1)));
+        assert_eq!(330, timezone("ist").unwrap().utc_offset_at(&Moment::ymd(2013, 7, 1)));
+        assert!(timezone("mars/olympus_mons").is_err());
+        let three_pm = hour(15, false).unwrap();
+        assert!(three_pm.in_timezone(&eastern).unwrap().in_timezone(&paris).is_err());
+        assert!(day_of_month(12).unwrap().in_timezone(&eastern).is_err());
+    }
+
+    #[test]
+    fn test_compose_mixed_fraction() {
+        let half = FloatValue::new_fraction(1, 2).unwrap();
+        let mixed = compose_mixed_fraction(2, &half).unwrap();
+        assert_eq!(2.5, mixed.value);
+        assert_eq!(Some(Fraction { numerator: 5, denominator: 2 }), mixed.fraction);
+        assert!(compose_mixed_fraction(2, &FloatValue::new_fraction(3, 2).unwrap()).is_err());
+        assert!(compose_mixed_fraction(2, &FloatValue::new(0.5).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_period_from_fraction() {
+        assert_eq!(Period::default() + PeriodComp::hours(1) + PeriodComp::minutes(30),
+                   period_from_fraction(1.5, Grain::Hour).unwrap());
+        assert_eq!(Period::default() + PeriodComp::days(2) + PeriodComp::hours(16),
+                   period_from_fraction(8.0 / 3.0, Grain::Day).unwrap());
+        assert_eq!(Period::from(PeriodComp::minutes(40)),
+                   period_from_fraction(2.0 / 3.0, Grain::Hour).unwrap());
+        assert!(period_from_fraction(-0.5, Grain::Hour).is_err());
+    }
+
+    #[test]
+    fn test_spoken_phone_number() {
+        let symbol = |w: &str| match w {
+            "plus" => Some("+"),
+            "oh" => Some("0"),
+            "one" => Some("1"),
+            "two" => Some("2"),
+            "six" => Some("6"),
+            _ => None,
+        };
+        let repeat = |w: &str| if w == "double" { Some(2) } else { None };
+        assert_eq!(PhoneNumberValue("06122611".to_string()),
+                   spoken_phone_number("oh six one two two six double one", &symbol, &repeat).unwrap());
+        assert_eq!(PhoneNumberValue("+16122611".to_string()),
+                   spoken_phone_number("plus one six one two two six one one", &symbol, &repeat).unwrap());
+        assert!(spoken_phone_number("oh six plus one two two six one", &symbol, &repeat).is_err());
+        assert!(spoken_phone_number("oh six one", &symbol, &repeat).is_err());
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!("example.com", url("https://www.example.com:8080/path?q=1").unwrap().domain);
+        assert_eq!("snips.ai", url("snips.ai").unwrap().domain);
+        assert_eq!("docs.rs", url("docs.rs/regex").unwrap().domain);
+    }
+
     #[test]
     fn test_computer_easter() {
         assert_eq!((2017, 4, 16), computer_easter(2017));
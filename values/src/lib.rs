@@ -7,14 +7,17 @@ extern crate regex;
 pub mod check;
 pub mod dimension;
 pub mod helpers;
+pub mod common_rules;
 #[macro_use]
 pub mod macros_training;
 #[macro_use]
 pub mod macros_rules;
 pub mod output;
 pub mod context;
+pub mod currency;
+pub mod locale;
 
 pub use dimension::Dimension;
 pub use dimension::DimensionKind;
 pub use output::Output;
-pub use context::{ResolverContext, ParsingContext, IdentityContext};
+pub use context::{ResolverContext, ResolutionPolicy, ClockPolicy, ParsingContext, IdentityContext};
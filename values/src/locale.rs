@@ -0,0 +1,75 @@
+use std::fmt;
+use std::str;
+
+use moment::{DateOrder, Week, Weekday};
+
+/// Regions whose week does not start on monday, or whose weekend is not saturday and sunday:
+/// region, first day of the week, first day of the weekend and number of days of the weekend
+static REGIONAL_WEEKS: &'static [(&'static str, Weekday, Weekday, u32)] = &[
+    ("US", Weekday::Sun, Weekday::Sat, 2),
+    ("CA", Weekday::Sun, Weekday::Sat, 2),
+    ("MX", Weekday::Sun, Weekday::Sat, 2),
+    ("BR", Weekday::Sun, Weekday::Sat, 2),
+    ("JP", Weekday::Sun, Weekday::Sat, 2),
+    ("PH", Weekday::Sun, Weekday::Sat, 2),
+    ("IL", Weekday::Sun, Weekday::Fri, 2),
+    ("SA", Weekday::Sun, Weekday::Fri, 2),
+    ("EG", Weekday::Sat, Weekday::Fri, 2),
+    ("DZ", Weekday::Sat, Weekday::Fri, 2),
+    ("IR", Weekday::Sat, Weekday::Fri, 1),
+];
+
+/// Regions writing the month before the day in dates such as "3/4/2015"
+static MONTH_FIRST_REGIONS: &'static [&'static str] = &[
+    "US", "PR", "PH", "FM", "MH", "PW", "CA", "CN", "JP", "KR", "TW",
+];
+
+/// ISO 3166-1 alpha-2 region code, such as "US" or "CA"
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Region([u8; 2]);
+
+impl Region {
+    /// Reads a two-letter region code, whatever its case
+    pub fn from_code(code: &str) -> Option<Region> {
+        let bytes = code.as_bytes();
+        if bytes.len() == 2 && bytes.iter().all(|b| b.is_ascii_alphabetic()) {
+            Some(Region([bytes[0].to_ascii_uppercase(), bytes[1].to_ascii_uppercase()]))
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // both bytes are ascii letters
+        str::from_utf8(&self.0).unwrap()
+    }
+
+    /// Days starting the week and making up the weekend in this region
+    pub fn week(&self) -> Week {
+        REGIONAL_WEEKS.iter()
+            .find(|&&(region, _, _, _)| region == self.as_str())
+            .map(|&(_, start, weekend_start, weekend_days)| Week { start, weekend_start, weekend_days })
+            .unwrap_or_default()
+    }
+
+    /// Order of the day and the month in dates written with numbers only in this region
+    pub fn date_order(&self) -> DateOrder {
+        if MONTH_FIRST_REGIONS.iter().any(|&region| region == self.as_str()) {
+            DateOrder::MonthDay
+        } else {
+            DateOrder::DayMonth
+        }
+    }
+}
+
+impl fmt::Debug for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Region({})", self.as_str())
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -83,6 +83,13 @@ macro_rules! temperature_check {
 }
 
 
+#[macro_export]
+macro_rules! quantity_check {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<QuantityValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! time_check {
     () => ( ::rustling::core::AnyNodePattern::<TimeValue>::new() );
@@ -115,6 +122,19 @@ macro_rules! duration_check {
 }
 
 
+#[macro_export]
+macro_rules! recurrence_check {
+    () => ( ::rustling::core::AnyNodePattern::<RecurrenceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<RecurrenceValue>::filter(vec![b!($predicate)]) );
+}
+
+
+#[macro_export]
+macro_rules! timezone_check {
+    () => ( ::rustling::core::AnyNodePattern::<TimezoneValue>::new() );
+}
+
+
 #[macro_export]
 macro_rules! relative_minute_check {
     () => ( ::rustling::core::AnyNodePattern::<RelativeMinuteValue>::new() );
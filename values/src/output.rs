@@ -1,5 +1,6 @@
 use moment::*;
 use dimension::*;
+use currency::Currency;
 
 #[derive(Clone,PartialEq,Debug)]
 pub enum Output {
@@ -10,8 +11,17 @@ pub enum Output {
     Time(TimeOutput),
     TimeInterval(TimeIntervalOutput),
     AmountOfMoney(AmountOfMoneyOutput),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalOutput),
     Temperature(TemperatureOutput),
+    TemperatureInterval(TemperatureIntervalOutput),
     Duration(DurationOutput),
+    DurationInterval(DurationIntervalOutput),
+    Recurrence(RecurrenceOutput),
+    Quantity(QuantityOutput),
+    Email(EmailOutput),
+    Url(UrlOutput),
+    PhoneNumber(PhoneNumberOutput),
+    NumberInterval(NumberIntervalOutput),
 }
 
 impl Output {
@@ -23,9 +33,18 @@ impl Output {
             &Output::Time(_) => OutputKind::Time,
             &Output::TimeInterval(_) => OutputKind::Time,
             &Output::AmountOfMoney(_) => OutputKind::AmountOfMoney,
+            &Output::AmountOfMoneyInterval(_) => OutputKind::AmountOfMoney,
             &Output::Temperature(_) => OutputKind::Temperature,
+            &Output::TemperatureInterval(_) => OutputKind::Temperature,
             &Output::Duration(_) => OutputKind::Duration,
+            &Output::DurationInterval(_) => OutputKind::DurationInterval,
+            &Output::Recurrence(_) => OutputKind::Recurrence,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::Quantity(_) => OutputKind::Quantity,
+            &Output::Email(_) => OutputKind::Email,
+            &Output::Url(_) => OutputKind::Url,
+            &Output::PhoneNumber(_) => OutputKind::PhoneNumber,
+            &Output::NumberInterval(_) => OutputKind::NumberInterval,
         }
     }
 }
@@ -35,10 +54,17 @@ enum_kind!(OutputKind,
         Number,
         Ordinal,
         Duration,
+        DurationInterval,
         Time,
+        Recurrence,
         AmountOfMoney,
+        NumberInterval,
         Temperature,
-        Percentage
+        Percentage,
+        Quantity,
+        Email,
+        Url,
+        PhoneNumber
     ]
 );
 
@@ -48,10 +74,17 @@ impl OutputKind {
             &OutputKind::Number => DimensionKind::Number,
             &OutputKind::Ordinal => DimensionKind::Ordinal,
             &OutputKind::Time => DimensionKind::Time,
+            &OutputKind::Recurrence => DimensionKind::Recurrence,
             &OutputKind::AmountOfMoney => DimensionKind::AmountOfMoney,
             &OutputKind::Temperature => DimensionKind::Temperature,
             &OutputKind::Duration => DimensionKind::Duration,
+            &OutputKind::DurationInterval => DimensionKind::DurationInterval,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::Quantity => DimensionKind::Quantity,
+            &OutputKind::Email => DimensionKind::Email,
+            &OutputKind::Url => DimensionKind::Url,
+            &OutputKind::PhoneNumber => DimensionKind::PhoneNumber,
+            &OutputKind::NumberInterval => DimensionKind::NumberInterval,
         }
     }
 }
@@ -70,39 +103,326 @@ pub struct OrdinalOutput(pub i64);
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct TimeOutput {
-    pub moment: Moment<Local>, 
+    pub moment: Moment<Zone>, 
     pub grain: Grain, 
     pub precision: Precision,
     pub latent: bool,
+    /// Zone stated in the expression, the moment itself is converted to the local zone
+    pub timezone: Option<TimezoneValue>,
+    pub form: Option<TimeForm>,
+    /// The hour was given on the 12-hour clock without saying whether it is am or pm
+    pub ambiguous_clock: bool,
+    /// Other reading of a date written with numbers only, with its day and month swapped, e.g.
+    /// april 3 for "3/4" read as march 4
+    pub alternative: Option<Moment<Zone>>,
+    /// First month of the fiscal year of the context, which the quarters follow
+    pub fiscal_year_start: u32,
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub enum TimeIntervalOutput {
     After(TimeOutput),
     Before(TimeOutput),
-    Between { start: Moment<Local>, end: Moment<Local>, precision: Precision, latent: bool }
+    Between { start: Moment<Zone>, end: Moment<Zone>, precision: Precision, latent: bool, timezone: Option<TimezoneValue>, form: Option<TimeForm>, ambiguous_clock: bool }
+}
+
+/// What a time expression named, beyond the moment it resolves to
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum TimeForm {
+    /// Holiday, with a stable identifier such as "easter_monday"
+    Celebration(&'static str),
+    PartOfDay(PartOfDayForm),
+    Meal(MealForm),
+    DayOfWeek(Weekday),
+}
+
+impl TimeForm {
+    pub fn from_form(form: &Form) -> Option<TimeForm> {
+        match form {
+            &Form::Celebration(id) => Some(TimeForm::Celebration(id)),
+            &Form::PartOfDay(part_of_day) => Some(TimeForm::PartOfDay(part_of_day)),
+            &Form::Meal(meal) => Some(TimeForm::Meal(meal)),
+            &Form::DayOfWeek { weekday, .. } => Some(TimeForm::DayOfWeek(weekday)),
+            _ => None,
+        }
+    }
+}
+
+/// Textual representation of time outputs
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum TimeFormat {
+    /// RFC 3339 timestamp with the UTC offset, e.g. "2013-02-12T04:30:00+01:00"
+    Rfc3339,
+    /// ISO 8601 representation truncated to the grain, e.g. "2013-02" for a month or
+    /// "2013-02-12T04+01:00" for an hour. Quarters are written "2013-Q1", or "FY2014-Q1" with the
+    /// fiscal year they belong to when it does not start in january, and weeks as the date of
+    /// their first day since they may not start on monday like ISO weeks.
+    Grain,
+}
+
+fn format_moment(moment: &Moment<Zone>, grain: Grain, format: TimeFormat) -> String {
+    format_moment_in_fiscal_year(moment, grain, format, 1)
+}
+
+/// Fiscal years are labelled by the year they end in, like `FiscalYear`
+fn format_moment_in_fiscal_year(moment: &Moment<Zone>, grain: Grain, format: TimeFormat, fiscal_year_start: u32) -> String {
+    match (format, grain) {
+        (TimeFormat::Rfc3339, _) | (TimeFormat::Grain, Grain::Second) => moment.to_rfc3339(),
+        (TimeFormat::Grain, Grain::Year) => moment.format("%Y").to_string(),
+        (TimeFormat::Grain, Grain::Quarter) if fiscal_year_start == 1 => format!("{}-Q{}", moment.year(), moment.month0() / 3 + 1),
+        (TimeFormat::Grain, Grain::Quarter) => {
+            let start0 = fiscal_year_start - 1;
+            let fiscal_year = if moment.month0() >= start0 { moment.year() + 1 } else { moment.year() };
+            format!("FY{}-Q{}", fiscal_year, (moment.month0() + 12 - start0) % 12 / 3 + 1)
+        }
+        (TimeFormat::Grain, Grain::Month) => moment.format("%Y-%m").to_string(),
+        (TimeFormat::Grain, Grain::Week) | (TimeFormat::Grain, Grain::Day) => moment.format("%Y-%m-%d").to_string(),
+        (TimeFormat::Grain, Grain::Hour) => moment.format("%Y-%m-%dT%H%:z").to_string(),
+        (TimeFormat::Grain, Grain::Minute) => moment.format("%Y-%m-%dT%H:%M%:z").to_string(),
+    }
+}
+
+/// Coarsest grain the moment is aligned on, used for intervals which do not carry their grain
+fn aligned_grain(moment: &Moment<Zone>) -> Grain {
+    if moment.second() != 0 {
+        Grain::Second
+    } else if moment.minute() != 0 {
+        Grain::Minute
+    } else if moment.hour() != 0 {
+        Grain::Hour
+    } else if moment.day() != 1 {
+        Grain::Day
+    } else if moment.month() != 1 {
+        Grain::Month
+    } else {
+        Grain::Year
+    }
+}
+
+impl TimeOutput {
+    pub fn format(&self, format: TimeFormat) -> String {
+        format_moment_in_fiscal_year(&self.moment, self.grain, format, self.fiscal_year_start)
+    }
+}
+
+impl TimeIntervalOutput {
+    /// Formats the interval as an ISO 8601 interval "<start>/<end>", the end being excluded.
+    /// Open bounds are written "..", e.g. "2013-02-12/.." for "after today".
+    pub fn format(&self, format: TimeFormat) -> String {
+        match self {
+            &TimeIntervalOutput::After(ref time) => format!("{}/..", time.format(format)),
+            &TimeIntervalOutput::Before(ref time) => format!("../{}", time.format(format)),
+            &TimeIntervalOutput::Between { ref start, ref end, .. } => {
+                let grain = ::std::cmp::max(aligned_grain(start), aligned_grain(end));
+                format!("{}/{}", format_moment(start, grain, format), format_moment(end, grain, format))
+            }
+        }
+    }
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct AmountOfMoneyOutput {
+    /// Amount in the main unit of the currency when it is known, rounded to its minor unit
     pub value: f32, 
     pub precision: Precision, 
     pub unit: Option<&'static str>,
+    /// Currency of the unit, resolved with the region of the context
+    pub currency: Option<&'static Currency>,
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum AmountOfMoneyIntervalOutput {
+    After(AmountOfMoneyOutput),
+    Before(AmountOfMoneyOutput),
+    Between { start: f32, end: f32, precision: Precision, unit: Option<&'static str>, currency: Option<&'static Currency> }
 }
 
 #[derive(Clone,Copy,PartialEq,Debug)]
 pub struct TemperatureOutput {
     pub value: f32, 
-    pub unit: Option<&'static str>,
+    /// Never `Degree`, degrees without a scale being resolved with the region of the context
+    pub unit: Option<TemperatureUnit>,
     pub latent: bool,
 }
 
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub enum TemperatureIntervalOutput {
+    After(TemperatureOutput),
+    Before(TemperatureOutput),
+    Between { start: f32, end: f32, unit: Option<TemperatureUnit>, latent: bool }
+}
+
 #[derive(Clone,PartialEq,Debug)]
 pub struct DurationOutput {
     pub period: Period, 
     pub precision: Precision,
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub struct DurationIntervalOutput {
+    pub min: Option<Period>,
+    pub max: Option<Period>,
+    pub precision: Precision,
+}
+
+/// Maximum number of periods walked when expanding a recurrence
+const MAX_RECURRENCE_PERIODS: i64 = 1000;
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct RecurrenceOutput {
+    pub frequency: Grain,
+    pub interval: i64,
+    pub by_day: Vec<Weekday>,
+    pub by_hour: Option<u32>,
+    pub by_minute: Option<u32>,
+    pub start: Option<Moment<Zone>>,
+    /// Exclusive upper bound of the occurrences
+    pub end: Option<Moment<Zone>>,
+    /// Reference time of the resolution, which the periods are counted from when there is no start
+    pub reference: Moment<Zone>,
+}
+
+impl RecurrenceOutput {
+    /// Formats the recurrence as an iCalendar (RFC 5545) RRULE value, e.g. "FREQ=WEEKLY;BYDAY=MO"
+    pub fn to_rrule(&self) -> String {
+        let (frequency, interval) = match self.frequency {
+            Grain::Year => ("YEARLY", self.interval),
+            Grain::Quarter => ("MONTHLY", 3 * self.interval),
+            Grain::Month => ("MONTHLY", self.interval),
+            Grain::Week => ("WEEKLY", self.interval),
+            Grain::Day => ("DAILY", self.interval),
+            Grain::Hour => ("HOURLY", self.interval),
+            Grain::Minute => ("MINUTELY", self.interval),
+            Grain::Second => ("SECONDLY", self.interval),
+        };
+        let mut parts = vec![format!("FREQ={}", frequency)];
+        if interval != 1 {
+            parts.push(format!("INTERVAL={}", interval));
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<&str> = self.by_day.iter().map(|d| rrule_weekday(*d)).collect();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if let Some(hour) = self.by_hour {
+            parts.push(format!("BYHOUR={}", hour));
+        }
+        if let Some(minute) = self.by_minute {
+            parts.push(format!("BYMINUTE={}", minute));
+        }
+        if let Some(end) = self.end {
+            // UNTIL is inclusive while end is not, and must be in UTC as the start has a zone
+            let until = (end - PeriodComp::seconds(1)).with_timezone(&FixedOffset::east(0));
+            parts.push(format!("UNTIL={}", until.format("%Y%m%dT%H%M%SZ")));
+        }
+        parts.join(";")
+    }
+
+    /// Expands the first `n` occurrences happening at or after `from`, or at or after the start
+    /// of the recurrence when it is later. The periods are counted from the start, or else from
+    /// the reference time, so that "every other week" gives the same weeks whenever it is expanded.
+    pub fn occurrences_after(&self, from: Moment<Zone>, n: usize) -> Vec<Moment<Zone>> {
+        let grain = self.occurrence_grain();
+        let from = from.round_to(grain);
+        let anchor = self.start.unwrap_or(self.reference).round_to(grain);
+        let mut result = vec![];
+        let mut index = 0;
+        while result.len() < n && index < MAX_RECURRENCE_PERIODS {
+            let shift = PeriodComp::new(self.frequency, index * self.interval);
+            for candidate in self.candidates(anchor, shift) {
+                if candidate < from || candidate < anchor {
+                    continue;
+                }
+                if self.end.map(|end| candidate >= end).unwrap_or(false) {
+                    return result;
+                }
+                result.push(candidate);
+                if result.len() == n {
+                    return result;
+                }
+            }
+            index += 1;
+        }
+        result
+    }
+
+    fn occurrence_grain(&self) -> Grain {
+        if self.by_hour.is_some() {
+            Grain::Minute
+        } else if self.frequency > Grain::Day {
+            self.frequency
+        } else {
+            Grain::Day
+        }
+    }
+
+    fn candidates(&self, anchor: Moment<Zone>, shift: PeriodComp) -> Vec<Moment<Zone>> {
+        let days = if self.by_day.is_empty() {
+            vec![anchor + shift]
+        } else {
+            let period_start = anchor.round_to(self.frequency) + shift;
+            let period_end = period_start + PeriodComp::new(self.frequency, 1);
+            let mut days = vec![];
+            let mut day = period_start.round_to(Grain::Day);
+            while day < period_end {
+                if self.by_day.contains(&day.weekday()) {
+                    days.push(day);
+                }
+                day = day + PeriodComp::days(1);
+            }
+            days
+        };
+        match self.by_hour {
+            Some(hour) => days.into_iter()
+                .map(|day| day.round_to(Grain::Day)
+                    + PeriodComp::hours(hour as i64)
+                    + PeriodComp::minutes(self.by_minute.unwrap_or(0) as i64))
+                .collect(),
+            None => days,
+        }
+    }
+}
+
+fn rrule_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct QuantityOutput {
+    pub value: f32,
+    pub unit: QuantityUnit,
+    /// Value expressed in the reference unit of the quantity kind (metre, litre or kilogram)
+    pub normalized_value: f32,
+    pub precision: Precision,
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct EmailOutput(pub String);
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct UrlOutput {
+    pub value: String,
+    pub domain: String,
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub struct PhoneNumberOutput(pub String);
+
+#[derive(Clone,Copy,PartialEq,Debug)]
+pub struct NumberIntervalOutput {
+    pub from: Option<f32>,
+    pub to: Option<f32>,
+    pub from_inclusive: bool,
+    pub to_inclusive: bool,
+}
+
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
@@ -110,5 +430,102 @@ variant_converters!(Output, Ordinal, OrdinalOutput);
 variant_converters!(Output, Time, TimeOutput);
 variant_converters!(Output, TimeInterval, TimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
+variant_converters!(Output, AmountOfMoneyInterval, AmountOfMoneyIntervalOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
-variant_converters!(Output, Duration, DurationOutput);
\ No newline at end of file
+variant_converters!(Output, TemperatureInterval, TemperatureIntervalOutput);
+variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, DurationInterval, DurationIntervalOutput);
+variant_converters!(Output, Recurrence, RecurrenceOutput);
+variant_converters!(Output, Quantity, QuantityOutput);
+variant_converters!(Output, Email, EmailOutput);
+variant_converters!(Output, Url, UrlOutput);
+variant_converters!(Output, PhoneNumber, PhoneNumberOutput);
+variant_converters!(Output, NumberInterval, NumberIntervalOutput);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn every_other_monday_at_eight() -> RecurrenceOutput {
+        RecurrenceOutput {
+            frequency: Grain::Week,
+            interval: 2,
+            by_day: vec![Weekday::Mon],
+            by_hour: Some(8),
+            by_minute: Some(0),
+            start: None,
+            end: Some(Moment(Zone::Local.ymd(2013, 3, 12).and_hms(0, 0, 0))),
+            reference: Moment(Zone::Local.ymd(2013, 2, 12).and_hms(4, 30, 0)),
+        }
+    }
+
+    #[test]
+    fn test_recurrence_rrule() {
+        let paris = Zone::Fixed(FixedOffset::east(3600));
+        let until_paris = RecurrenceOutput {
+            end: Some(Moment(paris.ymd(2013, 3, 12).and_hms(0, 0, 0))),
+            ..every_other_monday_at_eight()
+        };
+        assert_eq!("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;BYHOUR=8;BYMINUTE=0;UNTIL=20130311T225959Z",
+                   until_paris.to_rrule());
+        let now = Moment(Zone::Local.ymd(2013, 2, 12).and_hms(4, 30, 0));
+        let quarterly = RecurrenceOutput { frequency: Grain::Quarter, interval: 1, by_day: vec![], by_hour: None, by_minute: None, start: None, end: None, reference: now };
+        assert_eq!("FREQ=MONTHLY;INTERVAL=3", quarterly.to_rrule());
+    }
+
+    #[test]
+    fn test_recurrence_occurrences() {
+        let now = Moment(Zone::Local.ymd(2013, 2, 12).and_hms(4, 30, 0));
+        assert_eq!(vec![Moment(Zone::Local.ymd(2013, 2, 25).and_hms(8, 0, 0)), Moment(Zone::Local.ymd(2013, 3, 11).and_hms(8, 0, 0))],
+                   every_other_monday_at_eight().occurrences_after(now, 5));
+        // a week later, the weeks are still counted from the reference time
+        let next_week = Moment(Zone::Local.ymd(2013, 2, 19).and_hms(4, 30, 0));
+        assert_eq!(vec![Moment(Zone::Local.ymd(2013, 2, 25).and_hms(8, 0, 0)), Moment(Zone::Local.ymd(2013, 3, 11).and_hms(8, 0, 0))],
+                   every_other_monday_at_eight().occurrences_after(next_week, 5));
+        let daily = RecurrenceOutput { frequency: Grain::Day, interval: 1, by_day: vec![], by_hour: None, by_minute: None, start: None, end: None, reference: now };
+        assert_eq!(vec![Moment(Zone::Local.ymd(2013, 2, 12).and_hms(0, 0, 0)), Moment(Zone::Local.ymd(2013, 2, 13).and_hms(0, 0, 0))],
+                   daily.occurrences_after(now, 2));
+    }
+
+    #[test]
+    fn test_time_format() {
+        let zone = Zone::Fixed(FixedOffset::east(3600));
+        let time = |y, m, d, h, min, grain| TimeOutput {
+            moment: Moment(zone.ymd(y, m, d).and_hms(h, min, 0)),
+            grain: grain,
+            precision: Precision::Exact,
+            latent: false,
+            timezone: None,
+            form: None,
+            ambiguous_clock: false,
+            alternative: None,
+            fiscal_year_start: 1,
+        };
+        assert_eq!("2013-02-12T04:30:00+01:00", time(2013, 2, 12, 4, 30, Grain::Minute).format(TimeFormat::Rfc3339));
+        assert_eq!("2013-02-12T04:30+01:00", time(2013, 2, 12, 4, 30, Grain::Minute).format(TimeFormat::Grain));
+        assert_eq!("2013-02-12T04+01:00", time(2013, 2, 12, 4, 0, Grain::Hour).format(TimeFormat::Grain));
+        assert_eq!("2013-02-12", time(2013, 2, 12, 0, 0, Grain::Day).format(TimeFormat::Grain));
+        assert_eq!("2013-02-11", time(2013, 2, 11, 0, 0, Grain::Week).format(TimeFormat::Grain));
+        assert_eq!("2013-02", time(2013, 2, 1, 0, 0, Grain::Month).format(TimeFormat::Grain));
+        assert_eq!("2013-Q2", time(2013, 4, 1, 0, 0, Grain::Quarter).format(TimeFormat::Grain));
+        let fiscal_quarter = TimeOutput { fiscal_year_start: 10, ..time(2013, 10, 1, 0, 0, Grain::Quarter) };
+        assert_eq!("FY2014-Q1", fiscal_quarter.format(TimeFormat::Grain));
+        let fiscal_quarter = TimeOutput { fiscal_year_start: 10, ..time(2014, 7, 1, 0, 0, Grain::Quarter) };
+        assert_eq!("FY2014-Q4", fiscal_quarter.format(TimeFormat::Grain));
+        assert_eq!("2013", time(2013, 1, 1, 0, 0, Grain::Year).format(TimeFormat::Grain));
+
+        let after = TimeIntervalOutput::After(time(2013, 2, 12, 0, 0, Grain::Day));
+        assert_eq!("2013-02-12/..", after.format(TimeFormat::Grain));
+        let between = TimeIntervalOutput::Between {
+            start: Moment(zone.ymd(2013, 2, 12).and_hms(0, 0, 0)),
+            end: Moment(zone.ymd(2013, 2, 14).and_hms(0, 0, 0)),
+            precision: Precision::Exact,
+            latent: false,
+            timezone: None,
+            form: None,
+            ambiguous_clock: false,
+        };
+        assert_eq!("2013-02-12/2013-02-14", between.format(TimeFormat::Grain));
+        assert_eq!("2013-02-12T00:00:00+01:00/2013-02-14T00:00:00+01:00", between.format(TimeFormat::Rfc3339));
+    }
+}